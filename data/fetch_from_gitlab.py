@@ -65,5 +65,26 @@ if __name__ == '__main__':
     write_result(token, 'pipeline', '/projects/%s/pipelines/%d' % (REPO, PIPELINE_ID))
     write_result(token, 'group', '/groups/%s' % GROUP_ID)
     write_result(token, 'job', '/projects/%s/pipelines/%s/jobs' % (REPO, PIPELINE2_ID), dumpall=True)
+    # FIXME: there is no fork of the project to fetch, so `project_fork` is a copy of `project`
+    # with `forked_from_project` filled in by hand.
+    # write_result(token, 'project_fork', '/projects/%s' % FORK)
     # FIXME: these are hidden behind a `403 forbidden`, so we use a hardcoded example instead.
     # write_result(token, 'pipeline_variable', '/projects/%s/pipelines/%d/variables' % (REPO, PIPELINE_ID))
+    # write_result(token, 'variable', '/projects/%s/variables/%s' % (REPO, VARIABLE))
+    # write_result(token, 'release', '/projects/%s/releases/%s' % (REPO, RELEASE_TAG))
+    # write_result(token, 'pipeline_schedule', '/projects/%s/pipeline_schedules/%d' % (REPO, PIPELINE_SCHEDULE_ID))
+    # write_result(token, 'pipeline_trigger', '/projects/%s/triggers/%d' % (REPO, PIPELINE_TRIGGER_ID))
+    # FIXME: the pipeline has no test reports or downstream pipelines, so these are hardcoded
+    # examples.
+    # write_result(token, 'test_report', '/projects/%s/pipelines/%d/test_report' % (REPO, PIPELINE_ID))
+    # write_result(token, 'test_report_summary', '/projects/%s/pipelines/%d/test_report_summary' % (REPO, PIPELINE_ID))
+    # write_result(token, 'bridge', '/projects/%s/pipelines/%d/bridges' % (REPO, PIPELINE_ID))
+    # FIXME: the project has no deployments or environments, so these are hardcoded examples.
+    # write_result(token, 'deployment', '/projects/%s/deployments/%d' % (REPO, DEPLOYMENT_ID))
+    # write_result(token, 'environment', '/projects/%s/environments/%d' % (REPO, ENVIRONMENT_ID))
+    # FIXME: runner details require access to the runner, so this is a hardcoded example.
+    # write_result(token, 'runner_details', '/runners/%d' % RUNNER_ID)
+    # FIXME: linting requires a POST request, so this is a hardcoded example.
+    # write_result(token, 'ci_lint_result', '/projects/%s/ci/lint' % REPO)
+    # FIXME: this is a hardcoded example of comparing the first parent of `COMMIT` to itself.
+    # write_result(token, 'repo_compare', '/projects/%s/repository/compare?from=%s^&to=%s' % (REPO, COMMIT, COMMIT))
@@ -55,9 +55,29 @@
 //! // usually meant for endpoints which represent file contents, pipeline artifacts, etc., but may
 //! // be used with any endpoint.
 //! let raw_data: Vec<u8> = api::raw(endpoint).query(&client).unwrap();
+//!
+//! // Large responses, such as job artifact archives, may instead be written to a writer as they
+//! // arrive using the `api::download` function.
+//! let endpoint = projects::jobs::artifacts::JobArtifacts::builder()
+//!     .project("gitlab-org/gitlab")
+//!     .job(1234)
+//!     .build()
+//!     .unwrap();
+//! let mut archive = std::fs::File::create("artifacts.zip").unwrap();
+//! api::download(endpoint).query_to(&client, &mut archive).unwrap();
+//!
+//! // Most endpoints also have a default return type from `gitlab::types`. The `fetch` method of
+//! // the `TypedEndpoint` trait uses it so that no type needs to be named.
+//! use gitlab::api::TypedEndpoint;
+//! let endpoint = projects::Project::builder().project("gitlab-org/gitlab").build().unwrap();
+//! let project = endpoint.fetch(&client).unwrap();
+//! println!("{}", project.path_with_namespace);
 //! ```
 
+mod ad_hoc;
 mod client;
+mod description;
+mod download;
 mod endpoint;
 mod error;
 mod ignore;
@@ -66,27 +86,43 @@ mod params;
 pub(crate) mod query;
 mod raw;
 mod sudo;
+mod typed;
 
 pub mod endpoint_prelude;
 
+pub mod admin;
 pub mod common;
 pub mod deploy_keys;
+pub mod dry_run;
 pub mod groups;
 pub mod issues;
 pub mod projects;
 pub mod retry;
+pub mod runners;
 pub mod users;
 
 pub(crate) mod helpers;
 
+pub use self::ad_hoc::AdHocEndpoint;
+pub use self::ad_hoc::AdHocEndpointBuilder;
+pub use self::ad_hoc::AdHocEndpointBuilderError;
+
 pub use self::client::AsyncClient;
 pub use self::client::Client;
 pub use self::client::RestClient;
 
+pub use self::description::DescriptionBody;
+pub use self::description::DescriptionError;
+pub use self::description::EndpointDescription;
+
+pub use self::download::download;
+pub use self::download::Download;
+
 pub use self::endpoint::Endpoint;
 
 pub use self::error::ApiError;
 pub use self::error::BodyError;
+pub use self::error::GitlabError;
 
 pub use self::ignore::ignore;
 pub use self::ignore::Ignore;
@@ -112,3 +148,5 @@ pub use self::raw::Raw;
 pub use self::sudo::sudo;
 pub use self::sudo::Sudo;
 pub use self::sudo::SudoContext;
+
+pub use self::typed::TypedEndpoint;
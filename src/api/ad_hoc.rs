@@ -0,0 +1,339 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+const PLACEHOLDER: &str = "{}";
+
+/// An endpoint for API routes which do not have a dedicated structure.
+///
+/// The path is given as a template where each `{}` is replaced by the next segment. Segments
+/// which are names are escaped so that they are a single path component.
+///
+/// ```rust,no_run
+/// # use gitlab::api::{self, AdHocEndpoint, Query};
+/// # use http::Method;
+/// # let client = gitlab::Gitlab::new("gitlab.com", "private-token").unwrap();
+/// let endpoint = AdHocEndpoint::builder()
+///     .method(Method::POST)
+///     .path("projects/{}/feature_flags")
+///     .segment("group/project")
+///     .form_param("name", "new_ui")
+///     .build()
+///     .unwrap();
+/// api::ignore(endpoint).query(&client).unwrap();
+/// ```
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct AdHocEndpoint<'a> {
+    /// The HTTP method to use.
+    ///
+    /// Defaults to `GET`.
+    #[builder(default = "Method::GET")]
+    method: Method,
+    /// The path template for the endpoint.
+    #[builder(setter(into))]
+    path: Cow<'a, str>,
+    /// Segments to substitute into the path template.
+    #[builder(setter(name = "_segments"), default, private)]
+    segments: Vec<NameOrId<'a>>,
+    /// Query parameters for the endpoint.
+    #[builder(setter(name = "_query"), default, private)]
+    query: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    /// Form parameters for the endpoint.
+    #[builder(setter(name = "_form"), default, private)]
+    form: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    /// Whether the endpoint uses keyset pagination.
+    ///
+    /// Defaults to `false`.
+    #[builder(default)]
+    keyset_pagination: bool,
+}
+
+impl<'a> AdHocEndpoint<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AdHocEndpointBuilder<'a> {
+        AdHocEndpointBuilder::default()
+    }
+}
+
+impl<'a> AdHocEndpointBuilder<'a> {
+    /// Add a segment to the path.
+    pub fn segment<S>(&mut self, segment: S) -> &mut Self
+    where
+        S: Into<NameOrId<'a>>,
+    {
+        self.segments
+            .get_or_insert_with(Vec::new)
+            .push(segment.into());
+        self
+    }
+
+    /// Add multiple segments to the path.
+    pub fn segments<I, S>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = S>,
+        S: Into<NameOrId<'a>>,
+    {
+        self.segments
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    /// Add a query parameter.
+    pub fn query_param<'b, K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        self.query
+            .get_or_insert_with(Vec::new)
+            .push((key.into(), value.as_value()));
+        self
+    }
+
+    /// Add a form parameter.
+    ///
+    /// The endpoint has a form body if any form parameters are given.
+    pub fn form_param<'b, K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        self.form
+            .get_or_insert_with(Vec::new)
+            .push((key.into(), value.as_value()));
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if let Some(path) = self.path.as_ref() {
+            let placeholders = path.matches(PLACEHOLDER).count();
+            let segments = self.segments.as_ref().map_or(0, Vec::len);
+            if placeholders != segments {
+                return Err(format!(
+                    "the path template has {} placeholders, but {} segments were given",
+                    placeholders, segments,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for AdHocEndpoint<'a> {
+    fn method(&self) -> Method {
+        self.method.clone()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        let mut parts = self.path.split(PLACEHOLDER);
+        let mut endpoint = String::from(parts.next().unwrap_or_default());
+        for (segment, part) in self.segments.iter().zip(parts) {
+            endpoint.push_str(&segment.to_string());
+            endpoint.push_str(part);
+        }
+        endpoint.into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+        params.extend(
+            self.query
+                .iter()
+                .map(|(key, value)| (key.as_ref(), value.as_ref())),
+        );
+        params
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        if self.form.is_empty() {
+            return Ok(None);
+        }
+
+        let mut params = FormParams::default();
+        params.extend(
+            self.form
+                .iter()
+                .map(|(key, value)| (key.as_ref(), value.as_ref())),
+        );
+        params.into_body()
+    }
+}
+
+impl<'a> Pageable for AdHocEndpoint<'a> {
+    fn use_keyset_pagination(&self) -> bool {
+        self.keyset_pagination
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use serde_json::json;
+
+    use crate::api::common::NameOrId;
+    use crate::api::{self, AdHocEndpoint, AdHocEndpointBuilderError, Pagination, Query};
+    use crate::test::client::{ExpectedUrl, PagedTestClient, SingleTestClient};
+
+    #[test]
+    fn path_is_needed() {
+        let err = AdHocEndpoint::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AdHocEndpointBuilderError, "path");
+    }
+
+    #[test]
+    fn path_is_sufficient() {
+        AdHocEndpoint::builder().path("version").build().unwrap();
+    }
+
+    #[test]
+    fn segments_must_match_placeholders() {
+        let err = AdHocEndpoint::builder()
+            .path("projects/{}/issues/{}")
+            .segment("simple/project")
+            .build()
+            .unwrap_err();
+        if let AdHocEndpointBuilderError::ValidationError(message) = err {
+            assert_eq!(
+                message,
+                "the path template has 2 placeholders, but 1 segments were given",
+            );
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder().endpoint("version").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AdHocEndpoint::builder().path("version").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_segments() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues/1/links")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AdHocEndpoint::builder()
+            .path("projects/{}/issues/{}/links")
+            .segments(vec![NameOrId::from("simple/project"), 1.into()].into_iter())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_query() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1/search")
+            .add_query_params(&[("scope", "blobs"), ("search", "needle"), ("ref", "main")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AdHocEndpoint::builder()
+            .path("projects/{}/search")
+            .segment(1)
+            .query_param("scope", "blobs")
+            .query_param("search", "needle")
+            .query_param("ref", String::from("main"))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_form() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/feature_flags")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=new_ui", "&active=true", "&version=2"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AdHocEndpoint::builder()
+            .method(Method::POST)
+            .path("projects/{}/feature_flags")
+            .segment("simple/project")
+            .form_param("name", "new_ui")
+            .form_param("active", true)
+            .form_param("version", 2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_raw() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1/jobs/2/trace")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "log output");
+
+        let endpoint = AdHocEndpoint::builder()
+            .path("projects/{}/jobs/{}/trace")
+            .segment(1)
+            .segment(2)
+            .build()
+            .unwrap();
+        let data = api::raw(endpoint).query(&client).unwrap();
+        assert_eq!(data, b"log output");
+    }
+
+    #[test]
+    fn endpoint_sudo() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("version")
+            .add_query_params(&[("sudo", "user")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AdHocEndpoint::builder().path("version").build().unwrap();
+        api::ignore(api::sudo(endpoint, "user"))
+            .query(&client)
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1/feature_flags")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client =
+            PagedTestClient::new_raw(endpoint, (0..=255).map(|value| json!({ "value": value })));
+
+        let endpoint = AdHocEndpoint::builder()
+            .path("projects/{}/feature_flags")
+            .segment(1)
+            .build()
+            .unwrap();
+        let res: Vec<serde_json::Value> = api::paged(endpoint, Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.len(), 256);
+    }
+}
@@ -0,0 +1,11 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Instance-level CI/CD API endpoints.
+//!
+//! These endpoints are used for managing CI/CD settings which apply to the entire instance.
+
+pub mod variables;
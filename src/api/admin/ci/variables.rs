@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Instance variable API endpoints.
+//!
+//! These endpoints are used for querying and modifying the variables available to every project
+//! on an instance.
+
+mod create;
+mod delete;
+mod update;
+mod variable;
+mod variables;
+
+pub use self::create::CreateInstanceVariable;
+pub use self::create::CreateInstanceVariableBuilder;
+pub use self::create::CreateInstanceVariableBuilderError;
+
+pub use self::delete::DeleteInstanceVariable;
+pub use self::delete::DeleteInstanceVariableBuilder;
+pub use self::delete::DeleteInstanceVariableBuilderError;
+
+pub use self::update::UpdateInstanceVariable;
+pub use self::update::UpdateInstanceVariableBuilder;
+pub use self::update::UpdateInstanceVariableBuilderError;
+
+pub use self::variable::InstanceVariable;
+pub use self::variable::InstanceVariableBuilder;
+pub use self::variable::InstanceVariableBuilderError;
+
+pub use self::variables::InstanceVariables;
+pub use self::variables::InstanceVariablesBuilder;
+pub use self::variables::InstanceVariablesBuilderError;
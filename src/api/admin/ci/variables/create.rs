@@ -0,0 +1,224 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::VariableType;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Add an instance variable.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateInstanceVariable<'a> {
+    /// The key of the variable
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+    /// The value of a variable
+    #[builder(setter(into))]
+    value: Cow<'a, str>,
+    /// The type of the variable.
+    #[builder(default)]
+    variable_type: Option<VariableType>,
+    /// Whether the variable is protected.
+    #[builder(default)]
+    protected: Option<bool>,
+    /// Whether the variable is masked.
+    #[builder(default)]
+    masked: Option<bool>,
+    /// Whether the variable is treated as a raw string.
+    ///
+    /// Raw variables do not expand references to other variables.
+    #[builder(default)]
+    raw: Option<bool>,
+}
+
+impl<'a> CreateInstanceVariable<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateInstanceVariableBuilder<'a> {
+        CreateInstanceVariableBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateInstanceVariable<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "admin/ci/variables".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("key", &self.key)
+            .push("value", &self.value)
+            .push_opt("variable_type", self.variable_type)
+            .push_opt("protected", self.protected)
+            .push_opt("masked", self.masked)
+            .push_opt("raw", self.raw);
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for CreateInstanceVariable<'a> {
+    type Response = types::Variable;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::admin::ci::variables::{
+        CreateInstanceVariable, CreateInstanceVariableBuilderError,
+    };
+    use crate::api::common::VariableType;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = CreateInstanceVariable::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateInstanceVariableBuilderError, "key");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = CreateInstanceVariable::builder()
+            .value("testvalue")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateInstanceVariableBuilderError, "key");
+    }
+
+    #[test]
+    fn value_level_is_necessary() {
+        let err = CreateInstanceVariable::builder()
+            .key("testkey")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateInstanceVariableBuilderError, "value");
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        CreateInstanceVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/ci/variables")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("key=testkey", "&value=testvalue"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateInstanceVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_variable_type() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/ci/variables")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "key=testkey",
+                "&value=testvalue",
+                "&variable_type=file"
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateInstanceVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .variable_type(VariableType::File)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_protected() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/ci/variables")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "key=testkey",
+                "&value=testvalue",
+                "&protected=true"
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateInstanceVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .protected(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_masked() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/ci/variables")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("key=testkey", "&value=testvalue", "&masked=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateInstanceVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .masked(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_raw() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/ci/variables")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("key=testkey", "&value=testvalue", "&raw=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateInstanceVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .raw(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,73 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Get an instance variable.
+#[derive(Debug, Builder, Clone)]
+pub struct InstanceVariable<'a> {
+    /// The name of the variable.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> InstanceVariable<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> InstanceVariableBuilder<'a> {
+        InstanceVariableBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for InstanceVariable<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("admin/ci/variables/{}", common::path_escaped(&self.key)).into()
+    }
+}
+
+impl<'a> TypedEndpoint for InstanceVariable<'a> {
+    type Response = types::Variable;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::admin::ci::variables::{InstanceVariable, InstanceVariableBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn key_is_necessary() {
+        let err = InstanceVariable::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, InstanceVariableBuilderError, "key");
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        InstanceVariable::builder().key("testkey").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::GET)
+            .endpoint("admin/ci/variables/testkey%2F")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = InstanceVariable::builder().key("testkey/").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,61 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Query for the instance variables.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct InstanceVariables {}
+
+impl InstanceVariables {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> InstanceVariablesBuilder {
+        InstanceVariablesBuilder::default()
+    }
+}
+
+impl Endpoint for InstanceVariables {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "admin/ci/variables".into()
+    }
+}
+
+impl TypedEndpoint for InstanceVariables {
+    type Response = Vec<types::Variable>;
+}
+
+impl Pageable for InstanceVariables {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::admin::ci::variables::InstanceVariables;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        InstanceVariables::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("admin/ci/variables")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = InstanceVariables::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -5,9 +5,11 @@
 // except according to those terms.
 
 use std::error::Error;
+use std::io;
 
 use async_trait::async_trait;
-use bytes::Bytes;
+use bytes::{Buf, Bytes};
+use futures_util::stream::{self, BoxStream, StreamExt};
 use http::request::Builder as RequestBuilder;
 use http::Response;
 use url::Url;
@@ -33,6 +35,19 @@ pub trait Client: RestClient {
         request: RequestBuilder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>>;
+
+    /// Send a REST query and read the response body as it arrives.
+    ///
+    /// The default implementation collects the response body in memory using
+    /// [`Client::rest`]. Clients which are able to stream the body should override it.
+    fn rest_stream(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Box<dyn io::Read + Send>>, ApiError<Self::Error>> {
+        let rsp = self.rest(request, body)?;
+        Ok(rsp.map(|body| Box::new(body.reader()) as Box<dyn io::Read + Send>))
+    }
 }
 
 /// A trait representing an asynchronous client which can communicate with a GitLab instance.
@@ -44,4 +59,20 @@ pub trait AsyncClient: RestClient {
         request: RequestBuilder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>>;
+
+    /// Send a REST query asynchronously and receive the response body as a stream of chunks.
+    ///
+    /// The default implementation collects the response body in memory using
+    /// [`AsyncClient::rest_async`]. Clients which are able to stream the body should override it.
+    async fn rest_async_stream(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<
+        Response<BoxStream<'static, Result<Bytes, ApiError<Self::Error>>>>,
+        ApiError<Self::Error>,
+    > {
+        let rsp = self.rest_async(request, body).await?;
+        Ok(rsp.map(|body| stream::once(async { Ok(body) }).boxed()))
+    }
 }
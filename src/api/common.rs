@@ -294,6 +294,36 @@ impl ParamValue<'static> for ProtectedAccessLevel {
     }
 }
 
+/// The type of a CI/CD variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariableType {
+    /// An environment variable.
+    ///
+    /// The value of the variable is available as the value of the named environment variable.
+    EnvVar,
+    /// A file variable.
+    ///
+    /// The value of the variable is available in a file given by the value of the named
+    /// environment variable.
+    File,
+}
+
+impl VariableType {
+    /// The variable type query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            VariableType::EnvVar => "env_var",
+            VariableType::File => "file",
+        }
+    }
+}
+
+impl ParamValue<'static> for VariableType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
 /// A comma-separated list of values.
 #[derive(Debug, Clone, Default)]
 pub struct CommaSeparatedList<T> {
@@ -377,7 +407,7 @@ mod tests {
 
     use crate::api::common::{
         AccessLevel, CommaSeparatedList, EnableState, NameOrId, ProtectedAccessLevel, SortOrder,
-        VisibilityLevel, YesNo,
+        VariableType, VisibilityLevel, YesNo,
     };
     use crate::api::params::ParamValue;
 
@@ -572,6 +602,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn variable_type_as_str() {
+        let items = &[
+            (VariableType::EnvVar, "env_var"),
+            (VariableType::File, "file"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
     #[test]
     fn comma_separated_list_default() {
         let csl = CommaSeparatedList::<u64>::default();
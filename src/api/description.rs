@@ -0,0 +1,354 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+
+use http::Method;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::api::{BodyError, Endpoint, QueryParams};
+
+const FORM_CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Errors which may occur when describing an endpoint.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum DescriptionError {
+    /// The body of the endpoint could not be created.
+    #[error("failed to create the endpoint body: {}", source)]
+    Body {
+        /// The source of the error.
+        #[from]
+        source: BodyError,
+    },
+    /// The body of the endpoint could not be decoded as form parameters.
+    #[error("failed to decode form parameters: {}", source)]
+    Form {
+        /// The source of the error.
+        #[from]
+        source: serde_urlencoded::de::Error,
+    },
+    /// The body of the endpoint could not be decoded as JSON.
+    #[error("failed to decode JSON body: {}", source)]
+    Json {
+        /// The source of the error.
+        #[from]
+        source: serde_json::Error,
+    },
+    /// The body of the endpoint uses an unsupported content type.
+    #[error("unsupported body content type: {}", content_type)]
+    UnsupportedContentType {
+        /// The content type of the body.
+        content_type: &'static str,
+    },
+}
+
+/// The body of a described endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DescriptionBody {
+    /// Form parameters sent as `application/x-www-form-urlencoded`.
+    Form(Vec<(String, String)>),
+    /// A JSON document sent as `application/json`.
+    Json(serde_json::Value),
+}
+
+impl DescriptionBody {
+    fn new(content_type: &'static str, data: &[u8]) -> Result<Self, DescriptionError> {
+        match content_type {
+            FORM_CONTENT_TYPE => Ok(DescriptionBody::Form(serde_urlencoded::from_bytes(data)?)),
+            JSON_CONTENT_TYPE => Ok(DescriptionBody::Json(serde_json::from_slice(data)?)),
+            _ => {
+                Err(DescriptionError::UnsupportedContentType {
+                    content_type,
+                })
+            },
+        }
+    }
+}
+
+mod method {
+    use http::Method;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(method: &Method, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(method.as_str())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Method, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let method = String::deserialize(deserializer)?;
+        Method::from_bytes(method.as_bytes()).map_err(D::Error::custom)
+    }
+}
+
+/// A serializable description of a request to an endpoint.
+///
+/// Any endpoint may be captured into a description which records its method, path, query
+/// parameters, and body. The description may be stored or sent elsewhere and later executed
+/// since it is itself an endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointDescription {
+    /// The HTTP method to use for the endpoint.
+    #[serde(with = "method")]
+    pub method: Method,
+    /// The path to the endpoint.
+    pub path: String,
+    /// Query parameters for the endpoint.
+    #[serde(default)]
+    pub query: Vec<(String, String)>,
+    /// The body for the endpoint.
+    #[serde(default)]
+    pub body: Option<DescriptionBody>,
+}
+
+impl EndpointDescription {
+    /// Describe an endpoint.
+    pub fn new<E>(endpoint: &E) -> Result<Self, DescriptionError>
+    where
+        E: Endpoint + ?Sized,
+    {
+        let query = endpoint
+            .parameters()
+            .iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+        let body = endpoint
+            .body()?
+            .map(|(content_type, data)| DescriptionBody::new(content_type, &data))
+            .transpose()?;
+
+        Ok(Self {
+            method: endpoint.method(),
+            path: endpoint.endpoint().into_owned(),
+            query,
+            body,
+        })
+    }
+}
+
+impl Endpoint for EndpointDescription {
+    fn method(&self) -> Method {
+        self.method.clone()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.path.clone().into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+        params.extend(
+            self.query
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str())),
+        );
+        params
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        Ok(match &self.body {
+            Some(DescriptionBody::Form(params)) => {
+                Some((
+                    FORM_CONTENT_TYPE,
+                    serde_urlencoded::to_string(params)?.into_bytes(),
+                ))
+            },
+            Some(DescriptionBody::Json(value)) => {
+                Some((
+                    JSON_CONTENT_TYPE,
+                    serde_json::to_vec(value).expect("JSON values should always serialize"),
+                ))
+            },
+            None => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::projects::issues::notes::CreateIssueNote;
+    use crate::api::projects::Projects;
+    use crate::api::{self, DescriptionBody, DescriptionError, EndpointDescription, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn describe_query() {
+        let endpoint = Projects::builder().search("name").build().unwrap();
+        let description = EndpointDescription::new(&endpoint).unwrap();
+
+        assert_eq!(description.method, Method::GET);
+        assert_eq!(description.path, "projects");
+        assert_eq!(
+            description.query,
+            [("search".into(), "name".into())].to_vec(),
+        );
+        assert_eq!(description.body, None);
+    }
+
+    #[test]
+    fn describe_form() {
+        let endpoint = CreateIssueNote::builder()
+            .project("simple/project")
+            .issue(1)
+            .body("comment")
+            .build()
+            .unwrap();
+        let description = EndpointDescription::new(&endpoint).unwrap();
+
+        assert_eq!(description.method, Method::POST);
+        assert_eq!(description.path, "projects/simple%2Fproject/issues/1/notes");
+        assert!(description.query.is_empty());
+        assert_eq!(
+            description.body,
+            Some(DescriptionBody::Form(
+                [("body".into(), "comment".into())].to_vec(),
+            )),
+        );
+    }
+
+    #[test]
+    fn describe_unsupported_content_type() {
+        struct Dummy;
+
+        impl Endpoint for Dummy {
+            fn method(&self) -> Method {
+                Method::PUT
+            }
+
+            fn endpoint(&self) -> Cow<'static, str> {
+                "dummy".into()
+            }
+
+            fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+                Ok(Some(("application/octet-stream", b"data".to_vec())))
+            }
+        }
+
+        let err = EndpointDescription::new(&Dummy).unwrap_err();
+        if let DescriptionError::UnsupportedContentType {
+            content_type,
+        } = err
+        {
+            assert_eq!(content_type, "application/octet-stream");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let endpoint = CreateIssueNote::builder()
+            .project("simple/project")
+            .issue(1)
+            .body("comment")
+            .build()
+            .unwrap();
+        let description = EndpointDescription::new(&endpoint).unwrap();
+
+        let serialized = serde_json::to_value(&description).unwrap();
+        assert_eq!(
+            serialized,
+            json!({
+                "method": "POST",
+                "path": "projects/simple%2Fproject/issues/1/notes",
+                "query": [],
+                "body": {
+                    "type": "form",
+                    "data": [
+                        ["body", "comment"],
+                    ],
+                },
+            }),
+        );
+
+        let deserialized: EndpointDescription = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized, description);
+    }
+
+    #[test]
+    fn bad_method() {
+        let err = serde_json::from_value::<EndpointDescription>(json!({
+            "method": "NOT A METHOD",
+            "path": "dummy",
+        }))
+        .unwrap_err();
+        assert_eq!(err.to_string(), "invalid HTTP method");
+    }
+
+    #[test]
+    fn endpoint_query() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects")
+            .add_query_params(&[("search", "name")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Projects::builder().search("name").build().unwrap();
+        let description = EndpointDescription::new(&endpoint).unwrap();
+        api::ignore(description).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_form() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/issues/1/notes")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("body=comment")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let description: EndpointDescription = serde_json::from_value(json!({
+            "method": "POST",
+            "path": "projects/simple%2Fproject/issues/1/notes",
+            "body": {
+                "type": "form",
+                "data": [
+                    ["body", "comment"],
+                ],
+            },
+        }))
+        .unwrap();
+        api::ignore(description).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_json() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("dummy")
+            .content_type("application/json")
+            .body_str(r#"{"key":"value"}"#)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let description = EndpointDescription {
+            method: Method::PUT,
+            path: "dummy".into(),
+            query: Vec::new(),
+            body: Some(DescriptionBody::Json(json!({
+                "key": "value",
+            }))),
+        };
+        api::ignore(description).query(&client).unwrap();
+    }
+}
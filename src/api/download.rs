@@ -0,0 +1,314 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::io::{self, Read};
+
+use bytes::Bytes;
+use futures_util::io::{AsyncWrite, AsyncWriteExt};
+use futures_util::TryStreamExt;
+use http::request::Builder as RequestBuilder;
+use http::{header, HeaderMap, Request, StatusCode};
+
+use crate::api::{query, ApiError, AsyncClient, Client, Endpoint, RestClient};
+
+/// A query modifier that writes the raw data from the endpoint to a writer.
+///
+/// Unlike [`raw`](crate::api::raw), the response is not collected in memory first, so this is
+/// suitable for large responses such as job artifact archives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Download<E> {
+    endpoint: E,
+}
+
+/// Write the raw data from the endpoint to a writer.
+pub fn download<E>(endpoint: E) -> Download<E> {
+    Download {
+        endpoint,
+    }
+}
+
+impl<E> Download<E>
+where
+    E: Endpoint,
+{
+    fn request<C>(&self, client: &C) -> Result<(RequestBuilder, Vec<u8>), ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let mut url = client.rest_endpoint(&self.endpoint.endpoint())?;
+        self.endpoint.parameters().add_to_url(&mut url);
+
+        let req = Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url));
+        Ok(if let Some((mime, data)) = self.endpoint.body()? {
+            let req = req.header(header::CONTENT_TYPE, mime);
+            (req, data)
+        } else {
+            (req, Vec::new())
+        })
+    }
+
+    /// Perform the query, writing the response to `writer`.
+    ///
+    /// Returns the number of bytes written. Error responses are not written to `writer`.
+    pub fn query_to<C, W>(&self, client: &C, writer: &mut W) -> Result<u64, ApiError<C::Error>>
+    where
+        C: Client,
+        W: io::Write + ?Sized,
+    {
+        let (req, data) = self.request(client)?;
+        let (parts, mut body) = client.rest_stream(req, data)?.into_parts();
+        if !parts.status.is_success() {
+            let mut data = Vec::new();
+            body.read_to_end(&mut data)?;
+            return Err(error_response(parts.status, &parts.headers, data.into()));
+        }
+
+        Ok(io::copy(&mut body, writer)?)
+    }
+
+    /// Perform the query asynchronously, writing the response to `writer`.
+    ///
+    /// Returns the number of bytes written. Error responses are not written to `writer`.
+    pub async fn query_to_async<C, W>(
+        &self,
+        client: &C,
+        writer: &mut W,
+    ) -> Result<u64, ApiError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let (req, data) = self.request(client)?;
+        let (parts, mut body) = client.rest_async_stream(req, data).await?.into_parts();
+        if !parts.status.is_success() {
+            let mut data = Vec::new();
+            while let Some(chunk) = body.try_next().await? {
+                data.extend_from_slice(&chunk);
+            }
+            return Err(error_response(parts.status, &parts.headers, data.into()));
+        }
+
+        let mut written = 0;
+        while let Some(chunk) = body.try_next().await? {
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+
+        Ok(written)
+    }
+}
+
+fn error_response<E>(status: StatusCode, headers: &HeaderMap, body: Bytes) -> ApiError<E>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    if let Ok(v) = serde_json::from_slice(&body) {
+        ApiError::from_gitlab(status, headers, v)
+    } else {
+        ApiError::server_error(status, headers, &body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Cursor};
+    use std::thread;
+
+    use http::StatusCode;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::GitlabBuilder;
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    struct FailingWriter;
+
+    impl io::Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::WriteZero.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn archive() -> Vec<u8> {
+        (0..1 << 20).map(|i| (i % 251) as u8).collect()
+    }
+
+    // Serve a single chunked response so that the real clients are exercised.
+    fn serve_once(body: Vec<u8>) -> (String, thread::JoinHandle<()>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let host = format!("{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            assert_eq!(request.url().trim_end_matches('?'), "/api/v4/dummy");
+            let response = tiny_http::Response::new(
+                tiny_http::StatusCode(200),
+                Vec::new(),
+                Cursor::new(body),
+                None,
+                None,
+            );
+            request.respond(response).unwrap();
+        });
+        (host, handle)
+    }
+
+    #[test]
+    fn test_download() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "archive");
+
+        let mut data = Vec::new();
+        let written = api::download(Dummy).query_to(&client, &mut data).unwrap();
+        assert_eq!(written, 7);
+        assert_eq!(data, b"archive");
+    }
+
+    #[tokio::test]
+    async fn test_download_async() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "archive");
+
+        let mut data = Vec::new();
+        let written = api::download(Dummy)
+            .query_to_async(&client, &mut data)
+            .await
+            .unwrap();
+        assert_eq!(written, 7);
+        assert_eq!(data, b"archive");
+    }
+
+    #[test]
+    fn test_download_gitlab_error() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let mut data = Vec::new();
+        let err = api::download(Dummy)
+            .query_to(&client, &mut data)
+            .unwrap_err();
+        if let ApiError::Gitlab {
+            status,
+            msg,
+        } = err
+        {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        assert!(data.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_download_gitlab_error_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "404 Not found",
+            }),
+        );
+
+        let mut data = Vec::new();
+        let err = api::download(Dummy)
+            .query_to_async(&client, &mut data)
+            .await
+            .unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("404 Not found"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn test_download_write_error() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "archive");
+
+        let err = api::download(Dummy)
+            .query_to(&client, &mut FailingWriter)
+            .unwrap_err();
+        if let ApiError::Io {
+            source,
+        } = err
+        {
+            assert_eq!(source.kind(), io::ErrorKind::WriteZero);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_download_streamed() {
+        let (host, server) = serve_once(archive());
+        let client = GitlabBuilder::new_unauthenticated(host)
+            .insecure()
+            .build()
+            .unwrap();
+
+        let mut data = Vec::new();
+        let written = api::download(Dummy).query_to(&client, &mut data).unwrap();
+        server.join().unwrap();
+        assert_eq!(written, 1 << 20);
+        assert_eq!(data, archive());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_download_streamed_async() {
+        let (host, server) = serve_once(archive());
+        let client = GitlabBuilder::new_unauthenticated(host)
+            .insecure()
+            .build_async()
+            .await
+            .unwrap();
+
+        let mut data = Vec::new();
+        let written = api::download(Dummy)
+            .query_to_async(&client, &mut data)
+            .await
+            .unwrap();
+        server.join().unwrap();
+        assert_eq!(written, 1 << 20);
+        assert_eq!(data, archive());
+    }
+}
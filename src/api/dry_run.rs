@@ -0,0 +1,479 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Dry-run client wrapper
+//!
+//! This module provides a `Client` implementation which records every request made through it
+//! instead of sending it to GitLab. Requests which do not modify anything on the server (`GET` and
+//! `HEAD`) may optionally be passed through to a real client so that scripts which look up
+//! information before deciding what to change still work. The recorded mutations may then be
+//! rendered as a human-readable plan.
+//!
+//! Requests which are not sent receive a synthetic `200 OK` response with an empty JSON object
+//! as its body. Mutations should generally be performed via `api::ignore` when using this client
+//! since the endpoint's real response is not available.
+
+use std::convert::Infallible;
+use std::fmt;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::{header, Method, Response, StatusCode};
+use url::Url;
+
+use crate::api::{self, ApiError};
+
+const SYNTHETIC_RESPONSE: &[u8] = b"{}";
+
+/// A request recorded by the dry-run client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// The HTTP method of the request.
+    pub method: Method,
+    /// The URL of the request without its query string.
+    pub url: Url,
+    /// The query parameters of the request.
+    pub query: Vec<(String, String)>,
+    /// The `Content-Type` of the request body, if any.
+    pub content_type: Option<String>,
+    /// The body of the request.
+    pub body: Vec<u8>,
+    /// Whether the request was passed through to the wrapped client.
+    pub sent: bool,
+}
+
+impl RecordedRequest {
+    fn new(request: &RequestBuilder, body: &[u8]) -> Self {
+        let method = request.method_ref().cloned().unwrap_or(Method::GET);
+        let mut url = request
+            .uri_ref()
+            .and_then(|uri| Url::parse(&uri.to_string()).ok())
+            .expect("requests should always have an absolute URL");
+        let query = url
+            .query_pairs()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        url.set_query(None);
+        let content_type = request
+            .headers_ref()
+            .and_then(|headers| headers.get(header::CONTENT_TYPE))
+            .and_then(|value| value.to_str().ok())
+            .map(Into::into);
+
+        Self {
+            method,
+            url,
+            query,
+            content_type,
+            body: body.into(),
+            sent: false,
+        }
+    }
+
+    /// Whether the request modifies data on the server.
+    pub fn is_mutation(&self) -> bool {
+        !self.method.is_safe()
+    }
+
+    fn write_body(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.body.is_empty() {
+            return Ok(());
+        }
+
+        match self.content_type.as_deref() {
+            Some("application/x-www-form-urlencoded") => {
+                if let Ok(params) =
+                    serde_urlencoded::from_bytes::<Vec<(String, String)>>(&self.body)
+                {
+                    for (key, value) in params {
+                        writeln!(f, "    {} = {}", key, value)?;
+                    }
+                    return Ok(());
+                }
+            },
+            Some("application/json") => {
+                if let Ok(value) = serde_json::from_slice::<serde_json::Value>(&self.body) {
+                    if let Ok(pretty) = serde_json::to_string_pretty(&value) {
+                        for line in pretty.lines() {
+                            writeln!(f, "    {}", line)?;
+                        }
+                        return Ok(());
+                    }
+                }
+            },
+            _ => (),
+        }
+
+        writeln!(f, "    <{} bytes of body data>", self.body.len())
+    }
+}
+
+impl fmt::Display for RecordedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} {}", self.method, self.url)?;
+        for (key, value) in &self.query {
+            writeln!(f, "    ?{} = {}", key, value)?;
+        }
+        self.write_body(f)
+    }
+}
+
+/// A human-readable plan of the mutations recorded by a dry-run client.
+#[derive(Debug, Clone)]
+pub struct Plan {
+    mutations: Vec<RecordedRequest>,
+}
+
+impl Plan {
+    /// The mutations which would have been performed.
+    pub fn mutations(&self) -> &[RecordedRequest] {
+        &self.mutations
+    }
+
+    /// Whether the plan would change anything.
+    pub fn is_empty(&self) -> bool {
+        self.mutations.is_empty()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.mutations.is_empty() {
+            return writeln!(f, "no changes would be made");
+        }
+
+        writeln!(f, "{} change(s) would be made:", self.mutations.len())?;
+        for (idx, request) in self.mutations.iter().enumerate() {
+            write!(f, "{:>4}. {}", idx + 1, request)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A placeholder client for dry runs which do not have access to a GitLab instance.
+///
+/// All requests sent to it receive a synthetic successful response.
+#[derive(Debug, Clone)]
+pub struct Offline {
+    rest_url: Url,
+}
+
+impl Offline {
+    /// Create an offline client for the given GitLab host.
+    pub fn new<H>(host: H) -> Result<Self, url::ParseError>
+    where
+        H: AsRef<str>,
+    {
+        Ok(Self {
+            rest_url: Url::parse(&format!("https://{}/api/v4/", host.as_ref()))?,
+        })
+    }
+}
+
+impl api::RestClient for Offline {
+    type Error = Infallible;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.rest_url.join(endpoint)?)
+    }
+}
+
+impl api::Client for Offline {
+    fn rest(
+        &self,
+        _: RequestBuilder,
+        _: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        Ok(synthetic_response())
+    }
+}
+
+#[async_trait]
+impl api::AsyncClient for Offline {
+    async fn rest_async(
+        &self,
+        _: RequestBuilder,
+        _: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        Ok(synthetic_response())
+    }
+}
+
+fn synthetic_response() -> Response<Bytes> {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Bytes::from_static(SYNTHETIC_RESPONSE))
+        .unwrap()
+}
+
+/// A wrapper around a client which records requests rather than sending them.
+///
+/// No request which may modify data is ever sent to the wrapped client.
+pub struct Client<C> {
+    client: C,
+    pass_through_reads: bool,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl<C> Client<C> {
+    /// Create a client which records all requests without sending any of them.
+    pub fn new(client: C) -> Self {
+        Self {
+            client,
+            pass_through_reads: false,
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Create a client which sends read-only requests to the wrapped client.
+    ///
+    /// All requests are still recorded.
+    pub fn with_read_passthrough(client: C) -> Self {
+        Self {
+            client,
+            pass_through_reads: true,
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.client
+    }
+
+    /// All requests which have been recorded, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().expect("poisoned lock").clone()
+    }
+
+    /// The plan of all recorded mutations.
+    pub fn plan(&self) -> Plan {
+        Plan {
+            mutations: self
+                .requests
+                .lock()
+                .expect("poisoned lock")
+                .iter()
+                .filter(|request| request.is_mutation())
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Forget all recorded requests.
+    pub fn clear(&self) {
+        self.requests.lock().expect("poisoned lock").clear();
+    }
+
+    fn record(&self, request: &RequestBuilder, body: &[u8]) -> bool {
+        let mut recorded = RecordedRequest::new(request, body);
+        let send = self.pass_through_reads && !recorded.is_mutation();
+        recorded.sent = send;
+        self.requests.lock().expect("poisoned lock").push(recorded);
+        send
+    }
+}
+
+impl<C> api::RestClient for Client<C>
+where
+    C: api::RestClient,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.rest_endpoint(endpoint)
+    }
+}
+
+impl<C> api::Client for Client<C>
+where
+    C: api::Client,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        if self.record(&request, &body) {
+            self.client.rest(request, body)
+        } else {
+            Ok(synthetic_response())
+        }
+    }
+}
+
+#[async_trait]
+impl<C> api::AsyncClient for Client<C>
+where
+    C: api::AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        if self.record(&request, &body) {
+            self.client.rest_async(request, body).await
+        } else {
+            Ok(synthetic_response())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, dry_run, AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    struct DummyEdit;
+
+    impl Endpoint for DummyEdit {
+        fn method(&self) -> Method {
+            Method::PUT
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "projects/simple%2Fproject".into()
+        }
+
+        fn parameters(&self) -> QueryParams {
+            let mut params = QueryParams::default();
+            params.push("sudo", "user");
+            params
+        }
+
+        fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+            let mut params = FormParams::default();
+            params.push("name", "new name").push("archived", true);
+            params.into_body()
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    #[test]
+    fn offline_records_everything() {
+        let client = dry_run::Client::new(dry_run::Offline::new("gitlab.host.invalid").unwrap());
+
+        api::ignore(Dummy).query(&client).unwrap();
+        api::ignore(DummyEdit).query(&client).unwrap();
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, Method::GET);
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://gitlab.host.invalid/api/v4/dummy",
+        );
+        assert!(!requests[0].sent);
+        assert!(!requests[0].is_mutation());
+        assert_eq!(requests[1].method, Method::PUT);
+        assert_eq!(
+            requests[1].url.as_str(),
+            "https://gitlab.host.invalid/api/v4/projects/simple%2Fproject",
+        );
+        assert_eq!(requests[1].query, [("sudo".into(), "user".into())]);
+        assert_eq!(
+            requests[1].content_type.as_deref(),
+            Some("application/x-www-form-urlencoded"),
+        );
+        assert_eq!(requests[1].body, b"name=new+name&archived=true");
+        assert!(!requests[1].sent);
+        assert!(requests[1].is_mutation());
+    }
+
+    #[test]
+    fn read_passthrough() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 4,
+            }),
+        );
+        let client = dry_run::Client::with_read_passthrough(client);
+
+        let res: DummyResult = Dummy.query(&client).unwrap();
+        assert_eq!(res.value, 4);
+        // The test client would panic if this were sent.
+        api::ignore(DummyEdit).query(&client).unwrap();
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].sent);
+        assert!(!requests[1].sent);
+    }
+
+    #[tokio::test]
+    async fn read_passthrough_async() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 4,
+            }),
+        );
+        let client = dry_run::Client::with_read_passthrough(client);
+
+        let res: DummyResult = Dummy.query_async(&client).await.unwrap();
+        assert_eq!(res.value, 4);
+        api::ignore(DummyEdit).query_async(&client).await.unwrap();
+
+        assert_eq!(client.requests().len(), 2);
+        assert_eq!(client.plan().mutations().len(), 1);
+    }
+
+    #[test]
+    fn plan_rendering() {
+        let client = dry_run::Client::new(dry_run::Offline::new("gitlab.host.invalid").unwrap());
+
+        assert!(client.plan().is_empty());
+        assert_eq!(client.plan().to_string(), "no changes would be made\n");
+
+        api::ignore(Dummy).query(&client).unwrap();
+        api::ignore(DummyEdit).query(&client).unwrap();
+
+        let plan = client.plan();
+        assert_eq!(plan.mutations().len(), 1);
+        assert_eq!(
+            plan.to_string(),
+            concat!(
+                "1 change(s) would be made:\n",
+                "   1. PUT https://gitlab.host.invalid/api/v4/projects/simple%2Fproject\n",
+                "    ?sudo = user\n",
+                "    name = new name\n",
+                "    archived = true\n",
+            ),
+        );
+
+        client.clear();
+        assert!(client.requests().is_empty());
+    }
+}
@@ -56,10 +56,10 @@ where
         let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
             v
         } else {
-            return Err(ApiError::server_error(status, rsp.body()));
+            return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
         };
         if !status.is_success() {
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(status, rsp.headers(), v));
         }
 
         serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
@@ -91,10 +91,10 @@ where
         let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
             v
         } else {
-            return Err(ApiError::server_error(status, rsp.body()));
+            return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
         };
         if !status.is_success() {
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(status, rsp.headers(), v));
         }
 
         serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
@@ -187,7 +187,7 @@ mod tests {
     fn test_gitlab_error_detection() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
-            .status(StatusCode::NOT_FOUND)
+            .status(StatusCode::BAD_REQUEST)
             .build()
             .unwrap();
         let client = SingleTestClient::new_json(
@@ -200,9 +200,11 @@ mod tests {
         let res: Result<DummyResult, _> = Dummy.query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
@@ -210,12 +212,38 @@ mod tests {
     }
 
     #[test]
-    fn test_gitlab_error_detection_legacy() {
+    fn test_gitlab_error_not_found() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let res: Result<DummyResult, _> = Dummy.query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_detection_legacy() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
         let client = SingleTestClient::new_json(
             endpoint,
             &json!({
@@ -226,9 +254,11 @@ mod tests {
         let res: Result<DummyResult, _> = Dummy.query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
@@ -236,12 +266,38 @@ mod tests {
     }
 
     #[test]
-    fn test_gitlab_error_detection_unknown() {
+    fn test_gitlab_error_not_found_legacy() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "error": "dummy error message",
+            }),
+        );
+
+        let res: Result<DummyResult, _> = Dummy.query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_detection_unknown() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
         let err_obj = json!({
             "bogus": "dummy error message",
         });
@@ -250,15 +306,41 @@ mod tests {
         let res: Result<DummyResult, _> = Dummy.query(&client);
         let err = res.unwrap_err();
         if let ApiError::GitlabUnrecognized {
+            status,
             obj,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(obj, err_obj);
         } else {
             panic!("unexpected error: {}", err);
         }
     }
 
+    #[test]
+    fn test_gitlab_error_not_found_unknown() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let err_obj = json!({
+            "bogus": "dummy error message",
+        });
+        let client = SingleTestClient::new_json(endpoint, &err_obj);
+
+        let res: Result<DummyResult, _> = Dummy.query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.body(), &err_obj);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
     #[test]
     fn test_bad_deserialization() {
         let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
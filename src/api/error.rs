@@ -5,8 +5,14 @@
 // except according to those terms.
 
 use std::any;
+use std::collections::BTreeMap;
 use std::error::Error;
+use std::fmt;
+use std::io;
+use std::time::Duration;
 
+use http::{header, HeaderMap, StatusCode};
+use serde_json::Value;
 use thiserror::Error;
 
 use crate::api::PaginationError;
@@ -24,6 +30,130 @@ pub enum BodyError {
     },
 }
 
+/// Details of an error response from GitLab.
+#[derive(Debug, Clone)]
+pub struct GitlabError {
+    status: StatusCode,
+    message: Option<String>,
+    field_errors: BTreeMap<String, Vec<String>>,
+    body: Value,
+}
+
+impl GitlabError {
+    fn new(status: StatusCode, body: Value) -> Self {
+        let mut message = None;
+        let mut field_errors = BTreeMap::new();
+
+        match body.get("message").or_else(|| body.get("error")) {
+            Some(Value::String(msg)) => message = Some(msg.clone()),
+            Some(Value::Array(msgs)) => {
+                let msgs = msgs.iter().filter_map(Value::as_str).collect::<Vec<_>>();
+                if !msgs.is_empty() {
+                    message = Some(msgs.join("; "));
+                }
+            },
+            Some(Value::Object(fields)) => {
+                field_errors = fields
+                    .iter()
+                    .map(|(field, errors)| {
+                        let errors = match errors {
+                            Value::String(error) => vec![error.clone()],
+                            Value::Array(errors) => {
+                                errors
+                                    .iter()
+                                    .map(|error| {
+                                        error.as_str().map_or_else(|| error.to_string(), Into::into)
+                                    })
+                                    .collect()
+                            },
+                            error => vec![error.to_string()],
+                        };
+                        (field.clone(), errors)
+                    })
+                    .collect();
+            },
+            _ => (),
+        }
+
+        Self {
+            status,
+            message,
+            field_errors,
+            body,
+        }
+    }
+
+    fn from_text(status: StatusCode, body: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(body);
+        let text = text.trim();
+
+        Self {
+            status,
+            message: if text.is_empty() {
+                None
+            } else {
+                Some(text.into())
+            },
+            field_errors: BTreeMap::new(),
+            body: Value::Null,
+        }
+    }
+
+    /// The HTTP status of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The error message from GitLab, if any.
+    ///
+    /// Lists of messages are joined with `; `.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Validation errors for each field of the request.
+    pub fn field_errors(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.field_errors
+    }
+
+    /// The validation errors for a single field of the request.
+    pub fn field_error(&self, field: &str) -> &[String] {
+        self.field_errors
+            .get(field)
+            .map(AsRef::as_ref)
+            .unwrap_or_default()
+    }
+
+    /// The full response body from GitLab.
+    ///
+    /// This is `null` if the response was not JSON.
+    pub fn body(&self) -> &Value {
+        &self.body
+    }
+}
+
+impl fmt::Display for GitlabError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(msg) = self.message.as_ref() {
+            write!(f, "{}", msg)
+        } else if !self.field_errors.is_empty() {
+            let mut first = true;
+            for (field, errors) in &self.field_errors {
+                for error in errors {
+                    if !first {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} {}", field, error)?;
+                    first = false;
+                }
+            }
+            Ok(())
+        } else {
+            write!(f, "{}", self.status)
+        }
+    }
+}
+
 /// Errors which may occur when using API endpoints.
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -61,6 +191,8 @@ where
     /// GitLab returned an error message.
     #[error("gitlab server error: {}", msg)]
     Gitlab {
+        /// The status code for the return.
+        status: http::StatusCode,
         /// The error message from GitLab.
         msg: String,
     },
@@ -75,15 +207,51 @@ where
     /// GitLab returned an error object.
     #[error("gitlab server error: {:?}", obj)]
     GitlabObject {
+        /// The status code for the return.
+        status: http::StatusCode,
         /// The error object from GitLab.
         obj: serde_json::Value,
     },
     /// GitLab returned an HTTP error with JSON we did not recognize.
     #[error("gitlab server error: {:?}", obj)]
     GitlabUnrecognized {
+        /// The status code for the return.
+        status: http::StatusCode,
         /// The full object from GitLab.
         obj: serde_json::Value,
     },
+    /// The requested resource does not exist or is not visible to the user.
+    #[error("gitlab resource not found: {}", error)]
+    NotFound {
+        /// The error from GitLab.
+        error: GitlabError,
+    },
+    /// The user is not allowed to perform the request.
+    #[error("gitlab request forbidden: {}", error)]
+    Forbidden {
+        /// The error from GitLab.
+        error: GitlabError,
+    },
+    /// The request conflicts with the current state of the resource.
+    #[error("gitlab request conflict: {}", error)]
+    Conflict {
+        /// The error from GitLab.
+        error: GitlabError,
+    },
+    /// Too many requests have been made.
+    #[error("gitlab rate limit exceeded: {}", error)]
+    RateLimited {
+        /// The error from GitLab.
+        error: GitlabError,
+        /// How long to wait before retrying, if GitLab indicated it.
+        retry_after: Option<Duration>,
+    },
+    /// GitLab rejected the values of fields in the request.
+    #[error("gitlab validation failed: {}", error)]
+    Validation {
+        /// The error from GitLab.
+        error: GitlabError,
+    },
     /// Failed to parse an expected data type from JSON.
     #[error("could not parse {} data from JSON: {}", typename, source)]
     DataType {
@@ -99,6 +267,13 @@ where
         #[from]
         source: PaginationError,
     },
+    /// Transferring the response body failed.
+    #[error("failed to transfer the response body: {}", source)]
+    Io {
+        /// The source of the error.
+        #[from]
+        source: io::Error,
+    },
 }
 
 impl<E> ApiError<E>
@@ -144,9 +319,11 @@ where
                 }
             },
             Self::Gitlab {
+                status,
                 msg,
             } => {
                 ApiError::Gitlab {
+                    status,
                     msg,
                 }
             },
@@ -160,19 +337,60 @@ where
                 }
             },
             Self::GitlabObject {
+                status,
                 obj,
             } => {
                 ApiError::GitlabObject {
+                    status,
                     obj,
                 }
             },
             Self::GitlabUnrecognized {
+                status,
                 obj,
             } => {
                 ApiError::GitlabUnrecognized {
+                    status,
                     obj,
                 }
             },
+            Self::NotFound {
+                error,
+            } => {
+                ApiError::NotFound {
+                    error,
+                }
+            },
+            Self::Forbidden {
+                error,
+            } => {
+                ApiError::Forbidden {
+                    error,
+                }
+            },
+            Self::Conflict {
+                error,
+            } => {
+                ApiError::Conflict {
+                    error,
+                }
+            },
+            Self::RateLimited {
+                error,
+                retry_after,
+            } => {
+                ApiError::RateLimited {
+                    error,
+                    retry_after,
+                }
+            },
+            Self::Validation {
+                error,
+            } => {
+                ApiError::Validation {
+                    error,
+                }
+            },
             Self::DataType {
                 source,
                 typename,
@@ -189,17 +407,109 @@ where
                     source,
                 }
             },
+            Self::Io {
+                source,
+            } => {
+                ApiError::Io {
+                    source,
+                }
+            },
+        }
+    }
+
+    /// The HTTP status of the response from GitLab, if the error came from one.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Gitlab {
+                status, ..
+            }
+            | Self::GitlabService {
+                status, ..
+            }
+            | Self::GitlabObject {
+                status, ..
+            }
+            | Self::GitlabUnrecognized {
+                status, ..
+            } => Some(*status),
+            _ => self.gitlab_error().map(GitlabError::status),
+        }
+    }
+
+    /// The structured error from GitLab, if available.
+    pub fn gitlab_error(&self) -> Option<&GitlabError> {
+        match self {
+            Self::NotFound {
+                error,
+            }
+            | Self::Forbidden {
+                error,
+            }
+            | Self::Conflict {
+                error,
+            }
+            | Self::RateLimited {
+                error, ..
+            }
+            | Self::Validation {
+                error,
+            } => Some(error),
+            _ => None,
         }
     }
 
-    pub(crate) fn server_error(status: http::StatusCode, body: &bytes::Bytes) -> Self {
+    fn rate_limited(error: GitlabError, headers: &HeaderMap) -> Self {
+        let retry_after = headers
+            .get(header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse().ok())
+            .map(Duration::from_secs);
+
+        Self::RateLimited {
+            error,
+            retry_after,
+        }
+    }
+
+    pub(crate) fn server_error(
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &bytes::Bytes,
+    ) -> Self {
+        // Rate limiting responses are plain text rather than JSON.
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Self::rate_limited(GitlabError::from_text(status, body), headers);
+        }
+
         Self::GitlabService {
             status,
             data: body.into_iter().copied().collect(),
         }
     }
 
-    pub(crate) fn from_gitlab(value: serde_json::Value) -> Self {
+    pub(crate) fn from_gitlab(status: StatusCode, headers: &HeaderMap, value: Value) -> Self {
+        match status {
+            StatusCode::FORBIDDEN => {
+                return Self::Forbidden {
+                    error: GitlabError::new(status, value),
+                };
+            },
+            StatusCode::NOT_FOUND => {
+                return Self::NotFound {
+                    error: GitlabError::new(status, value),
+                };
+            },
+            StatusCode::CONFLICT => {
+                return Self::Conflict {
+                    error: GitlabError::new(status, value),
+                };
+            },
+            StatusCode::TOO_MANY_REQUESTS => {
+                return Self::rate_limited(GitlabError::new(status, value), headers);
+            },
+            _ => (),
+        }
+
         let error_value = value
             .pointer("/message")
             .or_else(|| value.pointer("/error"));
@@ -207,15 +517,22 @@ where
         if let Some(error_value) = error_value {
             if let Some(msg) = error_value.as_str() {
                 ApiError::Gitlab {
+                    status,
                     msg: msg.into(),
                 }
+            } else if error_value.is_object() && status.is_client_error() {
+                ApiError::Validation {
+                    error: GitlabError::new(status, value),
+                }
             } else {
                 ApiError::GitlabObject {
+                    status,
                     obj: error_value.clone(),
                 }
             }
         } else {
             ApiError::GitlabUnrecognized {
+                status,
                 obj: value,
             }
         }
@@ -231,6 +548,10 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use http::{header, HeaderMap, HeaderValue, StatusCode};
     use serde_json::json;
     use thiserror::Error;
 
@@ -246,11 +567,15 @@ mod tests {
             "error": "error contents",
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(obj);
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::BAD_REQUEST, &HeaderMap::new(), obj);
+        assert_eq!(err.status(), Some(StatusCode::BAD_REQUEST));
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "error contents");
         } else {
             panic!("unexpected error: {}", err);
@@ -263,17 +588,84 @@ mod tests {
             "message": "error contents",
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(obj);
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::BAD_REQUEST, &HeaderMap::new(), obj);
+        assert_eq!(err.status(), Some(StatusCode::BAD_REQUEST));
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "error contents");
         } else {
             panic!("unexpected error: {}", err);
         }
     }
 
+    #[test]
+    fn gitlab_error_unauthorized() {
+        let obj = json!({
+            "message": "401 Unauthorized",
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::UNAUTHORIZED, &HeaderMap::new(), obj);
+        assert_eq!(err.status(), Some(StatusCode::UNAUTHORIZED));
+        if let ApiError::Gitlab {
+            status,
+            msg,
+        } = err
+        {
+            assert_eq!(status, StatusCode::UNAUTHORIZED);
+            assert_eq!(msg, "401 Unauthorized");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn gitlab_error_unprocessable_entity() {
+        let obj = json!({
+            "message": "Branch already exists",
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::UNPROCESSABLE_ENTITY, &HeaderMap::new(), obj);
+        assert_eq!(err.status(), Some(StatusCode::UNPROCESSABLE_ENTITY));
+        if let ApiError::Gitlab {
+            status,
+            msg,
+        } = err
+        {
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(msg, "Branch already exists");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn gitlab_error_internal_server_error() {
+        let obj = json!({
+            "message": "500 Internal Server Error",
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::INTERNAL_SERVER_ERROR, &HeaderMap::new(), obj);
+        assert_eq!(err.status(), Some(StatusCode::INTERNAL_SERVER_ERROR));
+        if let ApiError::Gitlab {
+            status,
+            msg,
+        } = err
+        {
+            assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+            assert_eq!(msg, "500 Internal Server Error");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
     #[test]
     fn gitlab_error_message_object() {
         let err_obj = json!({
@@ -283,11 +675,15 @@ mod tests {
             "message": err_obj,
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(obj);
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::INTERNAL_SERVER_ERROR, &HeaderMap::new(), obj);
+        assert_eq!(err.status(), Some(StatusCode::INTERNAL_SERVER_ERROR));
         if let ApiError::GitlabObject {
+            status,
             obj,
         } = err
         {
+            assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
             assert_eq!(obj, err_obj);
         } else {
             panic!("unexpected error: {}", err);
@@ -300,14 +696,165 @@ mod tests {
             "some_weird_key": "an even weirder value",
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(err_obj.clone());
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::BAD_REQUEST, &HeaderMap::new(), err_obj.clone());
+        assert_eq!(err.status(), Some(StatusCode::BAD_REQUEST));
         if let ApiError::GitlabUnrecognized {
+            status,
             obj,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(obj, err_obj);
         } else {
             panic!("unexpected error: {}", err);
         }
     }
+
+    #[test]
+    fn gitlab_error_validation() {
+        let obj = json!({
+            "message": {
+                "name": ["has already been taken"],
+                "path": ["has already been taken", "is reserved"],
+            },
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::BAD_REQUEST, &HeaderMap::new(), obj.clone());
+        assert_eq!(err.status(), Some(StatusCode::BAD_REQUEST));
+        if let ApiError::Validation {
+            error,
+        } = err
+        {
+            assert_eq!(error.status(), StatusCode::BAD_REQUEST);
+            assert_eq!(error.message(), None);
+            assert_eq!(error.field_error("name"), ["has already been taken"]);
+            assert_eq!(
+                error.field_error("path"),
+                ["has already been taken", "is reserved"],
+            );
+            assert!(error.field_error("description").is_empty());
+            assert_eq!(error.body(), &obj);
+            assert_eq!(
+                error.to_string(),
+                "name has already been taken, path has already been taken, path is reserved",
+            );
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn gitlab_error_not_found() {
+        let obj = json!({
+            "message": "404 Project Not Found",
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::NOT_FOUND, &HeaderMap::new(), obj);
+        assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("404 Project Not Found"));
+            assert!(error.field_errors().is_empty());
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn gitlab_error_forbidden() {
+        let obj = json!({
+            "message": "403 Forbidden",
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::FORBIDDEN, &HeaderMap::new(), obj);
+        if let ApiError::Forbidden {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("403 Forbidden"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn gitlab_error_conflict() {
+        let obj = json!({
+            "message": [
+                "Another open merge request already exists for this source branch: !1",
+            ],
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::CONFLICT, &HeaderMap::new(), obj);
+        if let ApiError::Conflict {
+            error,
+        } = err
+        {
+            assert_eq!(
+                error.message(),
+                Some("Another open merge request already exists for this source branch: !1"),
+            );
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn gitlab_error_rate_limited() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::RETRY_AFTER, HeaderValue::from_static("60"));
+        let body = Bytes::from_static(b"Retry later\n");
+
+        let err: ApiError<MyError> =
+            ApiError::server_error(StatusCode::TOO_MANY_REQUESTS, &headers, &body);
+        assert_eq!(err.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+        if let ApiError::RateLimited {
+            error,
+            retry_after,
+        } = err
+        {
+            assert_eq!(error.message(), Some("Retry later"));
+            assert_eq!(retry_after, Some(Duration::from_secs(60)));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn gitlab_error_rate_limited_json() {
+        let obj = json!({
+            "message": "429 Too Many Requests",
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::TOO_MANY_REQUESTS, &HeaderMap::new(), obj);
+        if let ApiError::RateLimited {
+            error,
+            retry_after,
+        } = err
+        {
+            assert_eq!(error.message(), Some("429 Too Many Requests"));
+            assert_eq!(retry_after, None);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn gitlab_error_service_status() {
+        let err: ApiError<MyError> = ApiError::server_error(
+            StatusCode::BAD_GATEWAY,
+            &HeaderMap::new(),
+            &Bytes::from_static(b"bad gateway"),
+        );
+        assert_eq!(err.status(), Some(StatusCode::BAD_GATEWAY));
+        assert!(err.gitlab_error().is_none());
+    }
 }
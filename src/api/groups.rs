@@ -11,6 +11,7 @@
 //! These endpoints are used for querying and modifying groups and their resources.
 
 mod create;
+mod delete;
 mod edit;
 mod group;
 mod groups;
@@ -19,9 +20,13 @@ pub mod issues;
 pub mod members;
 pub mod milestones;
 pub mod projects;
+mod restore;
+pub mod runners;
 mod share;
 pub mod subgroups;
+mod transfer;
 mod unshare;
+pub mod variables;
 
 pub use create::BranchProtection;
 pub use create::CreateGroup;
@@ -31,6 +36,10 @@ pub use create::GroupProjectCreationAccessLevel;
 pub use create::SharedRunnersMinutesLimit;
 pub use create::SubgroupCreationAccessLevel;
 
+pub use delete::DeleteGroup;
+pub use delete::DeleteGroupBuilder;
+pub use delete::DeleteGroupBuilderError;
+
 pub use edit::EditGroup;
 pub use edit::EditGroupBuilder;
 pub use edit::EditGroupBuilderError;
@@ -45,10 +54,18 @@ pub use groups::Groups;
 pub use groups::GroupsBuilder;
 pub use groups::GroupsBuilderError;
 
+pub use restore::RestoreGroup;
+pub use restore::RestoreGroupBuilder;
+pub use restore::RestoreGroupBuilderError;
+
 pub use share::ShareGroup;
 pub use share::ShareGroupBuilder;
 pub use share::ShareGroupBuilderError;
 
+pub use transfer::TransferGroup;
+pub use transfer::TransferGroupBuilder;
+pub use transfer::TransferGroupBuilderError;
+
 pub use unshare::UnshareGroup;
 pub use unshare::UnshareGroupBuilder;
 pub use unshare::UnshareGroupBuilderError;
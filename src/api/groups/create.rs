@@ -9,6 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::VisibilityLevel;
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Access levels for creating a project within a group.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -249,6 +250,10 @@ impl<'a> Endpoint for CreateGroup<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateGroup<'a> {
+    type Response = types::Group;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -0,0 +1,132 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete a group.
+///
+/// All projects and subgroups within the group are removed with it. Where the instance delays
+/// deletions, the group stays pending until the delay passes and may be recovered with
+/// `RestoreGroup` in the meantime.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct DeleteGroup<'a> {
+    /// The group to delete.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// The full path of the subgroup (e.g., `parent/subgroup`).
+    ///
+    /// Only subgroups may be removed permanently, and only if this matches the subgroup being
+    /// deleted.
+    #[builder(setter(into), default)]
+    full_path: Option<Cow<'a, str>>,
+    /// Immediately delete a subgroup which has been marked for deletion.
+    #[builder(default)]
+    permanently_remove: Option<bool>,
+}
+
+impl<'a> DeleteGroup<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupBuilder<'a> {
+        DeleteGroupBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteGroup<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}", self.group).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("full_path", self.full_path.as_ref())
+            .push_opt("permanently_remove", self.permanently_remove);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::{DeleteGroup, DeleteGroupBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_necessary() {
+        let err = DeleteGroup::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        DeleteGroup::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/group%2Fsubgroup")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroup::builder()
+            .group("group/subgroup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_permanently_remove() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/group%2Fsubgroup")
+            .add_query_params(&[("permanently_remove", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroup::builder()
+            .group("group/subgroup")
+            .permanently_remove(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_full_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/group%2Fsubgroup")
+            .add_query_params(&[("full_path", "group/subgroup")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroup::builder()
+            .group("group/subgroup")
+            .full_path("group/subgroup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -13,6 +13,7 @@ use crate::api::groups::{
     SubgroupCreationAccessLevel,
 };
 use crate::api::ParamValue;
+use crate::types;
 
 /// Access levels for creating a project within a group.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -188,6 +189,10 @@ impl<'a> Endpoint for EditGroup<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for EditGroup<'a> {
+    type Response = types::Group;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
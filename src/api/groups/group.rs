@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for a specific group on an instance.
 #[derive(Debug, Builder, Clone)]
@@ -56,6 +57,10 @@ impl<'a> Endpoint for Group<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Group<'a> {
+    type Response = types::Group;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::groups::{Group, GroupBuilderError};
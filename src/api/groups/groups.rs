@@ -11,6 +11,7 @@ use derive_builder::Builder;
 use crate::api::common::{AccessLevel, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Keys group results may be ordered by.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -193,6 +194,10 @@ impl<'a> Endpoint for Groups<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Groups<'a> {
+    type Response = Vec<types::Group>;
+}
+
 impl<'a> Pageable for Groups<'a> {
     fn use_keyset_pagination(&self) -> bool {
         self.order_by
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Create a new webhook for a group.
 #[derive(Debug, Builder, Clone)]
@@ -122,6 +123,10 @@ impl<'a> Endpoint for CreateHook<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateHook<'a> {
+    type Response = types::GroupHook;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
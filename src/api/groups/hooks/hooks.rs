@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for webhooks within a group.
 #[derive(Debug, Builder, Clone)]
@@ -34,6 +35,10 @@ impl<'a> Endpoint for Hooks<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Hooks<'a> {
+    type Response = Vec<types::GroupHook>;
+}
+
 impl<'a> Pageable for Hooks<'a> {}
 
 #[cfg(test)]
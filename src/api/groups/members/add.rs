@@ -11,6 +11,7 @@ use itertools::Itertools;
 use crate::api::common::{AccessLevel, NameOrId};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Tasks users may be assigned upon addition to a group.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -128,6 +129,10 @@ impl<'a> Endpoint for AddGroupMember<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for AddGroupMember<'a> {
+    type Response = types::Member;
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
@@ -9,6 +9,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{AccessLevel, NameOrId};
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Edit a member of a group.
 #[derive(Debug, Builder, Clone)]
@@ -55,6 +56,10 @@ impl<'a> Endpoint for EditGroupMember<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for EditGroupMember<'a> {
+    type Response = types::Member;
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
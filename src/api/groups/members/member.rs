@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query a single member of a group.
 #[derive(Debug, Builder, Clone)]
@@ -36,6 +37,10 @@ impl<'a> Endpoint for GroupMember<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for GroupMember<'a> {
+    type Response = types::Member;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::groups::members::{GroupMember, GroupMemberBuilderError};
@@ -10,6 +10,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query a members of a group.
 #[derive(Debug, Builder, Clone)]
@@ -73,6 +74,10 @@ impl<'a> Endpoint for GroupMembers<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for GroupMembers<'a> {
+    type Response = Vec<types::Member>;
+}
+
 impl<'a> Pageable for GroupMembers<'a> {}
 
 #[cfg(test)]
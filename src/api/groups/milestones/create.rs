@@ -10,6 +10,7 @@ use chrono::NaiveDate;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Create a new milestone on a group.
 #[derive(Debug, Builder, Clone)]
@@ -62,6 +63,10 @@ impl<'a> Endpoint for CreateGroupMilestone<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateGroupMilestone<'a> {
+    type Response = types::Milestone;
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
@@ -0,0 +1,100 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Query for the issues assigned to a milestone within a group.
+#[derive(Debug, Builder, Clone)]
+pub struct GroupMilestoneIssues<'a> {
+    /// The group the milestone belongs to.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the milestone.
+    milestone: u64,
+}
+
+impl<'a> GroupMilestoneIssues<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupMilestoneIssuesBuilder<'a> {
+        GroupMilestoneIssuesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupMilestoneIssues<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/milestones/{}/issues", self.group, self.milestone,).into()
+    }
+}
+
+impl<'a> TypedEndpoint for GroupMilestoneIssues<'a> {
+    type Response = Vec<types::Issue>;
+}
+
+impl<'a> Pageable for GroupMilestoneIssues<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::milestones::{GroupMilestoneIssues, GroupMilestoneIssuesBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_milestone_are_needed() {
+        let err = GroupMilestoneIssues::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneIssuesBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupMilestoneIssues::builder()
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneIssuesBuilderError, "group");
+    }
+
+    #[test]
+    fn milestone_is_needed() {
+        let err = GroupMilestoneIssues::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneIssuesBuilderError, "milestone");
+    }
+
+    #[test]
+    fn group_and_milestone_are_sufficient() {
+        GroupMilestoneIssues::builder()
+            .group(1)
+            .milestone(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/milestones/1/issues")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestoneIssues::builder()
+            .group("simple/group")
+            .milestone(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -10,6 +10,7 @@
 
 mod projects;
 mod shared;
+mod transfer;
 
 pub use self::projects::GroupProjects;
 pub use self::projects::GroupProjectsBuilder;
@@ -20,3 +21,7 @@ pub use self::shared::SharedGroupProjects;
 pub use self::shared::SharedGroupProjectsBuilder;
 pub use self::shared::SharedGroupProjectsBuilderError;
 pub use self::shared::SharedGroupProjectsOrderBy;
+
+pub use self::transfer::TransferProjectToGroup;
+pub use self::transfer::TransferProjectToGroupBuilder;
+pub use self::transfer::TransferProjectToGroupBuilderError;
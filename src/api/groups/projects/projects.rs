@@ -9,6 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::{AccessLevel, NameOrId, SortOrder, VisibilityLevel};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Keys project results may be ordered by.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -161,6 +162,10 @@ impl<'a> Endpoint for GroupProjects<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for GroupProjects<'a> {
+    type Response = Vec<types::Project>;
+}
+
 impl<'a> Pageable for GroupProjects<'a> {}
 
 #[cfg(test)]
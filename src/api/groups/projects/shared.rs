@@ -9,6 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::{AccessLevel, NameOrId, SortOrder, VisibilityLevel};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Keys project results may be ordered by.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -142,6 +143,10 @@ impl<'a> Endpoint for SharedGroupProjects<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for SharedGroupProjects<'a> {
+    type Response = Vec<types::Project>;
+}
+
 impl<'a> Pageable for SharedGroupProjects<'a> {}
 
 #[cfg(test)]
@@ -0,0 +1,99 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Transfer a project into a group.
+#[derive(Debug, Builder, Clone)]
+pub struct TransferProjectToGroup<'a> {
+    /// The group to transfer the project into.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The project to transfer.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> TransferProjectToGroup<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TransferProjectToGroupBuilder<'a> {
+        TransferProjectToGroupBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for TransferProjectToGroup<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/projects/{}", self.group, self.project).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::projects::{
+        TransferProjectToGroup, TransferProjectToGroupBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_project_are_necessary() {
+        let err = TransferProjectToGroup::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, TransferProjectToGroupBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = TransferProjectToGroup::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TransferProjectToGroupBuilderError, "group");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = TransferProjectToGroup::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TransferProjectToGroupBuilderError, "project");
+    }
+
+    #[test]
+    fn group_and_project_are_sufficient() {
+        TransferProjectToGroup::builder()
+            .group(1)
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/group%2Fsubgroup/projects/simple%2Fproject")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TransferProjectToGroup::builder()
+            .group("group/subgroup")
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
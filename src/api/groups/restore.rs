@@ -0,0 +1,76 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Restore a group which has been marked for deletion.
+#[derive(Debug, Builder, Clone)]
+pub struct RestoreGroup<'a> {
+    /// The group to restore.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> RestoreGroup<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RestoreGroupBuilder<'a> {
+        RestoreGroupBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for RestoreGroup<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/restore", self.group).into()
+    }
+}
+
+impl<'a> TypedEndpoint for RestoreGroup<'a> {
+    type Response = types::Group;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::{RestoreGroup, RestoreGroupBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_necessary() {
+        let err = RestoreGroup::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RestoreGroupBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        RestoreGroup::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/group%2Fsubgroup/restore")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RestoreGroup::builder()
+            .group("group/subgroup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,20 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group runner API endpoints.
+//!
+//! These endpoints are used for querying runners available to groups.
+
+mod reset_registration_token;
+mod runners;
+
+pub use self::reset_registration_token::ResetGroupRunnerRegistrationToken;
+pub use self::reset_registration_token::ResetGroupRunnerRegistrationTokenBuilder;
+pub use self::reset_registration_token::ResetGroupRunnerRegistrationTokenBuilderError;
+
+pub use self::runners::GroupRunners;
+pub use self::runners::GroupRunnersBuilder;
+pub use self::runners::GroupRunnersBuilderError;
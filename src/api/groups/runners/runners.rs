@@ -0,0 +1,199 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{CommaSeparatedList, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::api::runners::{RunnerStatus, RunnerType};
+use crate::types;
+
+/// Query for runners available to a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct GroupRunners<'a> {
+    /// The group to query for runners.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Filter runners by type.
+    #[builder(default)]
+    type_: Option<RunnerType>,
+    /// Filter runners by status.
+    #[builder(default)]
+    status: Option<RunnerStatus>,
+    /// Filter runners by whether they are paused or not.
+    #[builder(default)]
+    paused: Option<bool>,
+    /// Filter runners to those with all of the given tags.
+    #[builder(setter(name = "_tag_list"), default, private)]
+    tag_list: Option<CommaSeparatedList<Cow<'a, str>>>,
+}
+
+impl<'a> GroupRunners<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupRunnersBuilder<'a> {
+        GroupRunnersBuilder::default()
+    }
+}
+
+impl<'a> GroupRunnersBuilder<'a> {
+    /// Filter runners to those with a tag.
+    pub fn tag<T>(&mut self, tag: T) -> &mut Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(tag.into());
+        self
+    }
+
+    /// Filter runners to those with all of a set of tags.
+    pub fn tags<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for GroupRunners<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/runners", self.group).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("type", self.type_)
+            .push_opt("status", self.status)
+            .push_opt("paused", self.paused)
+            .push_opt("tag_list", self.tag_list.as_ref());
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for GroupRunners<'a> {
+    type Response = Vec<types::Runner>;
+}
+
+impl<'a> Pageable for GroupRunners<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::runners::{GroupRunners, GroupRunnersBuilderError};
+    use crate::api::runners::{RunnerStatus, RunnerType};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupRunners::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupRunnersBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupRunners::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/runners")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupRunners::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_type() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/runners")
+            .add_query_params(&[("type", "group_type")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupRunners::builder()
+            .group("simple/group")
+            .type_(RunnerType::Group)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_status() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/runners")
+            .add_query_params(&[("status", "offline")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupRunners::builder()
+            .group("simple/group")
+            .status(RunnerStatus::Offline)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_paused() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/runners")
+            .add_query_params(&[("paused", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupRunners::builder()
+            .group("simple/group")
+            .paused(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_tag_list() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/runners")
+            .add_query_params(&[("tag_list", "linux,docker,gpu")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupRunners::builder()
+            .group("simple/group")
+            .tag("linux")
+            .tags(["docker", "gpu"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
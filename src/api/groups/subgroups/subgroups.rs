@@ -11,6 +11,7 @@ use derive_builder::Builder;
 use crate::api::common::{AccessLevel, NameOrId, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Keys subgroup results may be ordered by.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -152,6 +153,10 @@ impl<'a> Endpoint for GroupSubgroups<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for GroupSubgroups<'a> {
+    type Response = Vec<types::Group>;
+}
+
 impl<'a> Pageable for GroupSubgroups<'a> {}
 
 #[cfg(test)]
@@ -0,0 +1,101 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Get the variable from a group.
+#[derive(Debug, Builder, Clone)]
+pub struct GroupVariable<'a> {
+    /// The group to get the variable from.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The name of the variable.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> GroupVariable<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupVariableBuilder<'a> {
+        GroupVariableBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupVariable<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/variables/{}",
+            self.group,
+            common::path_escaped(self.key.as_ref()),
+        )
+        .into()
+    }
+}
+
+impl<'a> TypedEndpoint for GroupVariable<'a> {
+    type Response = types::Variable;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::variables::{GroupVariable, GroupVariableBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = GroupVariable::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupVariableBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = GroupVariable::builder().key("testkey").build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupVariableBuilderError, "group");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = GroupVariable::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupVariableBuilderError, "key");
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        GroupVariable::builder()
+            .group(1)
+            .key("testkey")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::GET)
+            .endpoint("groups/simple%2Fgroup/variables/testkey%2F")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupVariable::builder()
+            .group("simple/group")
+            .key("testkey/")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
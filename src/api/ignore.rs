@@ -45,9 +45,13 @@ where
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
                 v
             } else {
-                return Err(ApiError::server_error(rsp.status(), rsp.body()));
+                return Err(ApiError::server_error(
+                    rsp.status(),
+                    rsp.headers(),
+                    rsp.body(),
+                ));
             };
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(rsp.status(), rsp.headers(), v));
         }
 
         Ok(())
@@ -78,9 +82,13 @@ where
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
                 v
             } else {
-                return Err(ApiError::server_error(rsp.status(), rsp.body()));
+                return Err(ApiError::server_error(
+                    rsp.status(),
+                    rsp.headers(),
+                    rsp.body(),
+                ));
             };
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(rsp.status(), rsp.headers(), v));
         }
 
         Ok(())
@@ -154,7 +162,7 @@ mod tests {
     fn test_gitlab_error_detection() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
-            .status(StatusCode::NOT_FOUND)
+            .status(StatusCode::BAD_REQUEST)
             .build()
             .unwrap();
         let client = SingleTestClient::new_json(
@@ -166,9 +174,11 @@ mod tests {
 
         let err = api::ignore(Dummy).query(&client).unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
@@ -176,12 +186,37 @@ mod tests {
     }
 
     #[test]
-    fn test_gitlab_error_detection_legacy() {
+    fn test_gitlab_error_not_found() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let err = api::ignore(Dummy).query(&client).unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_detection_legacy() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
         let client = SingleTestClient::new_json(
             endpoint,
             &json!({
@@ -191,9 +226,11 @@ mod tests {
 
         let err = api::ignore(Dummy).query(&client).unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
@@ -201,12 +238,37 @@ mod tests {
     }
 
     #[test]
-    fn test_gitlab_error_detection_unknown() {
+    fn test_gitlab_error_not_found_legacy() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "error": "dummy error message",
+            }),
+        );
+
+        let err = api::ignore(Dummy).query(&client).unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_detection_unknown() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
         let err_obj = json!({
             "bogus": "dummy error message",
         });
@@ -214,12 +276,37 @@ mod tests {
 
         let err = api::ignore(Dummy).query(&client).unwrap_err();
         if let ApiError::GitlabUnrecognized {
+            status,
             obj,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(obj, err_obj);
         } else {
             panic!("unexpected error: {}", err);
         }
     }
+
+    #[test]
+    fn test_gitlab_error_not_found_unknown() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let err_obj = json!({
+            "bogus": "dummy error message",
+        });
+        let client = SingleTestClient::new_json(endpoint, &err_obj);
+
+        let err = api::ignore(Dummy).query(&client).unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.body(), &err_obj);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
 }
@@ -13,8 +13,9 @@ use http::Method;
 use crate::api::{
     common::{CommaSeparatedList, NameOrId, SortOrder},
     helpers::{Labels, Milestone, ReactionEmoji},
-    Endpoint, Pageable, QueryParams,
+    Endpoint, Pageable, QueryParams, TypedEndpoint,
 };
+use crate::types;
 
 use super::{
     Assignee, IssueDueDateFilter, IssueIteration, IssueOrderBy, IssueScope, IssueSearchScope,
@@ -328,6 +329,10 @@ impl<'a> Endpoint for GroupIssues<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for GroupIssues<'a> {
+    type Response = Vec<types::Issue>;
+}
+
 impl<'a> Pageable for GroupIssues<'a> {}
 
 #[cfg(test)]
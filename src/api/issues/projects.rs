@@ -14,8 +14,9 @@ use crate::api::{
     common::{CommaSeparatedList, NameOrId, SortOrder},
     helpers::{Labels, Milestone, ReactionEmoji},
     issues::IssueType,
-    Endpoint, Pageable, QueryParams,
+    Endpoint, Pageable, QueryParams, TypedEndpoint,
 };
+use crate::types;
 
 use super::{
     Assignee, IssueDueDateFilter, IssueIteration, IssueOrderBy, IssueScope, IssueSearchScope,
@@ -334,6 +335,10 @@ impl<'a> Endpoint for ProjectIssues<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for ProjectIssues<'a> {
+    type Response = Vec<types::Issue>;
+}
+
 impl<'a> Pageable for ProjectIssues<'a> {}
 
 #[cfg(test)]
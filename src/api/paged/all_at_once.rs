@@ -13,6 +13,7 @@ use serde::de::DeserializeOwned;
 use crate::api::paged::link_header;
 use crate::api::{
     query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Pageable, Pagination, Query,
+    TypedEndpoint,
 };
 
 /// A query modifier that paginates an endpoint.
@@ -30,6 +31,14 @@ pub fn paged<E>(endpoint: E, pagination: Pagination) -> Paged<E> {
     }
 }
 
+impl<E, T> TypedEndpoint for Paged<E>
+where
+    E: TypedEndpoint<Response = Vec<T>>,
+    T: DeserializeOwned + Send + 'static,
+{
+    type Response = Vec<T>;
+}
+
 impl<E, T, C> Query<Vec<T>, C> for Paged<E>
 where
     E: Endpoint,
@@ -107,10 +116,10 @@ where
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
                 v
             } else {
-                return Err(ApiError::server_error(status, rsp.body()));
+                return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
             };
             if !status.is_success() {
-                return Err(ApiError::from_gitlab(v));
+                return Err(ApiError::from_gitlab(status, rsp.headers(), v));
             }
 
             let page =
@@ -230,7 +239,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .endpoint("paged_dummy")
             .add_query_params(&[("page", "1"), ("per_page", "100")])
-            .status(StatusCode::NOT_FOUND)
+            .status(StatusCode::BAD_REQUEST)
             .build()
             .unwrap();
         let client = SingleTestClient::new_json(
@@ -244,9 +253,11 @@ mod tests {
         let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All).query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
@@ -254,13 +265,41 @@ mod tests {
     }
 
     #[test]
-    fn test_gitlab_error_detection_legacy() {
+    fn test_gitlab_error_not_found() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("paged_dummy")
             .add_query_params(&[("page", "1"), ("per_page", "100")])
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+        let endpoint = Dummy::default();
+
+        let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_detection_legacy() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "1"), ("per_page", "100")])
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
         let client = SingleTestClient::new_json(
             endpoint,
             &json!({
@@ -272,9 +311,11 @@ mod tests {
         let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All).query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
@@ -282,13 +323,41 @@ mod tests {
     }
 
     #[test]
-    fn test_gitlab_error_detection_unknown() {
+    fn test_gitlab_error_not_found_legacy() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("paged_dummy")
             .add_query_params(&[("page", "1"), ("per_page", "100")])
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "error": "dummy error message",
+            }),
+        );
+        let endpoint = Dummy::default();
+
+        let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_detection_unknown() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "1"), ("per_page", "100")])
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
         let err_obj = json!({
             "bogus": "dummy error message",
         });
@@ -298,15 +367,43 @@ mod tests {
         let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All).query(&client);
         let err = res.unwrap_err();
         if let ApiError::GitlabUnrecognized {
+            status,
             obj,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(obj, err_obj);
         } else {
             panic!("unexpected error: {}", err);
         }
     }
 
+    #[test]
+    fn test_gitlab_error_not_found_unknown() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "1"), ("per_page", "100")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let err_obj = json!({
+            "bogus": "dummy error message",
+        });
+        let client = SingleTestClient::new_json(endpoint, &err_obj);
+        let endpoint = Dummy::default();
+
+        let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.body(), &err_obj);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
     #[test]
     fn test_pagination_limit() {
         let endpoint = ExpectedUrl::builder()
@@ -230,10 +230,10 @@ where
         let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
             v
         } else {
-            return Err(ApiError::server_error(status, rsp.body()));
+            return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
         };
         if !status.is_success() {
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(status, rsp.headers(), v));
         }
 
         let page = serde_json::from_value::<Vec<T>>(v).map_err(ApiError::data_type::<Vec<T>>)?;
@@ -501,7 +501,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .endpoint("paged_dummy")
             .add_query_params(&[("page", "1"), ("per_page", "100")])
-            .status(StatusCode::NOT_FOUND)
+            .status(StatusCode::BAD_REQUEST)
             .build()
             .unwrap();
         let client = SingleTestClient::new_json(
@@ -517,21 +517,53 @@ mod tests {
             .collect();
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
         }
     }
 
+    #[test]
+    fn test_gitlab_error_not_found() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "1"), ("per_page", "100")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+        let endpoint = Dummy::default();
+
+        let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All)
+            .iter(&client)
+            .collect();
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
     #[tokio::test]
     async fn test_gitlab_error_detection_async() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("paged_dummy")
             .add_query_params(&[("page", "1"), ("per_page", "100")])
-            .status(StatusCode::NOT_FOUND)
+            .status(StatusCode::BAD_REQUEST)
             .build()
             .unwrap();
         let client = SingleTestClient::new_json(
@@ -548,21 +580,54 @@ mod tests {
             .await;
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
         }
     }
 
+    #[tokio::test]
+    async fn test_gitlab_error_not_found_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "1"), ("per_page", "100")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+        let endpoint = Dummy::default();
+
+        let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All)
+            .iter_async(&client)
+            .try_collect()
+            .await;
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
     #[test]
     fn test_gitlab_error_detection_legacy() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("paged_dummy")
             .add_query_params(&[("page", "1"), ("per_page", "100")])
-            .status(StatusCode::NOT_FOUND)
+            .status(StatusCode::BAD_REQUEST)
             .build()
             .unwrap();
         let client = SingleTestClient::new_json(
@@ -578,21 +643,53 @@ mod tests {
             .collect();
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
         }
     }
 
+    #[test]
+    fn test_gitlab_error_not_found_legacy() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "1"), ("per_page", "100")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "error": "dummy error message",
+            }),
+        );
+        let endpoint = Dummy::default();
+
+        let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All)
+            .iter(&client)
+            .collect();
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
     #[tokio::test]
     async fn test_gitlab_error_detection_legacy_async() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("paged_dummy")
             .add_query_params(&[("page", "1"), ("per_page", "100")])
-            .status(StatusCode::NOT_FOUND)
+            .status(StatusCode::BAD_REQUEST)
             .build()
             .unwrap();
         let client = SingleTestClient::new_json(
@@ -609,21 +706,54 @@ mod tests {
             .await;
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
         }
     }
 
+    #[tokio::test]
+    async fn test_gitlab_error_not_found_legacy_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "1"), ("per_page", "100")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "error": "dummy error message",
+            }),
+        );
+        let endpoint = Dummy::default();
+
+        let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All)
+            .iter_async(&client)
+            .try_collect()
+            .await;
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
     #[test]
     fn test_gitlab_error_detection_unknown() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("paged_dummy")
             .add_query_params(&[("page", "1"), ("per_page", "100")])
-            .status(StatusCode::NOT_FOUND)
+            .status(StatusCode::BAD_REQUEST)
             .build()
             .unwrap();
         let err_obj = json!({
@@ -637,21 +767,51 @@ mod tests {
             .collect();
         let err = res.unwrap_err();
         if let ApiError::GitlabUnrecognized {
+            status,
             obj,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(obj, err_obj);
         } else {
             panic!("unexpected error: {}", err);
         }
     }
 
+    #[test]
+    fn test_gitlab_error_not_found_unknown() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "1"), ("per_page", "100")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let err_obj = json!({
+            "bogus": "dummy error message",
+        });
+        let client = SingleTestClient::new_json(endpoint, &err_obj);
+        let endpoint = Dummy::default();
+
+        let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All)
+            .iter(&client)
+            .collect();
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.body(), &err_obj);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
     #[tokio::test]
     async fn test_gitlab_error_detection_unknown_async() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("paged_dummy")
             .add_query_params(&[("page", "1"), ("per_page", "100")])
-            .status(StatusCode::NOT_FOUND)
+            .status(StatusCode::BAD_REQUEST)
             .build()
             .unwrap();
         let err_obj = json!({
@@ -666,15 +826,46 @@ mod tests {
             .await;
         let err = res.unwrap_err();
         if let ApiError::GitlabUnrecognized {
+            status,
             obj,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(obj, err_obj);
         } else {
             panic!("unexpected error: {}", err);
         }
     }
 
+    #[tokio::test]
+    async fn test_gitlab_error_not_found_unknown_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "1"), ("per_page", "100")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let err_obj = json!({
+            "bogus": "dummy error message",
+        });
+        let client = SingleTestClient::new_json(endpoint, &err_obj);
+        let endpoint = Dummy::default();
+
+        let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All)
+            .iter_async(&client)
+            .try_collect()
+            .await;
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.body(), &err_obj);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
     #[test]
     fn test_pagination_limit() {
         let endpoint = ExpectedUrl::builder()
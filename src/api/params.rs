@@ -191,6 +191,13 @@ impl<'a> QueryParams<'a> {
         let mut pairs = url.query_pairs_mut();
         pairs.extend_pairs(self.params.iter());
     }
+
+    /// Iterate over the parameters.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params
+            .iter()
+            .map(|(key, value)| (key.as_ref(), value.as_ref()))
+    }
 }
 
 #[cfg(test)]
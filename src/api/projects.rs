@@ -10,10 +10,15 @@
 //!
 //! These endpoints are used for querying and modifying projects and their resources.
 
+mod archive;
+pub mod ci;
 mod create;
+mod delete;
 pub mod deploy_keys;
+pub mod deployments;
 mod edit;
 pub mod environments;
+pub mod forks;
 pub mod hooks;
 pub mod issues;
 pub mod jobs;
@@ -22,6 +27,7 @@ pub mod members;
 pub mod merge_requests;
 pub mod merge_trains;
 pub mod milestones;
+pub mod pipeline_schedules;
 pub mod pipelines;
 mod project;
 mod projects;
@@ -30,9 +36,19 @@ pub mod protected_tags;
 pub mod registry;
 pub mod releases;
 pub mod repository;
+mod restore;
+pub mod runners;
 mod share;
+mod transfer;
+pub mod triggers;
+mod unarchive;
 mod unshare;
 pub mod variables;
+mod wait;
+
+pub use self::archive::ArchiveProject;
+pub use self::archive::ArchiveProjectBuilder;
+pub use self::archive::ArchiveProjectBuilderError;
 
 pub use self::create::AutoDevOpsDeployStrategy;
 pub use self::create::BuildGitStrategy;
@@ -50,6 +66,10 @@ pub use self::create::FeatureAccessLevelPublic;
 pub use self::create::MergeMethod;
 pub use self::create::SquashOption;
 
+pub use self::delete::DeleteProject;
+pub use self::delete::DeleteProjectBuilder;
+pub use self::delete::DeleteProjectBuilderError;
+
 pub use self::edit::EditProject;
 pub use self::edit::EditProjectBuilder;
 pub use self::edit::EditProjectBuilderError;
@@ -63,10 +83,24 @@ pub use self::projects::Projects;
 pub use self::projects::ProjectsBuilder;
 pub use self::projects::ProjectsBuilderError;
 
+pub use self::restore::RestoreProject;
+pub use self::restore::RestoreProjectBuilder;
+pub use self::restore::RestoreProjectBuilderError;
+
 pub use self::share::ShareProject;
 pub use self::share::ShareProjectBuilder;
 pub use self::share::ShareProjectBuilderError;
 
+pub use self::transfer::TransferProject;
+pub use self::transfer::TransferProjectBuilder;
+pub use self::transfer::TransferProjectBuilderError;
+
+pub use self::unarchive::UnarchiveProject;
+pub use self::unarchive::UnarchiveProjectBuilder;
+pub use self::unarchive::UnarchiveProjectBuilderError;
+
 pub use self::unshare::UnshareProject;
 pub use self::unshare::UnshareProjectBuilder;
 pub use self::unshare::UnshareProjectBuilderError;
+
+pub use self::wait::WaitError;
@@ -0,0 +1,20 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project CI API endpoints.
+//!
+//! These endpoints are used for validating CI configurations.
+
+mod lint;
+mod project_lint;
+
+pub use self::lint::CiLint;
+pub use self::lint::CiLintBuilder;
+pub use self::lint::CiLintBuilderError;
+
+pub use self::project_lint::ProjectCiLint;
+pub use self::project_lint::ProjectCiLintBuilder;
+pub use self::project_lint::ProjectCiLintBuilderError;
@@ -0,0 +1,179 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Validate CI configuration content within the context of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CiLint<'a> {
+    /// The project to validate the configuration in.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The CI configuration content.
+    #[builder(setter(into))]
+    content: Cow<'a, str>,
+
+    /// Simulate pipeline creation rather than only performing static checks.
+    #[builder(default)]
+    dry_run: Option<bool>,
+    /// Include the jobs which would be created in the result.
+    #[builder(default)]
+    include_jobs: Option<bool>,
+    /// The ref to use when simulating pipeline creation.
+    ///
+    /// Defaults to the default branch of the project.
+    #[builder(setter(into), default)]
+    ref_: Option<Cow<'a, str>>,
+}
+
+impl<'a> CiLint<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CiLintBuilder<'a> {
+        CiLintBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CiLint<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/ci/lint", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("content", &self.content)
+            .push_opt("dry_run", self.dry_run)
+            .push_opt("include_jobs", self.include_jobs)
+            .push_opt("ref", self.ref_.as_ref());
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for CiLint<'a> {
+    type Response = types::CiLintResult;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::ci::{CiLint, CiLintBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_content_are_needed() {
+        let err = CiLint::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CiLintBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CiLint::builder().content("").build().unwrap_err();
+        crate::test::assert_missing_field!(err, CiLintBuilderError, "project");
+    }
+
+    #[test]
+    fn content_is_needed() {
+        let err = CiLint::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CiLintBuilderError, "content");
+    }
+
+    #[test]
+    fn project_and_content_are_sufficient() {
+        CiLint::builder().project(1).content("").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("content=job%3A+%7B%7D")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CiLint::builder()
+            .project("simple/project")
+            .content("job: {}")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_dry_run() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("content=job%3A+%7B%7D", "&dry_run=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CiLint::builder()
+            .project("simple/project")
+            .content("job: {}")
+            .dry_run(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_jobs() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("content=job%3A+%7B%7D", "&include_jobs=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CiLint::builder()
+            .project("simple/project")
+            .content("job: {}")
+            .include_jobs(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_ref() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("content=job%3A+%7B%7D", "&ref=main"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CiLint::builder()
+            .project("simple/project")
+            .content("job: {}")
+            .ref_("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
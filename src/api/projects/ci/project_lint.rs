@@ -0,0 +1,171 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Validate the existing CI configuration of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ProjectCiLint<'a> {
+    /// The project to validate the configuration of.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The commit to read the configuration from.
+    ///
+    /// Defaults to the head of the default branch of the project.
+    #[builder(setter(into), default)]
+    sha: Option<Cow<'a, str>>,
+    /// Simulate pipeline creation rather than only performing static checks.
+    #[builder(default)]
+    dry_run: Option<bool>,
+    /// Include the jobs which would be created in the result.
+    #[builder(default)]
+    include_jobs: Option<bool>,
+    /// The ref to use when simulating pipeline creation.
+    ///
+    /// Defaults to the default branch of the project.
+    #[builder(setter(into), default)]
+    ref_: Option<Cow<'a, str>>,
+}
+
+impl<'a> ProjectCiLint<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectCiLintBuilder<'a> {
+        ProjectCiLintBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectCiLint<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/ci/lint", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("sha", self.sha.as_ref())
+            .push_opt("dry_run", self.dry_run)
+            .push_opt("include_jobs", self.include_jobs)
+            .push_opt("ref", self.ref_.as_ref());
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for ProjectCiLint<'a> {
+    type Response = types::CiLintResult;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::ci::{ProjectCiLint, ProjectCiLintBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectCiLint::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectCiLintBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectCiLint::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectCiLint::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sha() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .add_query_params(&[("sha", "0000000000000000000000000000000000000000")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectCiLint::builder()
+            .project("simple/project")
+            .sha("0000000000000000000000000000000000000000")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_dry_run() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .add_query_params(&[("dry_run", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectCiLint::builder()
+            .project("simple/project")
+            .dry_run(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_jobs() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .add_query_params(&[("include_jobs", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectCiLint::builder()
+            .project("simple/project")
+            .include_jobs(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_ref() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .add_query_params(&[("ref", "main")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectCiLint::builder()
+            .project("simple/project")
+            .ref_("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
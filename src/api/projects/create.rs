@@ -12,6 +12,7 @@ use derive_builder::Builder;
 use crate::api::common::{EnableState, VisibilityLevel};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Access levels available for most features.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -968,6 +969,10 @@ impl<'a> Endpoint for CreateProject<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateProject<'a> {
+    type Response = types::Project;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
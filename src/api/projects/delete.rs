@@ -0,0 +1,131 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete a project.
+///
+/// Depending on the instance's settings, the project may only be scheduled for removal after a
+/// retention period. A scheduled deletion may be cancelled using `RestoreProject`.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct DeleteProject<'a> {
+    /// The project to delete.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The full path of the project (e.g., `group/project`).
+    ///
+    /// GitLab requires this to match the project when `permanently_remove` is used so that the
+    /// wrong project is not removed by mistake.
+    #[builder(setter(into), default)]
+    full_path: Option<Cow<'a, str>>,
+    /// Immediately delete a project which has been marked for deletion.
+    #[builder(default)]
+    permanently_remove: Option<bool>,
+}
+
+impl<'a> DeleteProject<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteProjectBuilder<'a> {
+        DeleteProjectBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteProject<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("full_path", self.full_path.as_ref())
+            .push_opt("permanently_remove", self.permanently_remove);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::{DeleteProject, DeleteProjectBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteProject::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        DeleteProject::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteProject::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_full_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject")
+            .add_query_params(&[("full_path", "simple/project")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteProject::builder()
+            .project("simple/project")
+            .full_path("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_permanently_remove() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject")
+            .add_query_params(&[("permanently_remove", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteProject::builder()
+            .project("simple/project")
+            .permanently_remove(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,38 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project deployments API endpoints.
+//!
+//! These endpoints are used for querying and recording deployments.
+
+mod approve;
+mod create;
+mod deployment;
+mod deployments;
+mod update;
+
+pub use self::approve::ApproveDeployment;
+pub use self::approve::ApproveDeploymentBuilder;
+pub use self::approve::ApproveDeploymentBuilderError;
+pub use self::approve::DeploymentApprovalStatus;
+
+pub use self::create::CreateDeployment;
+pub use self::create::CreateDeploymentBuilder;
+pub use self::create::CreateDeploymentBuilderError;
+
+pub use self::deployment::Deployment;
+pub use self::deployment::DeploymentBuilder;
+pub use self::deployment::DeploymentBuilderError;
+
+pub use self::deployments::DeploymentOrderBy;
+pub use self::deployments::DeploymentStatus;
+pub use self::deployments::Deployments;
+pub use self::deployments::DeploymentsBuilder;
+pub use self::deployments::DeploymentsBuilderError;
+
+pub use self::update::UpdateDeployment;
+pub use self::update::UpdateDeploymentBuilder;
+pub use self::update::UpdateDeploymentBuilderError;
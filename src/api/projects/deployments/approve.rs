@@ -0,0 +1,219 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+use crate::types;
+
+/// Statuses for approving a deployment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeploymentApprovalStatus {
+    /// Approve the deployment.
+    Approved,
+    /// Reject the deployment.
+    Rejected,
+}
+
+impl DeploymentApprovalStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            DeploymentApprovalStatus::Approved => "approved",
+            DeploymentApprovalStatus::Rejected => "rejected",
+        }
+    }
+}
+
+impl ParamValue<'static> for DeploymentApprovalStatus {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Approve or reject a blocked deployment to a protected environment.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ApproveDeployment<'a> {
+    /// The project of the deployment.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the deployment.
+    deployment: u64,
+    /// Whether to approve or reject the deployment.
+    status: DeploymentApprovalStatus,
+
+    /// A comment to go with the approval.
+    #[builder(setter(into), default)]
+    comment: Option<Cow<'a, str>>,
+    /// The name of the user or group to approve as.
+    ///
+    /// Required if the user belongs to multiple approval rules.
+    #[builder(setter(into), default)]
+    represented_as: Option<Cow<'a, str>>,
+}
+
+impl<'a> ApproveDeployment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ApproveDeploymentBuilder<'a> {
+        ApproveDeploymentBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ApproveDeployment<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/deployments/{}/approval",
+            self.project, self.deployment,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("status", self.status)
+            .push_opt("comment", self.comment.as_ref())
+            .push_opt("represented_as", self.represented_as.as_ref());
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for ApproveDeployment<'a> {
+    type Response = types::DeploymentApproval;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::deployments::{
+        ApproveDeployment, ApproveDeploymentBuilderError, DeploymentApprovalStatus,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn deployment_approval_status_as_str() {
+        let items = &[
+            (DeploymentApprovalStatus::Approved, "approved"),
+            (DeploymentApprovalStatus::Rejected, "rejected"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ApproveDeployment::builder()
+            .deployment(1)
+            .status(DeploymentApprovalStatus::Approved)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ApproveDeploymentBuilderError, "project");
+    }
+
+    #[test]
+    fn deployment_is_needed() {
+        let err = ApproveDeployment::builder()
+            .project(1)
+            .status(DeploymentApprovalStatus::Approved)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ApproveDeploymentBuilderError, "deployment");
+    }
+
+    #[test]
+    fn status_is_needed() {
+        let err = ApproveDeployment::builder()
+            .project(1)
+            .deployment(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ApproveDeploymentBuilderError, "status");
+    }
+
+    #[test]
+    fn project_deployment_and_status_are_sufficient() {
+        ApproveDeployment::builder()
+            .project(1)
+            .deployment(1)
+            .status(DeploymentApprovalStatus::Approved)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/deployments/1/approval")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("status=approved")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ApproveDeployment::builder()
+            .project("simple/project")
+            .deployment(1)
+            .status(DeploymentApprovalStatus::Approved)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_comment() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/deployments/1/approval")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("status=rejected", "&comment=not+yet"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ApproveDeployment::builder()
+            .project("simple/project")
+            .deployment(1)
+            .status(DeploymentApprovalStatus::Rejected)
+            .comment("not yet")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_represented_as() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/deployments/1/approval")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("status=approved", "&represented_as=maintainers"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ApproveDeployment::builder()
+            .project("simple/project")
+            .deployment(1)
+            .status(DeploymentApprovalStatus::Approved)
+            .represented_as("maintainers")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,180 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::deployments::DeploymentStatus;
+use crate::types;
+
+/// Create a deployment within a project.
+///
+/// This records a deployment performed outside of GitLab CI.
+#[derive(Debug, Builder, Clone)]
+pub struct CreateDeployment<'a> {
+    /// The project to create the deployment within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the environment deployed to.
+    ///
+    /// The environment is created if it does not exist.
+    #[builder(setter(into))]
+    environment: Cow<'a, str>,
+    /// The commit that was deployed.
+    #[builder(setter(into))]
+    sha: Cow<'a, str>,
+    /// The name of the branch or tag that was deployed.
+    #[builder(setter(into))]
+    ref_: Cow<'a, str>,
+    /// Whether `ref_` is a tag or not.
+    tag: bool,
+    /// The status of the deployment.
+    ///
+    /// Only `running`, `success`, `failed`, and `canceled` are accepted.
+    status: DeploymentStatus,
+}
+
+impl<'a> CreateDeployment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateDeploymentBuilder<'a> {
+        CreateDeploymentBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateDeployment<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/deployments", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("environment", &self.environment)
+            .push("sha", &self.sha)
+            .push("ref", &self.ref_)
+            .push("tag", self.tag)
+            .push("status", self.status);
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for CreateDeployment<'a> {
+    type Response = types::Deployment;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::deployments::{
+        CreateDeployment, CreateDeploymentBuilder, CreateDeploymentBuilderError, DeploymentStatus,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    fn builder() -> CreateDeploymentBuilder<'static> {
+        let mut builder = CreateDeployment::builder();
+        builder
+            .project(1)
+            .environment("production")
+            .sha("0000000000000000000000000000000000000000")
+            .ref_("main")
+            .tag(false)
+            .status(DeploymentStatus::Success);
+        builder
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateDeployment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateDeploymentBuilderError, "project");
+    }
+
+    #[test]
+    fn environment_is_needed() {
+        let err = CreateDeployment::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateDeploymentBuilderError, "environment");
+    }
+
+    #[test]
+    fn sha_is_needed() {
+        let err = CreateDeployment::builder()
+            .project(1)
+            .environment("production")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateDeploymentBuilderError, "sha");
+    }
+
+    #[test]
+    fn ref_is_needed() {
+        let err = CreateDeployment::builder()
+            .project(1)
+            .environment("production")
+            .sha("0000000000000000000000000000000000000000")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateDeploymentBuilderError, "ref_");
+    }
+
+    #[test]
+    fn tag_is_needed() {
+        let err = CreateDeployment::builder()
+            .project(1)
+            .environment("production")
+            .sha("0000000000000000000000000000000000000000")
+            .ref_("main")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateDeploymentBuilderError, "tag");
+    }
+
+    #[test]
+    fn status_is_needed() {
+        let err = CreateDeployment::builder()
+            .project(1)
+            .environment("production")
+            .sha("0000000000000000000000000000000000000000")
+            .ref_("main")
+            .tag(false)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateDeploymentBuilderError, "status");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/deployments")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "environment=production",
+                "&sha=0000000000000000000000000000000000000000",
+                "&ref=main",
+                "&tag=false",
+                "&status=success",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = builder().project("simple/project").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
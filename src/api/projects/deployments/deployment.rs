@@ -0,0 +1,92 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Query for a deployment within a project.
+#[derive(Debug, Builder, Clone)]
+pub struct Deployment<'a> {
+    /// The project to query for the deployment.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the deployment.
+    deployment: u64,
+}
+
+impl<'a> Deployment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeploymentBuilder<'a> {
+        DeploymentBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Deployment<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/deployments/{}", self.project, self.deployment).into()
+    }
+}
+
+impl<'a> TypedEndpoint for Deployment<'a> {
+    type Response = types::Deployment;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::deployments::{Deployment, DeploymentBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_deployment_are_needed() {
+        let err = Deployment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeploymentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = Deployment::builder().deployment(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeploymentBuilderError, "project");
+    }
+
+    #[test]
+    fn deployment_is_needed() {
+        let err = Deployment::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeploymentBuilderError, "deployment");
+    }
+
+    #[test]
+    fn project_and_deployment_are_sufficient() {
+        Deployment::builder()
+            .project(1)
+            .deployment(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/deployments/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Deployment::builder()
+            .project("simple/project")
+            .deployment(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,381 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{NameOrId, SortOrder};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+use crate::types;
+
+/// Statuses of deployments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeploymentStatus {
+    /// The deployment has been created.
+    Created,
+    /// The deployment is running.
+    Running,
+    /// The deployment succeeded.
+    Success,
+    /// The deployment failed.
+    Failed,
+    /// The deployment was canceled.
+    Canceled,
+    /// The deployment is waiting for approval.
+    Blocked,
+}
+
+impl DeploymentStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            DeploymentStatus::Created => "created",
+            DeploymentStatus::Running => "running",
+            DeploymentStatus::Success => "success",
+            DeploymentStatus::Failed => "failed",
+            DeploymentStatus::Canceled => "canceled",
+            DeploymentStatus::Blocked => "blocked",
+        }
+    }
+}
+
+impl ParamValue<'static> for DeploymentStatus {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Keys deployment results may be ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeploymentOrderBy {
+    /// Order by the ID of the deployment.
+    Id,
+    /// Order by the IID of the deployment.
+    Iid,
+    /// Order by the creation date of the deployment.
+    CreatedAt,
+    /// Order by the last updated date of the deployment.
+    UpdatedAt,
+    /// Order by the completion date of the deployment.
+    FinishedAt,
+    /// Order by the ref of the deployment.
+    Ref,
+}
+
+impl Default for DeploymentOrderBy {
+    fn default() -> Self {
+        DeploymentOrderBy::Id
+    }
+}
+
+impl DeploymentOrderBy {
+    fn as_str(self) -> &'static str {
+        match self {
+            DeploymentOrderBy::Id => "id",
+            DeploymentOrderBy::Iid => "iid",
+            DeploymentOrderBy::CreatedAt => "created_at",
+            DeploymentOrderBy::UpdatedAt => "updated_at",
+            DeploymentOrderBy::FinishedAt => "finished_at",
+            DeploymentOrderBy::Ref => "ref",
+        }
+    }
+}
+
+impl ParamValue<'static> for DeploymentOrderBy {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for deployments within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Deployments<'a> {
+    /// The project to query for deployments.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Order results by a given key.
+    #[builder(default)]
+    order_by: Option<DeploymentOrderBy>,
+    /// The sort order for returned results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+    /// Only return deployments updated after this time.
+    #[builder(default)]
+    updated_after: Option<DateTime<Utc>>,
+    /// Only return deployments updated before this time.
+    #[builder(default)]
+    updated_before: Option<DateTime<Utc>>,
+    /// Only return deployments finished after this time.
+    ///
+    /// Requires ordering by `finished_at` and filtering by the `success` status.
+    #[builder(default)]
+    finished_after: Option<DateTime<Utc>>,
+    /// Only return deployments finished before this time.
+    ///
+    /// Requires ordering by `finished_at` and filtering by the `success` status.
+    #[builder(default)]
+    finished_before: Option<DateTime<Utc>>,
+    /// Filter deployments by the name of their environment.
+    #[builder(setter(into), default)]
+    environment: Option<Cow<'a, str>>,
+    /// Filter deployments by their status.
+    #[builder(default)]
+    status: Option<DeploymentStatus>,
+}
+
+impl<'a> Deployments<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeploymentsBuilder<'a> {
+        DeploymentsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Deployments<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/deployments", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort)
+            .push_opt("updated_after", self.updated_after)
+            .push_opt("updated_before", self.updated_before)
+            .push_opt("finished_after", self.finished_after)
+            .push_opt("finished_before", self.finished_before)
+            .push_opt("environment", self.environment.as_ref())
+            .push_opt("status", self.status);
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for Deployments<'a> {
+    type Response = Vec<types::Deployment>;
+}
+
+impl<'a> Pageable for Deployments<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::common::SortOrder;
+    use crate::api::projects::deployments::{
+        DeploymentOrderBy, DeploymentStatus, Deployments, DeploymentsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn deployment_status_as_str() {
+        let items = &[
+            (DeploymentStatus::Created, "created"),
+            (DeploymentStatus::Running, "running"),
+            (DeploymentStatus::Success, "success"),
+            (DeploymentStatus::Failed, "failed"),
+            (DeploymentStatus::Canceled, "canceled"),
+            (DeploymentStatus::Blocked, "blocked"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn order_by_default() {
+        assert_eq!(DeploymentOrderBy::default(), DeploymentOrderBy::Id);
+    }
+
+    #[test]
+    fn order_by_as_str() {
+        let items = &[
+            (DeploymentOrderBy::Id, "id"),
+            (DeploymentOrderBy::Iid, "iid"),
+            (DeploymentOrderBy::CreatedAt, "created_at"),
+            (DeploymentOrderBy::UpdatedAt, "updated_at"),
+            (DeploymentOrderBy::FinishedAt, "finished_at"),
+            (DeploymentOrderBy::Ref, "ref"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = Deployments::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeploymentsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        Deployments::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/deployments")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Deployments::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_order_by() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/deployments")
+            .add_query_params(&[("order_by", "created_at")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Deployments::builder()
+            .project("simple/project")
+            .order_by(DeploymentOrderBy::CreatedAt)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/deployments")
+            .add_query_params(&[("sort", "asc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Deployments::builder()
+            .project("simple/project")
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/deployments")
+            .add_query_params(&[("updated_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Deployments::builder()
+            .project("simple/project")
+            .updated_after(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/deployments")
+            .add_query_params(&[("updated_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Deployments::builder()
+            .project("simple/project")
+            .updated_before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_finished_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/deployments")
+            .add_query_params(&[("finished_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Deployments::builder()
+            .project("simple/project")
+            .finished_after(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_finished_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/deployments")
+            .add_query_params(&[("finished_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Deployments::builder()
+            .project("simple/project")
+            .finished_before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_environment() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/deployments")
+            .add_query_params(&[("environment", "production")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Deployments::builder()
+            .project("simple/project")
+            .environment("production")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_status() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/deployments")
+            .add_query_params(&[("status", "success")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Deployments::builder()
+            .project("simple/project")
+            .status(DeploymentStatus::Success)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
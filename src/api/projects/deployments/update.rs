@@ -0,0 +1,126 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::deployments::DeploymentStatus;
+use crate::types;
+
+/// Update the status of a deployment within a project.
+#[derive(Debug, Builder, Clone)]
+pub struct UpdateDeployment<'a> {
+    /// The project of the deployment.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the deployment.
+    deployment: u64,
+    /// The new status of the deployment.
+    ///
+    /// Only `running`, `success`, `failed`, and `canceled` are accepted.
+    status: DeploymentStatus,
+}
+
+impl<'a> UpdateDeployment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UpdateDeploymentBuilder<'a> {
+        UpdateDeploymentBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UpdateDeployment<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/deployments/{}", self.project, self.deployment).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("status", self.status);
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for UpdateDeployment<'a> {
+    type Response = types::Deployment;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::deployments::{
+        DeploymentStatus, UpdateDeployment, UpdateDeploymentBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = UpdateDeployment::builder()
+            .deployment(1)
+            .status(DeploymentStatus::Success)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateDeploymentBuilderError, "project");
+    }
+
+    #[test]
+    fn deployment_is_needed() {
+        let err = UpdateDeployment::builder()
+            .project(1)
+            .status(DeploymentStatus::Success)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateDeploymentBuilderError, "deployment");
+    }
+
+    #[test]
+    fn status_is_needed() {
+        let err = UpdateDeployment::builder()
+            .project(1)
+            .deployment(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateDeploymentBuilderError, "status");
+    }
+
+    #[test]
+    fn project_deployment_and_status_are_sufficient() {
+        UpdateDeployment::builder()
+            .project(1)
+            .deployment(1)
+            .status(DeploymentStatus::Success)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/deployments/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("status=failed")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateDeployment::builder()
+            .project("simple/project")
+            .deployment(1)
+            .status(DeploymentStatus::Failed)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -14,6 +14,7 @@ use crate::api::projects::{
     AutoDevOpsDeployStrategy, BuildGitStrategy, ContainerExpirationPolicy, FeatureAccessLevel,
     FeatureAccessLevelPublic, MergeMethod, SquashOption,
 };
+use crate::types;
 
 /// Edit an existing project.
 #[derive(Debug, Builder, Clone)]
@@ -490,6 +491,10 @@ impl<'a> Endpoint for EditProject<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for EditProject<'a> {
+    type Response = types::Project;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
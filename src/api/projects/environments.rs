@@ -6,10 +6,28 @@
 
 //! Project environments API endpoints.
 //!
-//! These endpoints are used for querying environments.
+//! These endpoints are used for querying and modifying environments.
 
+mod create;
+mod delete;
+mod edit;
 mod environment;
 mod environments;
+mod stop;
+mod stop_stale;
+
+pub use self::create::CreateEnvironment;
+pub use self::create::CreateEnvironmentBuilder;
+pub use self::create::CreateEnvironmentBuilderError;
+pub use self::create::EnvironmentTier;
+
+pub use self::delete::DeleteEnvironment;
+pub use self::delete::DeleteEnvironmentBuilder;
+pub use self::delete::DeleteEnvironmentBuilderError;
+
+pub use self::edit::EditEnvironment;
+pub use self::edit::EditEnvironmentBuilder;
+pub use self::edit::EditEnvironmentBuilderError;
 
 pub use self::environments::EnvironmentState;
 
@@ -20,3 +38,11 @@ pub use self::environment::EnvironmentBuilderError;
 pub use self::environments::Environments;
 pub use self::environments::EnvironmentsBuilder;
 pub use self::environments::EnvironmentsBuilderError;
+
+pub use self::stop::StopEnvironment;
+pub use self::stop::StopEnvironmentBuilder;
+pub use self::stop::StopEnvironmentBuilderError;
+
+pub use self::stop_stale::StopStaleEnvironments;
+pub use self::stop_stale::StopStaleEnvironmentsBuilder;
+pub use self::stop_stale::StopStaleEnvironmentsBuilderError;
@@ -0,0 +1,164 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::environments::EnvironmentTier;
+use crate::types;
+
+/// Edit an environment within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditEnvironment<'a> {
+    /// The project of the environment.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the environment.
+    environment: u64,
+
+    /// The new URL for the environment.
+    #[builder(setter(into), default)]
+    external_url: Option<Cow<'a, str>>,
+    /// The new deployment tier of the environment.
+    #[builder(default)]
+    tier: Option<EnvironmentTier>,
+}
+
+impl<'a> EditEnvironment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditEnvironmentBuilder<'a> {
+        EditEnvironmentBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditEnvironment<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/environments/{}",
+            self.project, self.environment,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("external_url", self.external_url.as_ref())
+            .push_opt("tier", self.tier);
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for EditEnvironment<'a> {
+    type Response = types::Environment;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::environments::{
+        EditEnvironment, EditEnvironmentBuilderError, EnvironmentTier,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_environment_are_needed() {
+        let err = EditEnvironment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditEnvironment::builder()
+            .environment(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn environment_is_needed() {
+        let err = EditEnvironment::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditEnvironmentBuilderError, "environment");
+    }
+
+    #[test]
+    fn project_and_environment_are_sufficient() {
+        EditEnvironment::builder()
+            .project(1)
+            .environment(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/environments/1")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_external_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/environments/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("external_url=https%3A%2F%2Freview.example.com")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .external_url("https://review.example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_tier() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/environments/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("tier=production")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .tier(EnvironmentTier::Production)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for an environment within a project.
 #[derive(Debug, Builder, Clone)]
@@ -40,6 +41,10 @@ impl<'a> Endpoint for Environment<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Environment<'a> {
+    type Response = types::Environment;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::environments::{Environment, EnvironmentBuilderError};
@@ -9,6 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 #[derive(Debug, Clone)]
 enum NameOrSearch<'a> {
@@ -120,6 +121,10 @@ impl<'a> Endpoint for Environments<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Environments<'a> {
+    type Response = Vec<types::Environment>;
+}
+
 impl<'a> Pageable for Environments<'a> {}
 
 #[cfg(test)]
@@ -0,0 +1,113 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Stop environments within a project which have not been updated recently.
+///
+/// Protected environments are not stopped.
+#[derive(Debug, Builder, Clone)]
+pub struct StopStaleEnvironments<'a> {
+    /// The project to stop environments within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// Stop environments which were last modified or deployed to before this time.
+    ///
+    /// Must be between 10 years ago and 1 week ago.
+    before: DateTime<Utc>,
+}
+
+impl<'a> StopStaleEnvironments<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StopStaleEnvironmentsBuilder<'a> {
+        StopStaleEnvironmentsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for StopStaleEnvironments<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/environments/stop_stale", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push("before", self.before);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::projects::environments::{
+        StopStaleEnvironments, StopStaleEnvironmentsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_before_are_needed() {
+        let err = StopStaleEnvironments::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, StopStaleEnvironmentsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = StopStaleEnvironments::builder()
+            .before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, StopStaleEnvironmentsBuilderError, "project");
+    }
+
+    #[test]
+    fn before_is_needed() {
+        let err = StopStaleEnvironments::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, StopStaleEnvironmentsBuilderError, "before");
+    }
+
+    #[test]
+    fn project_and_before_are_sufficient() {
+        StopStaleEnvironments::builder()
+            .project(1)
+            .before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments/stop_stale")
+            .add_query_params(&[("before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StopStaleEnvironments::builder()
+            .project("simple/project")
+            .before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
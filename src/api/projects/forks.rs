@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project fork API endpoints.
+//!
+//! These endpoints are used for forking projects and managing fork relationships.
+
+mod create_relationship;
+mod delete_relationship;
+mod fork;
+mod forks;
+
+pub use self::create_relationship::CreateForkRelationship;
+pub use self::create_relationship::CreateForkRelationshipBuilder;
+pub use self::create_relationship::CreateForkRelationshipBuilderError;
+
+pub use self::delete_relationship::DeleteForkRelationship;
+pub use self::delete_relationship::DeleteForkRelationshipBuilder;
+pub use self::delete_relationship::DeleteForkRelationshipBuilderError;
+
+pub use self::fork::ForkProject;
+pub use self::fork::ForkProjectBuilder;
+pub use self::fork::ForkProjectBuilderError;
+
+pub use self::forks::ProjectForks;
+pub use self::forks::ProjectForksBuilder;
+pub use self::forks::ProjectForksBuilderError;
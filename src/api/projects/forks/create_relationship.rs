@@ -0,0 +1,97 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Mark a project as a fork of another project.
+#[derive(Debug, Builder, Clone)]
+pub struct CreateForkRelationship<'a> {
+    /// The project to mark as a fork.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The project it was forked from.
+    #[builder(setter(into))]
+    forked_from: NameOrId<'a>,
+}
+
+impl<'a> CreateForkRelationship<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateForkRelationshipBuilder<'a> {
+        CreateForkRelationshipBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateForkRelationship<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/fork/{}", self.project, self.forked_from).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::forks::{CreateForkRelationship, CreateForkRelationshipBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_forked_from_are_necessary() {
+        let err = CreateForkRelationship::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateForkRelationshipBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = CreateForkRelationship::builder()
+            .forked_from(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateForkRelationshipBuilderError, "project");
+    }
+
+    #[test]
+    fn forked_from_is_necessary() {
+        let err = CreateForkRelationship::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateForkRelationshipBuilderError, "forked_from");
+    }
+
+    #[test]
+    fn project_and_forked_from_are_sufficient() {
+        CreateForkRelationship::builder()
+            .project(1)
+            .forked_from(2)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/user%2Fproject/fork/upstream%2Fproject")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateForkRelationship::builder()
+            .project("user/project")
+            .forked_from("upstream/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
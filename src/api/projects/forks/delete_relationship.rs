@@ -0,0 +1,74 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Remove the fork relationship of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteForkRelationship<'a> {
+    /// The project to unlink from the project it was forked from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> DeleteForkRelationship<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteForkRelationshipBuilder<'a> {
+        DeleteForkRelationshipBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteForkRelationship<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/fork", self.project).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::forks::{DeleteForkRelationship, DeleteForkRelationshipBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteForkRelationship::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteForkRelationshipBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        DeleteForkRelationship::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/user%2Fproject/fork")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteForkRelationship::builder()
+            .project("user/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,278 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{NameOrId, VisibilityLevel};
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Fork a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ForkProject<'a> {
+    /// The project to fork.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The namespace to create the fork in.
+    ///
+    /// Defaults to the namespace of the current user.
+    #[builder(setter(into), default)]
+    namespace: Option<NameOrId<'a>>,
+    /// The path of the fork.
+    #[builder(setter(into), default)]
+    path: Option<Cow<'a, str>>,
+    /// The name of the fork.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// The description of the fork.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// The visibility of the fork.
+    #[builder(default)]
+    visibility: Option<VisibilityLevel>,
+    /// Whether merge requests in the fork target the fork by default.
+    #[builder(default)]
+    mr_default_target_self: Option<bool>,
+    /// The branches to fork.
+    ///
+    /// All branches are forked by default.
+    #[builder(setter(into), default)]
+    branches: Option<Cow<'a, str>>,
+}
+
+impl<'a> ForkProject<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ForkProjectBuilder<'a> {
+        ForkProjectBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ForkProject<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/fork", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        if let Some(namespace) = self.namespace.as_ref() {
+            match namespace {
+                NameOrId::Id(id) => params.push("namespace_id", *id),
+                NameOrId::Name(name) => params.push("namespace_path", name),
+            };
+        }
+
+        params
+            .push_opt("path", self.path.as_ref())
+            .push_opt("name", self.name.as_ref())
+            .push_opt("description", self.description.as_ref())
+            .push_opt("visibility", self.visibility)
+            .push_opt("mr_default_target_self", self.mr_default_target_self)
+            .push_opt("branches", self.branches.as_ref());
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for ForkProject<'a> {
+    type Response = types::Project;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::common::VisibilityLevel;
+    use crate::api::projects::forks::{ForkProject, ForkProjectBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ForkProject::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ForkProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ForkProject::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/fork")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ForkProject::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_namespace_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/fork")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("namespace_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ForkProject::builder()
+            .project("simple/project")
+            .namespace(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_namespace_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/fork")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("namespace_path=user%2Fgroup")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ForkProject::builder()
+            .project("simple/project")
+            .namespace("user/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/fork")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("path=fork")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ForkProject::builder()
+            .project("simple/project")
+            .path("fork")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/fork")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=My+Fork")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ForkProject::builder()
+            .project("simple/project")
+            .name("My Fork")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/fork")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("description=description")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ForkProject::builder()
+            .project("simple/project")
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_visibility() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/fork")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("visibility=private")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ForkProject::builder()
+            .project("simple/project")
+            .visibility(VisibilityLevel::Private)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_mr_default_target_self() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/fork")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("mr_default_target_self=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ForkProject::builder()
+            .project("simple/project")
+            .mr_default_target_self(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_branches() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/fork")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("branches=main")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ForkProject::builder()
+            .project("simple/project")
+            .branches("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,439 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{AccessLevel, NameOrId, SortOrder, VisibilityLevel};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::ProjectOrderBy;
+use crate::types;
+
+/// Query for forks of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ProjectForks<'a> {
+    /// The project to query for forks.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Search for forks using a query string.
+    ///
+    /// The search query will be escaped automatically.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+
+    /// Filter forks by its archived state.
+    #[builder(default)]
+    archived: Option<bool>,
+    /// Filter forks by its visibility.
+    #[builder(default)]
+    visibility: Option<VisibilityLevel>,
+    /// Return only simple fields for search results.
+    #[builder(default)]
+    simple: Option<bool>,
+    /// Filter forks by those owned by the API caller.
+    #[builder(default)]
+    owned: Option<bool>,
+    /// Filter forks by those the caller is a member of.
+    #[builder(default)]
+    membership: Option<bool>,
+    /// Filter forks by those the caller has starred.
+    #[builder(default)]
+    starred: Option<bool>,
+    /// Include fork statistics in the results.
+    #[builder(default)]
+    statistics: Option<bool>,
+
+    /// Filter forks with issues enabled.
+    #[builder(default)]
+    with_issues_enabled: Option<bool>,
+    /// Filter forks with merge requests enabled.
+    #[builder(default)]
+    with_merge_requests_enabled: Option<bool>,
+    /// Filter forks by those where the caller has a minimum access level.
+    #[builder(default)]
+    min_access_level: Option<AccessLevel>,
+    /// Search for forks with custom attributes.
+    #[builder(default)]
+    with_custom_attributes: Option<bool>,
+
+    /// Filter forks updated after a point in time.
+    #[builder(default)]
+    updated_after: Option<DateTime<Utc>>,
+    /// Filter forks updated before a point in time.
+    #[builder(default)]
+    updated_before: Option<DateTime<Utc>>,
+
+    /// Order results by a given key.
+    #[builder(default)]
+    order_by: Option<ProjectOrderBy>,
+    /// The sort order for return results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> ProjectForks<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectForksBuilder<'a> {
+        ProjectForksBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectForks<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/forks", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("search", self.search.as_ref())
+            .push_opt("archived", self.archived)
+            .push_opt("visibility", self.visibility)
+            .push_opt("simple", self.simple)
+            .push_opt("owned", self.owned)
+            .push_opt("membership", self.membership)
+            .push_opt("starred", self.starred)
+            .push_opt("statistics", self.statistics)
+            .push_opt("with_issues_enabled", self.with_issues_enabled)
+            .push_opt(
+                "with_merge_requests_enabled",
+                self.with_merge_requests_enabled,
+            )
+            .push_opt(
+                "min_access_level",
+                self.min_access_level.map(|level| level.as_u64()),
+            )
+            .push_opt("with_custom_attributes", self.with_custom_attributes)
+            .push_opt("updated_after", self.updated_after)
+            .push_opt("updated_before", self.updated_before)
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for ProjectForks<'a> {
+    type Response = Vec<types::Project>;
+}
+
+impl<'a> Pageable for ProjectForks<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::common::{AccessLevel, SortOrder, VisibilityLevel};
+    use crate::api::projects::forks::{ProjectForks, ProjectForksBuilderError};
+    use crate::api::projects::ProjectOrderBy;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ProjectForks::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectForksBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectForks::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("search", "special/query")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .search("special/query")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_archived() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("archived", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .archived(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_visibility() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("visibility", "private")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .visibility(VisibilityLevel::Private)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_simple() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("simple", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .simple(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_owned() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("owned", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .owned(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_membership() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("membership", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .membership(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_starred() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("starred", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .starred(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_statistics() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("statistics", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .statistics(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_issues_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("with_issues_enabled", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .with_issues_enabled(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_merge_requests_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("with_merge_requests_enabled", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .with_merge_requests_enabled(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_min_access_level() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("min_access_level", "30")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .min_access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_custom_attributes() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("with_custom_attributes", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .with_custom_attributes(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("updated_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .updated_after(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("updated_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .updated_before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_order_by() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("order_by", "id")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .order_by(ProjectOrderBy::Id)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/forks")
+            .add_query_params(&[("sort", "desc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectForks::builder()
+            .project("simple/project")
+            .sort(SortOrder::Descending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Create a new webhook for a project.
 #[derive(Debug, Builder, Clone)]
@@ -118,6 +119,10 @@ impl<'a> Endpoint for CreateHook<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateHook<'a> {
+    type Response = types::ProjectHook;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
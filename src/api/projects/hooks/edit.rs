@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Edit an existing webhook for a project.
 #[derive(Debug, Builder, Clone)]
@@ -120,6 +121,10 @@ impl<'a> Endpoint for EditHook<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for EditHook<'a> {
+    type Response = types::ProjectHook;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
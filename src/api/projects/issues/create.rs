@@ -12,6 +12,7 @@ use derive_builder::Builder;
 use crate::api::common::{CommaSeparatedList, NameOrId};
 use crate::api::endpoint_prelude::*;
 use crate::api::issues::IssueType;
+use crate::types;
 
 /// Create a new issue on a project.
 #[derive(Debug, Builder, Clone)]
@@ -182,6 +183,10 @@ impl<'a> Endpoint for CreateIssue<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateIssue<'a> {
+    type Response = types::Issue;
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{NaiveDate, TimeZone, Utc};
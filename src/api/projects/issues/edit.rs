@@ -14,6 +14,7 @@ use crate::api::common::{CommaSeparatedList, NameOrId};
 use crate::api::endpoint_prelude::*;
 use crate::api::issues::IssueType;
 use crate::api::ParamValue;
+use crate::types;
 
 /// States an issue may be set to.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -306,6 +307,10 @@ impl<'a> Endpoint for EditIssue<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for EditIssue<'a> {
+    type Response = types::Issue;
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{NaiveDate, TimeZone, Utc};
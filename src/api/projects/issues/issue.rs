@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for a issue within a project.
 #[derive(Debug, Builder, Clone)]
@@ -36,6 +37,10 @@ impl<'a> Endpoint for Issue<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Issue<'a> {
+    type Response = types::Issue;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::issues::{Issue, IssueBuilderError};
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for merge requests closing an issue
 #[derive(Debug, Builder, Clone)]
@@ -36,6 +37,10 @@ impl<'a> Endpoint for MergeRequestsClosing<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequestsClosing<'a> {
+    type Response = Vec<types::MergeRequestBasic>;
+}
+
 impl<'a> Pageable for MergeRequestsClosing<'a> {}
 
 #[cfg(test)]
@@ -9,6 +9,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Create a new note on an issue on a project.
 #[derive(Debug, Builder, Clone)]
@@ -61,6 +62,10 @@ impl<'a> Endpoint for CreateIssueNote<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateIssueNote<'a> {
+    type Response = types::Note;
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{TimeZone, Utc};
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Edit an issue note on a project.
 #[derive(Debug, Builder, Clone)]
@@ -60,6 +61,10 @@ impl<'a> Endpoint for EditIssueNote<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for EditIssueNote<'a> {
+    type Response = types::Note;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -9,6 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::{NameOrId, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::helpers::NoteOrderBy;
+use crate::types;
 
 /// Query for notes on an issue within a project.
 #[derive(Debug, Builder, Clone)]
@@ -55,6 +56,10 @@ impl<'a> Endpoint for IssueNotes<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for IssueNotes<'a> {
+    type Response = Vec<types::Note>;
+}
+
 impl<'a> Pageable for IssueNotes<'a> {}
 
 #[cfg(test)]
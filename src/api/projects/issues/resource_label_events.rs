@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for resource label events for an issue.
 #[derive(Debug, Builder, Clone)]
@@ -40,6 +41,10 @@ impl<'a> Endpoint for IssueResourceLabelEvents<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for IssueResourceLabelEvents<'a> {
+    type Response = Vec<types::ResourceLabelEvent>;
+}
+
 impl<'a> Pageable for IssueResourceLabelEvents<'a> {}
 
 #[cfg(test)]
@@ -6,15 +6,18 @@
 
 //! Project job API endpoints.
 //!
-//! These endpoints are used for querying CI jobs.
+//! These endpoints are used for querying CI jobs and their artifacts.
 
+pub mod artifacts;
 mod cancel;
 mod erase;
+mod follow;
 mod job;
 mod jobs;
 mod play;
 mod retry;
 mod trace;
+mod wait;
 
 pub use self::cancel::CancelJob;
 pub use self::cancel::CancelJobBuilder;
@@ -24,6 +27,11 @@ pub use self::erase::EraseJob;
 pub use self::erase::EraseJobBuilder;
 pub use self::erase::EraseJobBuilderError;
 
+pub use self::follow::JobTraceFollower;
+pub use self::follow::JobTraceFollowerBuilder;
+pub use self::follow::JobTraceFollowerBuilderError;
+pub use self::follow::JobTraceIter;
+
 pub use self::job::Job;
 pub use self::job::JobBuilder;
 pub use self::job::JobBuilderError;
@@ -47,3 +55,7 @@ pub use self::retry::RetryJobBuilderError;
 pub use self::trace::JobTrace;
 pub use self::trace::JobTraceBuilder;
 pub use self::trace::JobTraceBuilderError;
+
+pub use self::wait::JobWaiter;
+pub use self::wait::JobWaiterBuilder;
+pub use self::wait::JobWaiterBuilderError;
@@ -0,0 +1,47 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project job artifacts API endpoints.
+//!
+//! These endpoints are used for downloading and managing the artifacts of CI jobs. Downloads
+//! return raw data and should be queried using [`crate::api::download`] (or [`crate::api::raw`]
+//! for small files).
+
+mod delete;
+mod delete_project;
+mod download;
+mod download_by_ref;
+mod file;
+mod file_by_ref;
+mod keep;
+
+pub use self::delete::DeleteJobArtifacts;
+pub use self::delete::DeleteJobArtifactsBuilder;
+pub use self::delete::DeleteJobArtifactsBuilderError;
+
+pub use self::delete_project::DeleteProjectArtifacts;
+pub use self::delete_project::DeleteProjectArtifactsBuilder;
+pub use self::delete_project::DeleteProjectArtifactsBuilderError;
+
+pub use self::download::JobArtifacts;
+pub use self::download::JobArtifactsBuilder;
+pub use self::download::JobArtifactsBuilderError;
+
+pub use self::download_by_ref::JobArtifactsByRef;
+pub use self::download_by_ref::JobArtifactsByRefBuilder;
+pub use self::download_by_ref::JobArtifactsByRefBuilderError;
+
+pub use self::file::JobArtifactFile;
+pub use self::file::JobArtifactFileBuilder;
+pub use self::file::JobArtifactFileBuilderError;
+
+pub use self::file_by_ref::JobArtifactFileByRef;
+pub use self::file_by_ref::JobArtifactFileByRefBuilder;
+pub use self::file_by_ref::JobArtifactFileByRefBuilderError;
+
+pub use self::keep::KeepJobArtifacts;
+pub use self::keep::KeepJobArtifactsBuilder;
+pub use self::keep::KeepJobArtifactsBuilderError;
@@ -0,0 +1,157 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Download the artifacts archive of the latest successful job for a ref.
+///
+/// Note: This endpoint returns raw data, so [`crate::api::download`] is recommended to write it
+/// to a file as it arrives. [`crate::api::raw`] may be used instead to collect it in memory.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct JobArtifactsByRef<'a> {
+    /// The project of the job.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The branch or tag name to get the latest artifacts for.
+    #[builder(setter(into))]
+    ref_name: Cow<'a, str>,
+    /// The name of the job.
+    #[builder(setter(into))]
+    job: Cow<'a, str>,
+
+    /// A CI job token to authenticate with.
+    ///
+    /// Only supported within multi-project pipelines.
+    #[builder(setter(into), default)]
+    job_token: Option<Cow<'a, str>>,
+}
+
+impl<'a> JobArtifactsByRef<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> JobArtifactsByRefBuilder<'a> {
+        JobArtifactsByRefBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for JobArtifactsByRef<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/jobs/artifacts/{}/download",
+            self.project,
+            common::path_escaped(&self.ref_name),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("job", &self.job)
+            .push_opt("job_token", self.job_token.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::jobs::artifacts::{JobArtifactsByRef, JobArtifactsByRefBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_ref_name_and_job_are_needed() {
+        let err = JobArtifactsByRef::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobArtifactsByRefBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = JobArtifactsByRef::builder()
+            .ref_name("main")
+            .job("build")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, JobArtifactsByRefBuilderError, "project");
+    }
+
+    #[test]
+    fn ref_name_is_needed() {
+        let err = JobArtifactsByRef::builder()
+            .project(1)
+            .job("build")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, JobArtifactsByRefBuilderError, "ref_name");
+    }
+
+    #[test]
+    fn job_is_needed() {
+        let err = JobArtifactsByRef::builder()
+            .project(1)
+            .ref_name("main")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, JobArtifactsByRefBuilderError, "job");
+    }
+
+    #[test]
+    fn project_ref_name_and_job_are_sufficient() {
+        JobArtifactsByRef::builder()
+            .project(1)
+            .ref_name("main")
+            .job("build")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/jobs/artifacts/topic%2Fbranch/download")
+            .add_query_params(&[("job", "build")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "archive");
+
+        let endpoint = JobArtifactsByRef::builder()
+            .project("simple/project")
+            .ref_name("topic/branch")
+            .job("build")
+            .build()
+            .unwrap();
+        let data = api::raw(endpoint).query(&client).unwrap();
+        assert_eq!(data, b"archive");
+    }
+
+    #[test]
+    fn endpoint_job_token() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/jobs/artifacts/main/download")
+            .add_query_params(&[("job", "build"), ("job_token", "token")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = JobArtifactsByRef::builder()
+            .project("simple/project")
+            .ref_name("main")
+            .job("build")
+            .job_token("token")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
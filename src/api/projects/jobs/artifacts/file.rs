@@ -0,0 +1,160 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+use itertools::Itertools;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Escape an artifact path while keeping its directory separators.
+pub(super) fn artifact_path_escaped(path: &str) -> String {
+    path.split('/').map(common::path_escaped).join("/")
+}
+
+/// Download a single file from the artifacts of a job.
+///
+/// Note: This endpoint returns raw data, so [`crate::api::download`] is recommended to write it
+/// to a file as it arrives. [`crate::api::raw`] may be used instead to collect it in memory.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct JobArtifactFile<'a> {
+    /// The project of the job.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the job.
+    job: u64,
+    /// The path of the file within the artifacts archive.
+    #[builder(setter(into))]
+    artifact_path: Cow<'a, str>,
+
+    /// A CI job token to authenticate with.
+    ///
+    /// Only supported within multi-project pipelines.
+    #[builder(setter(into), default)]
+    job_token: Option<Cow<'a, str>>,
+}
+
+impl<'a> JobArtifactFile<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> JobArtifactFileBuilder<'a> {
+        JobArtifactFileBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for JobArtifactFile<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/jobs/{}/artifacts/{}",
+            self.project,
+            self.job,
+            artifact_path_escaped(&self.artifact_path),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("job_token", self.job_token.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::jobs::artifacts::{JobArtifactFile, JobArtifactFileBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_job_and_artifact_path_are_needed() {
+        let err = JobArtifactFile::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobArtifactFileBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = JobArtifactFile::builder()
+            .job(1)
+            .artifact_path("build/output.log")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, JobArtifactFileBuilderError, "project");
+    }
+
+    #[test]
+    fn job_is_needed() {
+        let err = JobArtifactFile::builder()
+            .project(1)
+            .artifact_path("build/output.log")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, JobArtifactFileBuilderError, "job");
+    }
+
+    #[test]
+    fn artifact_path_is_needed() {
+        let err = JobArtifactFile::builder()
+            .project(1)
+            .job(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, JobArtifactFileBuilderError, "artifact_path");
+    }
+
+    #[test]
+    fn project_job_and_artifact_path_are_sufficient() {
+        JobArtifactFile::builder()
+            .project(1)
+            .job(1)
+            .artifact_path("build/output.log")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/jobs/1/artifacts/build/test%20output.log")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "contents");
+
+        let endpoint = JobArtifactFile::builder()
+            .project("simple/project")
+            .job(1)
+            .artifact_path("build/test output.log")
+            .build()
+            .unwrap();
+        let data = api::raw(endpoint).query(&client).unwrap();
+        assert_eq!(data, b"contents");
+    }
+
+    #[test]
+    fn endpoint_job_token() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/jobs/1/artifacts/output.log")
+            .add_query_params(&[("job_token", "token")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = JobArtifactFile::builder()
+            .project("simple/project")
+            .job(1)
+            .artifact_path("output.log")
+            .job_token("token")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
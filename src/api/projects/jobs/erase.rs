@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Erase a job.
 #[derive(Debug, Builder, Clone)]
@@ -36,6 +37,10 @@ impl<'a> Endpoint for EraseJob<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for EraseJob<'a> {
+    type Response = types::Job;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -0,0 +1,486 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::future::Future;
+use std::thread;
+use std::time::Duration;
+
+use bytes::Bytes;
+use derive_builder::Builder;
+use futures_util::Stream;
+use http::{header, Request, Response, StatusCode};
+use serde::Deserialize;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::jobs::{Job, JobTrace};
+use crate::api::projects::wait::is_finished;
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient};
+use crate::joblog::LogCleaner;
+use crate::types::StatusState;
+
+/// Follow the trace of a job as it runs.
+///
+/// The trace is polled using HTTP `Range` requests so that only new output is transferred. New
+/// output is yielded in chunks until the job has finished.
+///
+/// ```rust,no_run
+/// # use gitlab::api::projects::jobs::JobTraceFollower;
+/// # use std::io::Write;
+/// # use std::time::Duration;
+/// # let client = gitlab::Gitlab::new("gitlab.com", "private-token").unwrap();
+/// let follower = JobTraceFollower::builder()
+///     .project("group/project")
+///     .job(1)
+///     .interval(Duration::from_secs(1))
+///     .strip(true)
+///     .build()
+///     .unwrap();
+/// for chunk in follower.iter(&client) {
+///     std::io::stdout().write_all(&chunk.unwrap()).unwrap();
+/// }
+/// ```
+#[derive(Debug, Builder, Clone)]
+pub struct JobTraceFollower<'a> {
+    /// The project of the job.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the job.
+    job: u64,
+
+    /// How long to wait between polls.
+    ///
+    /// Defaults to 3 seconds.
+    #[builder(default = "Duration::from_secs(3)")]
+    interval: Duration,
+    /// Whether to remove ANSI escape sequences and section markers from the output.
+    ///
+    /// When enabled, output is yielded a line at a time.
+    ///
+    /// Defaults to `false`.
+    #[builder(default)]
+    strip: bool,
+}
+
+impl<'a> JobTraceFollower<'a> {
+    /// Create a builder for the follower.
+    pub fn builder() -> JobTraceFollowerBuilder<'a> {
+        JobTraceFollowerBuilder::default()
+    }
+
+    /// Create an iterator over new trace output using a client.
+    ///
+    /// The iterator blocks between polls.
+    pub fn iter<'b, C>(&'b self, client: &'b C) -> JobTraceIter<'b, C> {
+        JobTraceIter::new(self, client)
+    }
+
+    /// Create a stream over new trace output using a client.
+    ///
+    /// The `sleep` function is used to wait between polls so that any async runtime may be used
+    /// (e.g., `tokio::time::sleep`).
+    pub fn stream<'b, C, S, F>(
+        &'b self,
+        client: &'b C,
+        sleep: S,
+    ) -> impl Stream<Item = Result<Vec<u8>, ApiError<C::Error>>> + 'b
+    where
+        C: AsyncClient + Sync,
+        S: Fn(Duration) -> F + 'b,
+        F: Future<Output = ()> + 'b,
+    {
+        let iter = JobTraceIter::new(self, client);
+        futures_util::stream::unfold((iter, sleep), |(mut iter, sleep)| {
+            async move {
+                iter.next_async(&sleep)
+                    .await
+                    .map(|item| (item, (iter, sleep)))
+            }
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct JobStatus {
+    status: StatusState,
+}
+
+/// An iterator over new trace output of a job.
+pub struct JobTraceIter<'a, C> {
+    client: &'a C,
+    job: Job<'a>,
+    trace: JobTrace<'a>,
+    interval: Duration,
+    cleaner: Option<LogCleaner>,
+    offset: usize,
+    polled: bool,
+    done: bool,
+}
+
+impl<'a, C> JobTraceIter<'a, C> {
+    fn new(follower: &'a JobTraceFollower, client: &'a C) -> Self {
+        let job = Job::builder()
+            .project(follower.project.clone())
+            .job(follower.job)
+            .build()
+            .expect("the project and job are always set");
+        let trace = JobTrace::builder()
+            .project(follower.project.clone())
+            .job(follower.job)
+            .build()
+            .expect("the project and job are always set");
+
+        Self {
+            client,
+            job,
+            trace,
+            interval: follower.interval,
+            cleaner: if follower.strip {
+                Some(LogCleaner::new())
+            } else {
+                None
+            },
+            offset: 0,
+            polled: false,
+            done: false,
+        }
+    }
+
+    fn trace_request<E>(&self) -> Result<http::request::Builder, ApiError<E>>
+    where
+        C: RestClient,
+        E: std::error::Error + Send + Sync + 'static,
+        ApiError<E>: From<ApiError<C::Error>>,
+    {
+        let mut url = self.client.rest_endpoint(&self.trace.endpoint())?;
+        self.trace.parameters().add_to_url(&mut url);
+
+        let req = Request::builder()
+            .method(self.trace.method())
+            .uri(query::url_to_http_uri(url));
+        Ok(if self.offset > 0 {
+            req.header(header::RANGE, format!("bytes={}-", self.offset))
+        } else {
+            req
+        })
+    }
+
+    /// Extract the new output from a trace response.
+    fn new_output<E>(&mut self, rsp: Response<Bytes>) -> Result<Vec<u8>, ApiError<E>>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let status = rsp.status();
+        let data = if status == StatusCode::PARTIAL_CONTENT {
+            rsp.body().to_vec()
+        } else if status == StatusCode::RANGE_NOT_SATISFIABLE {
+            // There is no new output.
+            Vec::new()
+        } else if status.is_success() {
+            // The range was ignored, so skip the output which has already been seen.
+            rsp.body().get(self.offset..).unwrap_or_default().into()
+        } else {
+            let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+                v
+            } else {
+                return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
+            };
+            return Err(ApiError::from_gitlab(status, rsp.headers(), v));
+        };
+        self.offset += data.len();
+
+        Ok(data)
+    }
+
+    /// Process new output from the job.
+    ///
+    /// Returns the chunk to yield, if any.
+    fn process(&mut self, data: Vec<u8>, finished: bool) -> Option<Vec<u8>> {
+        let chunk = if let Some(cleaner) = self.cleaner.as_mut() {
+            let mut chunk = cleaner.push(&data);
+            if finished {
+                chunk.extend(cleaner.finish());
+            }
+            chunk
+        } else {
+            data
+        };
+        self.done = finished;
+
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a, C> JobTraceIter<'a, C>
+where
+    C: Client,
+{
+    fn poll(&mut self) -> Result<Option<Vec<u8>>, ApiError<C::Error>> {
+        // Query the status first so that all output is available once the job has finished.
+        let job: JobStatus = self.job.query(self.client)?;
+        let req = self.trace_request::<C::Error>()?;
+        let rsp = self.client.rest(req, Vec::new())?;
+        let data = self.new_output(rsp)?;
+        Ok(self.process(data, is_finished(job.status)))
+    }
+}
+
+impl<'a, C> Iterator for JobTraceIter<'a, C>
+where
+    C: Client,
+{
+    type Item = Result<Vec<u8>, ApiError<C::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            if self.polled {
+                thread::sleep(self.interval);
+            }
+            self.polled = true;
+
+            match self.poll() {
+                Ok(Some(chunk)) => return Some(Ok(chunk)),
+                Ok(None) => (),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+
+        None
+    }
+}
+
+// Instead of implementing Stream directly, we implement this "async" next method and use it with
+// `stream::unfold` to return an anonymous Stream impl.
+impl<'a, C> JobTraceIter<'a, C>
+where
+    C: AsyncClient + Sync,
+{
+    async fn poll_async(&mut self) -> Result<Option<Vec<u8>>, ApiError<C::Error>> {
+        // Query the status first so that all output is available once the job has finished.
+        let job: JobStatus = self.job.query_async(self.client).await?;
+        let req = self.trace_request::<C::Error>()?;
+        let rsp = self.client.rest_async(req, Vec::new()).await?;
+        let data = self.new_output(rsp)?;
+        Ok(self.process(data, is_finished(job.status)))
+    }
+
+    async fn next_async<S, F>(&mut self, sleep: &S) -> Option<Result<Vec<u8>, ApiError<C::Error>>>
+    where
+        S: Fn(Duration) -> F,
+        F: Future<Output = ()>,
+    {
+        while !self.done {
+            if self.polled {
+                sleep(self.interval).await;
+            }
+            self.polled = true;
+
+            match self.poll_async().await {
+                Ok(Some(chunk)) => return Some(Ok(chunk)),
+                Ok(None) => (),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures_util::TryStreamExt;
+    use http::{Method, StatusCode};
+    use serde_json::json;
+
+    use crate::api::projects::jobs::{JobTraceFollower, JobTraceFollowerBuilderError};
+    use crate::api::ApiError;
+    use crate::testing::mock::{MockClient, MockResponse, Route};
+
+    const JOB: &str = "projects/simple%2Fproject/jobs/1";
+    const TRACE: &str = "projects/simple%2Fproject/jobs/1/trace";
+
+    fn follower(strip: bool) -> JobTraceFollower<'static> {
+        JobTraceFollower::builder()
+            .project("simple/project")
+            .job(1)
+            .interval(Duration::from_secs(0))
+            .strip(strip)
+            .build()
+            .unwrap()
+    }
+
+    fn job_status(status: &str) -> MockResponse {
+        MockResponse::json(&json!({
+            "status": status,
+        }))
+    }
+
+    #[test]
+    fn project_and_job_are_needed() {
+        let err = JobTraceFollower::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobTraceFollowerBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = JobTraceFollower::builder().job(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobTraceFollowerBuilderError, "project");
+    }
+
+    #[test]
+    fn job_is_needed() {
+        let err = JobTraceFollower::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobTraceFollowerBuilderError, "job");
+    }
+
+    #[test]
+    fn project_and_job_are_sufficient() {
+        JobTraceFollower::builder()
+            .project(1)
+            .job(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn follow_ranges() {
+        let client = MockClient::new();
+        client.add(
+            Route::new(Method::GET, JOB)
+                .respond(job_status("running"))
+                .respond(job_status("running"))
+                .respond(job_status("success")),
+        );
+        let ranged = client.add(
+            Route::new(Method::GET, TRACE)
+                .header("range", "bytes=6-")
+                .respond(MockResponse::raw("").status(StatusCode::RANGE_NOT_SATISFIABLE))
+                .respond(MockResponse::raw("world\n").status(StatusCode::PARTIAL_CONTENT)),
+        );
+        let full = client.add(Route::new(Method::GET, TRACE).respond(MockResponse::raw("hello\n")));
+
+        let follower = follower(false);
+        let chunks = follower
+            .iter(&client)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(chunks, [b"hello\n".to_vec(), b"world\n".to_vec()]);
+        assert_eq!(client.calls(full), 1);
+        assert_eq!(client.calls(ranged), 2);
+    }
+
+    #[test]
+    fn follow_range_ignored() {
+        let client = MockClient::new();
+        client.add(
+            Route::new(Method::GET, JOB)
+                .respond(job_status("running"))
+                .respond(job_status("failed")),
+        );
+        client.add(
+            Route::new(Method::GET, TRACE)
+                .respond(MockResponse::raw("hello\n"))
+                .respond(MockResponse::raw("hello\nworld\n")),
+        );
+
+        let follower = follower(false);
+        let chunks = follower
+            .iter(&client)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(chunks, [b"hello\n".to_vec(), b"world\n".to_vec()]);
+    }
+
+    #[test]
+    fn follow_finished() {
+        let client = MockClient::new();
+        let job = client.add(Route::new(Method::GET, JOB).respond(job_status("canceled")));
+        client.add(Route::new(Method::GET, TRACE).respond(MockResponse::raw("")));
+
+        let follower = follower(false);
+        assert_eq!(follower.iter(&client).count(), 0);
+        assert_eq!(client.calls(job), 1);
+    }
+
+    #[test]
+    fn follow_strip() {
+        let client = MockClient::new();
+        client.add(
+            Route::new(Method::GET, JOB)
+                .respond(job_status("running"))
+                .respond(job_status("success")),
+        );
+        client.add(
+            Route::new(Method::GET, TRACE)
+                .header("range", "bytes=40-")
+                .respond(
+                    MockResponse::raw("\x1b[32;1mdone\x1b[0;m").status(StatusCode::PARTIAL_CONTENT),
+                ),
+        );
+        client.add(Route::new(Method::GET, TRACE).respond(MockResponse::raw(
+            "\x1b[0Ksection_start:1:build\r\x1b[0Kmake\nmake ",
+        )));
+
+        let follower = follower(true);
+        let chunks = follower
+            .iter(&client)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(chunks, [b"make\n".to_vec(), b"make done".to_vec()]);
+    }
+
+    #[test]
+    fn follow_error() {
+        let client = MockClient::new();
+        client.add(Route::new(Method::GET, JOB).respond(job_status("running")));
+        client.add(
+            Route::new(Method::GET, TRACE)
+                .respond(MockResponse::error(StatusCode::FORBIDDEN, "403 Forbidden")),
+        );
+
+        let follower = follower(false);
+        let err = follower.iter(&client).next().unwrap().unwrap_err();
+        if let ApiError::Forbidden {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("403 Forbidden"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn follow_async() {
+        let client = MockClient::new();
+        client.add(
+            Route::new(Method::GET, JOB)
+                .respond(job_status("running"))
+                .respond(job_status("running"))
+                .respond(job_status("success")),
+        );
+        client.add(
+            Route::new(Method::GET, TRACE)
+                .header("range", "bytes=6-")
+                .respond(MockResponse::raw("").status(StatusCode::RANGE_NOT_SATISFIABLE))
+                .respond(MockResponse::raw("world\n").status(StatusCode::PARTIAL_CONTENT)),
+        );
+        client.add(Route::new(Method::GET, TRACE).respond(MockResponse::raw("hello\n")));
+
+        let follower = follower(false);
+        let chunks: Vec<_> = follower
+            .stream(&client, |_| async {})
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(chunks, [b"hello\n".to_vec(), b"world\n".to_vec()]);
+    }
+}
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for a job within a project.
 #[derive(Debug, Builder, Clone)]
@@ -36,6 +37,10 @@ impl<'a> Endpoint for Job<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Job<'a> {
+    type Response = types::Job;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::jobs::{Job, JobBuilderError};
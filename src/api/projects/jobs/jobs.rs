@@ -11,6 +11,7 @@ use derive_builder::Builder;
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Scopes for jobs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -109,6 +110,10 @@ impl<'a> Endpoint for Jobs<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Jobs<'a> {
+    type Response = Vec<types::Job>;
+}
+
 impl<'a> Pageable for Jobs<'a> {}
 
 #[cfg(test)]
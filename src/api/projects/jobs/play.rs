@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// A job variable for a manual job.
 #[derive(Debug, Builder, Clone)]
@@ -94,6 +95,10 @@ impl<'a> Endpoint for PlayJob<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for PlayJob<'a> {
+    type Response = types::Job;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
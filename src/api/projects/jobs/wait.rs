@@ -0,0 +1,341 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::future::Future;
+use std::thread;
+use std::time::Duration;
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::jobs::Job;
+use crate::api::projects::wait::{self, Poller, WaitError};
+use crate::api::{AsyncClient, AsyncQuery, Client, Query};
+use crate::types::{self, StatusState};
+
+/// Wait for a job to finish.
+///
+/// The job is polled until it succeeds, fails, is canceled, or is skipped. Note that manual jobs
+/// will not finish on their own; use a timeout to avoid waiting on them forever.
+///
+/// ```rust,no_run
+/// # use gitlab::api::projects::jobs::JobWaiter;
+/// # use std::time::Duration;
+/// # let client = gitlab::Gitlab::new("gitlab.com", "private-token").unwrap();
+/// let waiter = JobWaiter::builder()
+///     .project("group/project")
+///     .job(1)
+///     .timeout(Duration::from_secs(600))
+///     .build()
+///     .unwrap();
+/// let job = waiter
+///     .wait_with(&client, |job| println!("{}: {:?}", job.name, job.status))
+///     .unwrap();
+/// println!("job finished: {:?}", job.status);
+/// ```
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct JobWaiter<'a> {
+    /// The project of the job.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the job.
+    job: u64,
+
+    /// How long to wait between the first polls.
+    ///
+    /// Defaults to 3 seconds.
+    #[builder(default = "Duration::from_secs(3)")]
+    interval: Duration,
+    /// The scale parameter for the interval after each poll.
+    ///
+    /// Must be finite and at least `1.0`. Defaults to `1.0` (a constant interval).
+    #[builder(default = "1.0")]
+    backoff: f64,
+    /// The longest interval to wait between polls.
+    #[builder(default)]
+    max_interval: Option<Duration>,
+    /// How long to wait for the job to finish.
+    ///
+    /// By default, there is no timeout.
+    #[builder(default)]
+    timeout: Option<Duration>,
+}
+
+impl<'a> JobWaiterBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        wait::validate_backoff(self.backoff)
+    }
+}
+
+impl<'a> JobWaiter<'a> {
+    /// Create a builder for the waiter.
+    pub fn builder() -> JobWaiterBuilder<'a> {
+        JobWaiterBuilder::default()
+    }
+
+    /// Wait for the job to finish.
+    ///
+    /// Blocks the current thread between polls.
+    pub fn wait<C>(&self, client: &C) -> Result<types::Job, WaitError<C::Error>>
+    where
+        C: Client,
+    {
+        self.wait_with(client, |_| ())
+    }
+
+    /// Wait for the job to finish, reporting status changes.
+    ///
+    /// The callback is called with the job when it is first seen and whenever its status
+    /// changes. Blocks the current thread between polls.
+    pub fn wait_with<C, F>(
+        &self,
+        client: &C,
+        mut on_job: F,
+    ) -> Result<types::Job, WaitError<C::Error>>
+    where
+        C: Client,
+        F: FnMut(&types::Job),
+    {
+        let endpoint = self.endpoint();
+        let mut poller = self.poller();
+        let mut last_status = None;
+
+        loop {
+            let job: types::Job = endpoint.query(client)?;
+            report(&job, &mut last_status, &mut on_job);
+            if wait::is_finished(job.status) {
+                return Ok(job);
+            }
+
+            thread::sleep(poller.next_delay(job.status)?);
+        }
+    }
+
+    /// Wait for the job to finish asynchronously.
+    ///
+    /// The `sleep` function is used to wait between polls so that any async runtime may be used
+    /// (e.g., `tokio::time::sleep`).
+    pub async fn wait_async<C, S, Fut>(
+        &self,
+        client: &C,
+        sleep: S,
+    ) -> Result<types::Job, WaitError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        S: Fn(Duration) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        self.wait_with_async(client, sleep, |_| ()).await
+    }
+
+    /// Wait for the job to finish asynchronously, reporting status changes.
+    ///
+    /// See `wait_with` and `wait_async`.
+    pub async fn wait_with_async<C, S, Fut, F>(
+        &self,
+        client: &C,
+        sleep: S,
+        mut on_job: F,
+    ) -> Result<types::Job, WaitError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        S: Fn(Duration) -> Fut,
+        Fut: Future<Output = ()>,
+        F: FnMut(&types::Job),
+    {
+        let endpoint = self.endpoint();
+        let mut poller = self.poller();
+        let mut last_status = None;
+
+        loop {
+            let job: types::Job = endpoint.query_async(client).await?;
+            report(&job, &mut last_status, &mut on_job);
+            if wait::is_finished(job.status) {
+                return Ok(job);
+            }
+
+            sleep(poller.next_delay(job.status)?).await;
+        }
+    }
+
+    fn endpoint(&self) -> Job<'a> {
+        Job::builder()
+            .project(self.project.clone())
+            .job(self.job)
+            .build()
+            .expect("the project and job are always set")
+    }
+
+    fn poller(&self) -> Poller {
+        Poller::new(self.interval, self.backoff, self.max_interval, self.timeout)
+    }
+}
+
+fn report<F>(job: &types::Job, last_status: &mut Option<StatusState>, on_job: &mut F)
+where
+    F: FnMut(&types::Job),
+{
+    if last_status.replace(job.status) != Some(job.status) {
+        on_job(job);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http::Method;
+    use serde_json::{json, Value};
+
+    use crate::api::projects::jobs::{JobWaiter, JobWaiterBuilder, JobWaiterBuilderError};
+    use crate::api::projects::WaitError;
+    use crate::testing::mock::{MockClient, MockResponse, Route};
+    use crate::types::StatusState;
+
+    const JOB: &str = "projects/simple%2Fproject/jobs/1";
+
+    fn waiter() -> JobWaiterBuilder<'static> {
+        let mut builder = JobWaiter::builder();
+        builder
+            .project("simple/project")
+            .job(1)
+            .interval(Duration::from_secs(0));
+        builder
+    }
+
+    fn job(status: &str) -> MockResponse {
+        let jobs: Vec<Value> =
+            serde_json::from_str(include_str!("../../../../data/job.json")).unwrap();
+        let mut job = jobs[0].clone();
+        job["status"] = json!(status);
+        MockResponse::json(&job)
+    }
+
+    #[test]
+    fn project_and_job_are_needed() {
+        let err = JobWaiter::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobWaiterBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = JobWaiter::builder().job(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobWaiterBuilderError, "project");
+    }
+
+    #[test]
+    fn job_is_needed() {
+        let err = JobWaiter::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobWaiterBuilderError, "job");
+    }
+
+    #[test]
+    fn project_and_job_are_sufficient() {
+        JobWaiter::builder().project(1).job(1).build().unwrap();
+    }
+
+    #[test]
+    fn backoff_must_not_shrink() {
+        let err = JobWaiter::builder()
+            .project(1)
+            .job(1)
+            .backoff(0.5)
+            .build()
+            .unwrap_err();
+        if let JobWaiterBuilderError::ValidationError(message) = err {
+            assert_eq!(
+                message,
+                "the backoff must be a finite number of at least 1.0, not 0.5",
+            );
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn backoff_must_be_finite() {
+        let err = JobWaiter::builder()
+            .project(1)
+            .job(1)
+            .backoff(f64::NAN)
+            .build()
+            .unwrap_err();
+        if let JobWaiterBuilderError::ValidationError(_) = err {
+            // expected
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn wait_with() {
+        let client = MockClient::new();
+        let route = client.add(
+            Route::new(Method::GET, JOB)
+                .respond(job("pending"))
+                .respond(job("running"))
+                .respond(job("running"))
+                .respond(job("success")),
+        );
+
+        let mut changes = Vec::new();
+        let job = waiter()
+            .build()
+            .unwrap()
+            .wait_with(&client, |job| changes.push(job.status))
+            .unwrap();
+        assert_eq!(job.status, StatusState::Success);
+        assert_eq!(
+            changes,
+            [
+                StatusState::Pending,
+                StatusState::Running,
+                StatusState::Success,
+            ],
+        );
+        assert_eq!(client.calls(route), 4);
+    }
+
+    #[test]
+    fn wait_timeout() {
+        let client = MockClient::new();
+        client.add(Route::new(Method::GET, JOB).respond(job("running")));
+
+        let err = waiter()
+            .timeout(Duration::from_secs(0))
+            .build()
+            .unwrap()
+            .wait(&client)
+            .unwrap_err();
+        if let WaitError::Timeout {
+            status, ..
+        } = err
+        {
+            assert_eq!(status, StatusState::Running);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn wait_async() {
+        let client = MockClient::new();
+        client.add(
+            Route::new(Method::GET, JOB)
+                .respond(job("running"))
+                .respond(job("skipped")),
+        );
+
+        let job = waiter()
+            .build()
+            .unwrap()
+            .wait_async(&client, |_| async {})
+            .await
+            .unwrap();
+        assert_eq!(job.status, StatusState::Skipped);
+    }
+}
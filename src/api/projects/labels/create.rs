@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Create a label within a project.
 #[derive(Debug, Builder, Clone)]
@@ -64,6 +65,10 @@ impl<'a> Endpoint for CreateLabel<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateLabel<'a> {
+    type Response = types::Label;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for a label within a project.
 #[derive(Debug, Builder, Clone)]
@@ -52,6 +53,10 @@ impl<'a> Endpoint for Label<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Label<'a> {
+    type Response = types::Label;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::labels::{Label, LabelBuilderError};
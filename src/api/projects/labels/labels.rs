@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for labels within a project.
 #[derive(Debug, Builder, Clone)]
@@ -58,6 +59,10 @@ impl<'a> Endpoint for Labels<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Labels<'a> {
+    type Response = Vec<types::Label>;
+}
+
 impl<'a> Pageable for Labels<'a> {}
 
 #[cfg(test)]
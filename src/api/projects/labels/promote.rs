@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Promote a label within a project to a group label.
 #[derive(Debug, Clone, Builder)]
@@ -37,6 +38,10 @@ impl<'a> Endpoint for PromoteLabel<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for PromoteLabel<'a> {
+    type Response = types::Label;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
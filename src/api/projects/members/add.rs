@@ -11,6 +11,7 @@ use itertools::Itertools;
 use crate::api::common::{AccessLevel, CommaSeparatedList, NameOrId};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Tasks users may be assigned upon addition to a project.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -148,6 +149,10 @@ impl<'a> Endpoint for AddProjectMember<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for AddProjectMember<'a> {
+    type Response = types::Member;
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query a single member of a project with ancestor collapsing.
 #[derive(Debug, Builder, Clone)]
@@ -36,6 +37,10 @@ impl<'a> Endpoint for AllProjectMember<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for AllProjectMember<'a> {
+    type Response = types::Member;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::members::{AllProjectMember, AllProjectMemberBuilderError};
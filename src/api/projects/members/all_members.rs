@@ -11,6 +11,7 @@ use derive_builder::Builder;
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// States that project memberships may be in.
@@ -102,6 +103,10 @@ impl<'a> Endpoint for AllProjectMembers<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for AllProjectMembers<'a> {
+    type Response = Vec<types::Member>;
+}
+
 impl<'a> Pageable for AllProjectMembers<'a> {}
 
 #[cfg(test)]
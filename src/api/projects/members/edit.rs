@@ -9,6 +9,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{AccessLevel, NameOrId};
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Edit a member of a project.
 #[derive(Debug, Builder, Clone)]
@@ -55,6 +56,10 @@ impl<'a> Endpoint for EditProjectMember<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for EditProjectMember<'a> {
+    type Response = types::Member;
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
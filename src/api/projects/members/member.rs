@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query a single member of a project.
 #[derive(Debug, Builder, Clone)]
@@ -55,6 +56,10 @@ impl<'a> Endpoint for ProjectMember<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for ProjectMember<'a> {
+    type Response = types::Member;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::members::{ProjectMember, ProjectMemberBuilderError};
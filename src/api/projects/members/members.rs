@@ -10,6 +10,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query a members of a project.
 #[derive(Debug, Builder, Clone)]
@@ -90,6 +91,10 @@ impl<'a> Endpoint for ProjectMembers<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for ProjectMembers<'a> {
+    type Response = Vec<types::Member>;
+}
+
 impl<'a> Pageable for ProjectMembers<'a> {}
 
 #[cfg(test)]
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for awards on a merge request within a project.
 #[derive(Debug, Builder, Clone)]
@@ -40,6 +41,10 @@ impl<'a> Endpoint for MergeRequestAwards<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequestAwards<'a> {
+    type Response = Vec<types::AwardEmoji>;
+}
+
 impl<'a> Pageable for MergeRequestAwards<'a> {}
 
 #[cfg(test)]
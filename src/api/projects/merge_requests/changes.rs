@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Shows information of a merge request including its files and changes.
 #[derive(Debug, Builder, Clone)]
@@ -53,6 +54,10 @@ impl<'a> Endpoint for MergeRequestChanges<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequestChanges<'a> {
+    type Response = types::MergeRequestChanges;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for a merge request on a project.
 #[derive(Debug, Builder, Clone)]
@@ -40,6 +41,10 @@ impl<'a> Endpoint for MergeRequestCommits<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequestCommits<'a> {
+    type Response = Vec<types::MergeRequestCommit>;
+}
+
 impl<'a> Pageable for MergeRequestCommits<'a> {}
 
 #[cfg(test)]
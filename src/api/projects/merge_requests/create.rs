@@ -11,6 +11,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{CommaSeparatedList, NameOrId};
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 #[derive(Debug, Clone)]
 pub(crate) enum Assignee {
@@ -265,6 +266,10 @@ impl<'a> Endpoint for CreateMergeRequest<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateMergeRequest<'a> {
+    type Response = types::MergeRequest;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -10,6 +10,7 @@ use derive_builder::Builder;
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// The type of line to comment on.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -293,6 +294,10 @@ impl<'a> Endpoint for CreateMergeRequestDiscussion<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateMergeRequestDiscussion<'a> {
+    type Response = types::Discussion;
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{TimeZone, Utc};
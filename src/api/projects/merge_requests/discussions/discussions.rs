@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for discussions on an merge request within a project.
 #[derive(Debug, Builder, Clone)]
@@ -40,6 +41,10 @@ impl<'a> Endpoint for MergeRequestDiscussions<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequestDiscussions<'a> {
+    type Response = Vec<types::Discussion>;
+}
+
 impl<'a> Pageable for MergeRequestDiscussions<'a> {}
 
 #[cfg(test)]
@@ -12,6 +12,7 @@ use crate::api::common::{CommaSeparatedList, NameOrId};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::merge_requests::create::{Assignee, Reviewer};
 use crate::api::ParamValue;
+use crate::types;
 
 #[derive(Debug, Clone)]
 enum MergeRequestLabels<'a> {
@@ -314,6 +315,10 @@ impl<'a> Endpoint for EditMergeRequest<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for EditMergeRequest<'a> {
+    type Response = types::MergeRequest;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
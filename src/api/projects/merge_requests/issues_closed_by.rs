@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for issues closed by a merge request.
 #[derive(Debug, Builder, Clone)]
@@ -41,6 +42,10 @@ impl<'a> Endpoint for IssuesClosedBy<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for IssuesClosedBy<'a> {
+    type Response = Vec<types::Issue>;
+}
+
 impl<'a> Pageable for IssuesClosedBy<'a> {}
 
 #[cfg(test)]
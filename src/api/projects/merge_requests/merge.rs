@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Merge a merge request.
 #[derive(Debug, Builder, Clone)]
@@ -81,6 +82,10 @@ impl<'a> Endpoint for MergeMergeRequest<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeMergeRequest<'a> {
+    type Response = types::MergeRequest;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for a merge request on a project.
 #[derive(Debug, Builder, Clone)]
@@ -68,6 +69,10 @@ impl<'a> Endpoint for MergeRequest<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequest<'a> {
+    type Response = types::MergeRequest;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::merge_requests::{MergeRequest, MergeRequestBuilderError};
@@ -14,6 +14,7 @@ use crate::api::common::{NameOrId, SortOrder, YesNo};
 use crate::api::endpoint_prelude::*;
 use crate::api::helpers::{Labels, Milestone, ReactionEmoji};
 use crate::api::ParamValue;
+use crate::types;
 
 /// Filters for merge request states.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -608,6 +609,10 @@ impl<'a> Endpoint for MergeRequests<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequests<'a> {
+    type Response = Vec<types::MergeRequest>;
+}
+
 impl<'a> Pageable for MergeRequests<'a> {}
 
 #[cfg(test)]
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for awards on a note on a merge request within a project.
 #[derive(Debug, Builder, Clone)]
@@ -42,6 +43,10 @@ impl<'a> Endpoint for MergeRequestNoteAwards<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequestNoteAwards<'a> {
+    type Response = Vec<types::AwardEmoji>;
+}
+
 impl<'a> Pageable for MergeRequestNoteAwards<'a> {}
 
 #[cfg(test)]
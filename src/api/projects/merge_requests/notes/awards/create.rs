@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Create a new award on a note on a merge request on a project.
 #[derive(Debug, Builder, Clone)]
@@ -53,6 +54,10 @@ impl<'a> Endpoint for CreateMergeRequestNoteAward<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateMergeRequestNoteAward<'a> {
+    type Response = types::AwardEmoji;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
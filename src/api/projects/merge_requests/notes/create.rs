@@ -9,6 +9,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Create a new note on a merge request on a project.
 #[derive(Debug, Builder, Clone)]
@@ -71,6 +72,10 @@ impl<'a> Endpoint for CreateMergeRequestNote<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateMergeRequestNote<'a> {
+    type Response = types::Note;
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{TimeZone, Utc};
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Edit a merge request note on a project.
 #[derive(Debug, Builder, Clone)]
@@ -64,6 +65,10 @@ impl<'a> Endpoint for EditMergeRequestNote<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for EditMergeRequestNote<'a> {
+    type Response = types::Note;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -9,6 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::{NameOrId, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::helpers::NoteOrderBy;
+use crate::types;
 
 /// Query for notes on an merge request within a project.
 #[derive(Debug, Builder, Clone)]
@@ -59,6 +60,10 @@ impl<'a> Endpoint for MergeRequestNotes<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequestNotes<'a> {
+    type Response = Vec<types::Note>;
+}
+
 impl<'a> Pageable for MergeRequestNotes<'a> {}
 
 #[cfg(test)]
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// List all pipelines attached to a merge request.
 #[derive(Debug, Builder, Clone)]
@@ -40,6 +41,10 @@ impl<'a> Endpoint for CreateMergeRequestPipelines<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateMergeRequestPipelines<'a> {
+    type Response = types::Pipeline;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// List all pipelines attached to a merge request.
 #[derive(Debug, Builder)]
@@ -40,6 +41,10 @@ impl<'a> Endpoint for MergeRequestPipelines<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequestPipelines<'a> {
+    type Response = Vec<types::PipelineBasic>;
+}
+
 impl<'a> Pageable for MergeRequestPipelines<'a> {}
 
 #[cfg(test)]
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for resource label events for a merge request.
 #[derive(Debug, Builder, Clone)]
@@ -40,6 +41,10 @@ impl<'a> Endpoint for MergeRequestResourceLabelEvents<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequestResourceLabelEvents<'a> {
+    type Response = Vec<types::ResourceLabelEvent>;
+}
+
 impl<'a> Pageable for MergeRequestResourceLabelEvents<'a> {}
 
 #[cfg(test)]
@@ -12,6 +12,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{NameOrId, SortOrder};
 use crate::api::{endpoint_prelude::*, ParamValue};
+use crate::types;
 
 /// Filter merge train entries by a scope.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -81,6 +82,10 @@ impl<'a> Endpoint for MergeTrains<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeTrains<'a> {
+    type Response = Vec<types::MergeTrain>;
+}
+
 impl<'a> Pageable for MergeTrains<'a> {}
 
 #[cfg(test)]
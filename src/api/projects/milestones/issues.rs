@@ -0,0 +1,106 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Query for the issues assigned to a milestone within a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectMilestoneIssues<'a> {
+    /// The project the milestone belongs to.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the milestone.
+    milestone: u64,
+}
+
+impl<'a> ProjectMilestoneIssues<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectMilestoneIssuesBuilder<'a> {
+        ProjectMilestoneIssuesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectMilestoneIssues<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/milestones/{}/issues",
+            self.project, self.milestone,
+        )
+        .into()
+    }
+}
+
+impl<'a> TypedEndpoint for ProjectMilestoneIssues<'a> {
+    type Response = Vec<types::Issue>;
+}
+
+impl<'a> Pageable for ProjectMilestoneIssues<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::milestones::{
+        ProjectMilestoneIssues, ProjectMilestoneIssuesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_milestone_are_needed() {
+        let err = ProjectMilestoneIssues::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectMilestoneIssuesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectMilestoneIssues::builder()
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectMilestoneIssuesBuilderError, "project");
+    }
+
+    #[test]
+    fn milestone_is_needed() {
+        let err = ProjectMilestoneIssues::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectMilestoneIssuesBuilderError, "milestone");
+    }
+
+    #[test]
+    fn project_and_milestone_are_sufficient() {
+        ProjectMilestoneIssues::builder()
+            .project(1)
+            .milestone(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/milestones/1/issues")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectMilestoneIssues::builder()
+            .project("simple/project")
+            .milestone(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
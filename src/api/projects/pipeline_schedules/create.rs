@@ -0,0 +1,226 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Create a new pipeline schedule on a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreatePipelineSchedule<'a> {
+    /// The project to create the pipeline schedule within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The description of the pipeline schedule.
+    #[builder(setter(into))]
+    description: Cow<'a, str>,
+    /// The branch or tag to run pipelines for.
+    #[builder(setter(into))]
+    ref_: Cow<'a, str>,
+    /// When to run the pipeline using cron syntax (e.g., `0 1 * * *`).
+    #[builder(setter(into))]
+    cron: Cow<'a, str>,
+
+    /// The timezone to interpret the cron expression in.
+    ///
+    /// Uses timezone names as understood by Rails (e.g., `UTC` or `America/New_York`).
+    /// Defaults to `UTC`.
+    #[builder(setter(into), default)]
+    cron_timezone: Option<Cow<'a, str>>,
+    /// Whether the schedule is active or not.
+    ///
+    /// Inactive schedules do not run. Defaults to `true`.
+    #[builder(default)]
+    active: Option<bool>,
+}
+
+impl<'a> CreatePipelineSchedule<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreatePipelineScheduleBuilder<'a> {
+        CreatePipelineScheduleBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreatePipelineSchedule<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/pipeline_schedules", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("description", &self.description)
+            .push("ref", &self.ref_)
+            .push("cron", &self.cron)
+            .push_opt("cron_timezone", self.cron_timezone.as_ref())
+            .push_opt("active", self.active);
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for CreatePipelineSchedule<'a> {
+    type Response = types::PipelineSchedule;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::pipeline_schedules::{
+        CreatePipelineSchedule, CreatePipelineScheduleBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = CreatePipelineSchedule::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreatePipelineScheduleBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreatePipelineSchedule::builder()
+            .description("nightly")
+            .ref_("master")
+            .cron("0 1 * * *")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreatePipelineScheduleBuilderError, "project");
+    }
+
+    #[test]
+    fn description_is_needed() {
+        let err = CreatePipelineSchedule::builder()
+            .project(1)
+            .ref_("master")
+            .cron("0 1 * * *")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreatePipelineScheduleBuilderError, "description");
+    }
+
+    #[test]
+    fn ref_is_needed() {
+        let err = CreatePipelineSchedule::builder()
+            .project(1)
+            .description("nightly")
+            .cron("0 1 * * *")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreatePipelineScheduleBuilderError, "ref_");
+    }
+
+    #[test]
+    fn cron_is_needed() {
+        let err = CreatePipelineSchedule::builder()
+            .project(1)
+            .description("nightly")
+            .ref_("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreatePipelineScheduleBuilderError, "cron");
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        CreatePipelineSchedule::builder()
+            .project(1)
+            .description("nightly")
+            .ref_("master")
+            .cron("0 1 * * *")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/pipeline_schedules")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "description=nightly",
+                "&ref=master",
+                "&cron=0+1+*+*+*",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreatePipelineSchedule::builder()
+            .project("simple/project")
+            .description("nightly")
+            .ref_("master")
+            .cron("0 1 * * *")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_cron_timezone() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/pipeline_schedules")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "description=nightly",
+                "&ref=master",
+                "&cron=0+1+*+*+*",
+                "&cron_timezone=America%2FNew_York",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreatePipelineSchedule::builder()
+            .project("simple/project")
+            .description("nightly")
+            .ref_("master")
+            .cron("0 1 * * *")
+            .cron_timezone("America/New_York")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_active() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/pipeline_schedules")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "description=nightly",
+                "&ref=master",
+                "&cron=0+1+*+*+*",
+                "&active=false",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreatePipelineSchedule::builder()
+            .project("simple/project")
+            .description("nightly")
+            .ref_("master")
+            .cron("0 1 * * *")
+            .active(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Delete a pipeline schedule.
+#[derive(Debug, Builder, Clone)]
+pub struct DeletePipelineSchedule<'a> {
+    /// The project of the pipeline schedule.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the pipeline schedule.
+    schedule: u64,
+}
+
+impl<'a> DeletePipelineSchedule<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeletePipelineScheduleBuilder<'a> {
+        DeletePipelineScheduleBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeletePipelineSchedule<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/pipeline_schedules/{}",
+            self.project, self.schedule,
+        )
+        .into()
+    }
+}
+
+impl<'a> TypedEndpoint for DeletePipelineSchedule<'a> {
+    type Response = types::PipelineSchedule;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::pipeline_schedules::{
+        DeletePipelineSchedule, DeletePipelineScheduleBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_schedule_are_needed() {
+        let err = DeletePipelineSchedule::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeletePipelineScheduleBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeletePipelineSchedule::builder()
+            .schedule(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeletePipelineScheduleBuilderError, "project");
+    }
+
+    #[test]
+    fn schedule_is_needed() {
+        let err = DeletePipelineSchedule::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeletePipelineScheduleBuilderError, "schedule");
+    }
+
+    #[test]
+    fn project_and_schedule_are_sufficient() {
+        DeletePipelineSchedule::builder()
+            .project(1)
+            .schedule(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/pipeline_schedules/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeletePipelineSchedule::builder()
+            .project("simple/project")
+            .schedule(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
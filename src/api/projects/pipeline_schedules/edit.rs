@@ -0,0 +1,170 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Edit a pipeline schedule on a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditPipelineSchedule<'a> {
+    /// The project of the pipeline schedule.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the pipeline schedule.
+    schedule: u64,
+
+    /// The description of the pipeline schedule.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// The branch or tag to run pipelines for.
+    #[builder(setter(into), default)]
+    ref_: Option<Cow<'a, str>>,
+    /// When to run the pipeline using cron syntax (e.g., `0 1 * * *`).
+    #[builder(setter(into), default)]
+    cron: Option<Cow<'a, str>>,
+    /// The timezone to interpret the cron expression in.
+    ///
+    /// Uses timezone names as understood by Rails (e.g., `UTC` or `America/New_York`).
+    #[builder(setter(into), default)]
+    cron_timezone: Option<Cow<'a, str>>,
+    /// Whether the schedule is active or not.
+    #[builder(default)]
+    active: Option<bool>,
+}
+
+impl<'a> EditPipelineSchedule<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditPipelineScheduleBuilder<'a> {
+        EditPipelineScheduleBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditPipelineSchedule<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/pipeline_schedules/{}",
+            self.project, self.schedule,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("description", self.description.as_ref())
+            .push_opt("ref", self.ref_.as_ref())
+            .push_opt("cron", self.cron.as_ref())
+            .push_opt("cron_timezone", self.cron_timezone.as_ref())
+            .push_opt("active", self.active);
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for EditPipelineSchedule<'a> {
+    type Response = types::PipelineSchedule;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::pipeline_schedules::{
+        EditPipelineSchedule, EditPipelineScheduleBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_schedule_are_needed() {
+        let err = EditPipelineSchedule::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditPipelineScheduleBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditPipelineSchedule::builder()
+            .schedule(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditPipelineScheduleBuilderError, "project");
+    }
+
+    #[test]
+    fn schedule_is_needed() {
+        let err = EditPipelineSchedule::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditPipelineScheduleBuilderError, "schedule");
+    }
+
+    #[test]
+    fn project_and_schedule_are_sufficient() {
+        EditPipelineSchedule::builder()
+            .project(1)
+            .schedule(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/pipeline_schedules/1")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditPipelineSchedule::builder()
+            .project("simple/project")
+            .schedule(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_all() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/pipeline_schedules/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "description=weekly",
+                "&ref=release",
+                "&cron=0+2+*+*+0",
+                "&cron_timezone=UTC",
+                "&active=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditPipelineSchedule::builder()
+            .project("simple/project")
+            .schedule(1)
+            .description("weekly")
+            .ref_("release")
+            .cron("0 2 * * 0")
+            .cron_timezone("UTC")
+            .active(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,98 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Query for a pipeline schedule within a project.
+#[derive(Debug, Builder, Clone)]
+pub struct PipelineSchedule<'a> {
+    /// The project of the pipeline schedule.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the pipeline schedule.
+    schedule: u64,
+}
+
+impl<'a> PipelineSchedule<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PipelineScheduleBuilder<'a> {
+        PipelineScheduleBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for PipelineSchedule<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/pipeline_schedules/{}",
+            self.project, self.schedule,
+        )
+        .into()
+    }
+}
+
+impl<'a> TypedEndpoint for PipelineSchedule<'a> {
+    type Response = types::PipelineSchedule;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::pipeline_schedules::{
+        PipelineSchedule, PipelineScheduleBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_schedule_are_needed() {
+        let err = PipelineSchedule::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineScheduleBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = PipelineSchedule::builder().schedule(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineScheduleBuilderError, "project");
+    }
+
+    #[test]
+    fn schedule_is_needed() {
+        let err = PipelineSchedule::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineScheduleBuilderError, "schedule");
+    }
+
+    #[test]
+    fn project_and_schedule_are_sufficient() {
+        PipelineSchedule::builder()
+            .project(1)
+            .schedule(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/pipeline_schedules/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PipelineSchedule::builder()
+            .project("simple/project")
+            .schedule(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,105 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Run a pipeline schedule immediately.
+///
+/// The pipeline is queued to run as soon as possible; the schedule's next run time is not
+/// affected.
+#[derive(Debug, Builder, Clone)]
+pub struct PlayPipelineSchedule<'a> {
+    /// The project of the pipeline schedule.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the pipeline schedule.
+    schedule: u64,
+}
+
+impl<'a> PlayPipelineSchedule<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PlayPipelineScheduleBuilder<'a> {
+        PlayPipelineScheduleBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for PlayPipelineSchedule<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/pipeline_schedules/{}/play",
+            self.project, self.schedule,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::pipeline_schedules::{
+        PlayPipelineSchedule, PlayPipelineScheduleBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_schedule_are_needed() {
+        let err = PlayPipelineSchedule::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, PlayPipelineScheduleBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = PlayPipelineSchedule::builder()
+            .schedule(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, PlayPipelineScheduleBuilderError, "project");
+    }
+
+    #[test]
+    fn schedule_is_needed() {
+        let err = PlayPipelineSchedule::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, PlayPipelineScheduleBuilderError, "schedule");
+    }
+
+    #[test]
+    fn project_and_schedule_are_sufficient() {
+        PlayPipelineSchedule::builder()
+            .project(1)
+            .schedule(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/pipeline_schedules/1/play")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PlayPipelineSchedule::builder()
+            .project("simple/project")
+            .schedule(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,123 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Take ownership of a pipeline schedule.
+///
+/// Pipelines started by the schedule will run as the user taking ownership.
+#[derive(Debug, Builder, Clone)]
+pub struct TakePipelineScheduleOwnership<'a> {
+    /// The project of the pipeline schedule.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the pipeline schedule.
+    schedule: u64,
+}
+
+impl<'a> TakePipelineScheduleOwnership<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TakePipelineScheduleOwnershipBuilder<'a> {
+        TakePipelineScheduleOwnershipBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for TakePipelineScheduleOwnership<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/pipeline_schedules/{}/take_ownership",
+            self.project, self.schedule,
+        )
+        .into()
+    }
+}
+
+impl<'a> TypedEndpoint for TakePipelineScheduleOwnership<'a> {
+    type Response = types::PipelineSchedule;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::pipeline_schedules::{
+        TakePipelineScheduleOwnership, TakePipelineScheduleOwnershipBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_schedule_are_needed() {
+        let err = TakePipelineScheduleOwnership::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            TakePipelineScheduleOwnershipBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = TakePipelineScheduleOwnership::builder()
+            .schedule(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            TakePipelineScheduleOwnershipBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn schedule_is_needed() {
+        let err = TakePipelineScheduleOwnership::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            TakePipelineScheduleOwnershipBuilderError,
+            "schedule"
+        );
+    }
+
+    #[test]
+    fn project_and_schedule_are_sufficient() {
+        TakePipelineScheduleOwnership::builder()
+            .project(1)
+            .schedule(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/pipeline_schedules/1/take_ownership")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TakePipelineScheduleOwnership::builder()
+            .project("simple/project")
+            .schedule(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,188 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, VariableType};
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Update a variable on a pipeline schedule.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct UpdatePipelineScheduleVariable<'a> {
+    /// The project of the pipeline schedule.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the pipeline schedule.
+    schedule: u64,
+    /// The key of the variable.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+    /// The value of the variable.
+    #[builder(setter(into))]
+    value: Cow<'a, str>,
+
+    /// The type of the variable.
+    #[builder(default)]
+    variable_type: Option<VariableType>,
+}
+
+impl<'a> UpdatePipelineScheduleVariable<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UpdatePipelineScheduleVariableBuilder<'a> {
+        UpdatePipelineScheduleVariableBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UpdatePipelineScheduleVariable<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/pipeline_schedules/{}/variables/{}",
+            self.project,
+            self.schedule,
+            common::path_escaped(&self.key),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("value", &self.value)
+            .push_opt("variable_type", self.variable_type);
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for UpdatePipelineScheduleVariable<'a> {
+    type Response = types::PipelineVariable;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::common::VariableType;
+    use crate::api::projects::pipeline_schedules::variables::{
+        UpdatePipelineScheduleVariable, UpdatePipelineScheduleVariableBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = UpdatePipelineScheduleVariable::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            UpdatePipelineScheduleVariableBuilderError,
+            "project",
+        );
+    }
+
+    #[test]
+    fn schedule_is_needed() {
+        let err = UpdatePipelineScheduleVariable::builder()
+            .project(1)
+            .key("testkey")
+            .value("testvalue")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            UpdatePipelineScheduleVariableBuilderError,
+            "schedule",
+        );
+    }
+
+    #[test]
+    fn key_is_needed() {
+        let err = UpdatePipelineScheduleVariable::builder()
+            .project(1)
+            .schedule(1)
+            .value("testvalue")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdatePipelineScheduleVariableBuilderError, "key");
+    }
+
+    #[test]
+    fn value_is_needed() {
+        let err = UpdatePipelineScheduleVariable::builder()
+            .project(1)
+            .schedule(1)
+            .key("testkey")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            UpdatePipelineScheduleVariableBuilderError,
+            "value",
+        );
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        UpdatePipelineScheduleVariable::builder()
+            .project(1)
+            .schedule(1)
+            .key("testkey")
+            .value("testvalue")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/pipeline_schedules/1/variables/testkey")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=testvalue")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdatePipelineScheduleVariable::builder()
+            .project("simple/project")
+            .schedule(1)
+            .key("testkey")
+            .value("testvalue")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_variable_type() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/pipeline_schedules/1/variables/testkey")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=testvalue", "&variable_type=env_var"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdatePipelineScheduleVariable::builder()
+            .project("simple/project")
+            .schedule(1)
+            .key("testkey")
+            .value("testvalue")
+            .variable_type(VariableType::EnvVar)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,6 +8,7 @@
 //!
 //! These endpoints are used for querying CI pipelines.
 
+mod bridges;
 mod cancel;
 mod create;
 mod delete;
@@ -15,7 +16,16 @@ mod jobs;
 mod pipeline;
 mod pipelines;
 mod retry;
+mod test_report;
+mod test_report_summary;
+mod trigger;
+mod trigger_webhook;
 mod variables;
+mod wait;
+
+pub use self::bridges::PipelineBridges;
+pub use self::bridges::PipelineBridgesBuilder;
+pub use self::bridges::PipelineBridgesBuilderError;
 
 pub use self::cancel::CancelPipeline;
 pub use self::cancel::CancelPipelineBuilder;
@@ -53,6 +63,26 @@ pub use self::retry::RetryPipeline;
 pub use self::retry::RetryPipelineBuilder;
 pub use self::retry::RetryPipelineBuilderError;
 
+pub use self::test_report::PipelineTestReport;
+pub use self::test_report::PipelineTestReportBuilder;
+pub use self::test_report::PipelineTestReportBuilderError;
+
+pub use self::test_report_summary::PipelineTestReportSummary;
+pub use self::test_report_summary::PipelineTestReportSummaryBuilder;
+pub use self::test_report_summary::PipelineTestReportSummaryBuilderError;
+
+pub use self::trigger::TriggerPipeline;
+pub use self::trigger::TriggerPipelineBuilder;
+pub use self::trigger::TriggerPipelineBuilderError;
+
+pub use self::trigger_webhook::TriggerPipelineWebhook;
+pub use self::trigger_webhook::TriggerPipelineWebhookBuilder;
+pub use self::trigger_webhook::TriggerPipelineWebhookBuilderError;
+
 pub use self::variables::PipelineVariables;
 pub use self::variables::PipelineVariablesBuilder;
 pub use self::variables::PipelineVariablesBuilderError;
+
+pub use self::wait::PipelineWaiter;
+pub use self::wait::PipelineWaiterBuilder;
+pub use self::wait::PipelineWaiterBuilderError;
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Cancel a pipeline.
 #[derive(Debug, Builder, Clone)]
@@ -40,6 +41,10 @@ impl<'a> Endpoint for CancelPipeline<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CancelPipeline<'a> {
+    type Response = types::Pipeline;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
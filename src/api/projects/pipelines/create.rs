@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// The type of a pipeline variable.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -136,6 +137,10 @@ impl<'a> Endpoint for CreatePipeline<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreatePipeline<'a> {
+    type Response = types::Pipeline;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
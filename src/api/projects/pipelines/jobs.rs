@@ -11,6 +11,7 @@ use derive_builder::Builder;
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::jobs::JobScope;
+use crate::types;
 
 /// Query for jobs within a pipeline.
 #[derive(Debug, Builder, Clone)]
@@ -74,6 +75,10 @@ impl<'a> Endpoint for PipelineJobs<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for PipelineJobs<'a> {
+    type Response = Vec<types::Job>;
+}
+
 impl<'a> Pageable for PipelineJobs<'a> {}
 
 #[cfg(test)]
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query a single pipeline on a project.
 #[derive(Debug, Builder, Clone)]
@@ -36,6 +37,10 @@ impl<'a> Endpoint for Pipeline<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Pipeline<'a> {
+    type Response = types::Pipeline;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::pipelines::{Pipeline, PipelineBuilderError};
@@ -10,6 +10,7 @@ use derive_builder::Builder;
 use crate::api::common::{NameOrId, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Scopes for pipelines.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -290,6 +291,10 @@ impl<'a> Endpoint for Pipelines<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Pipelines<'a> {
+    type Response = Vec<types::PipelineBasic>;
+}
+
 impl<'a> Pageable for Pipelines<'a> {}
 
 #[cfg(test)]
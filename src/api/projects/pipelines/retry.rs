@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Retry a pipeline.
 ///
@@ -42,6 +43,10 @@ impl<'a> Endpoint for RetryPipeline<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for RetryPipeline<'a> {
+    type Response = types::Pipeline;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
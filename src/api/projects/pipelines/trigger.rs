@@ -0,0 +1,200 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Trigger a new pipeline on a project using a trigger token.
+///
+/// The trigger token authenticates the request, so this endpoint may also be used with an
+/// unauthenticated client. The pipeline runs as the owner of the trigger.
+#[derive(Debug, Builder, Clone)]
+pub struct TriggerPipeline<'a> {
+    /// The project to create the pipeline within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The trigger token.
+    #[builder(setter(into))]
+    token: Cow<'a, str>,
+    /// The ref to create the pipeline for.
+    #[builder(setter(into))]
+    ref_: Cow<'a, str>,
+
+    /// Variables to pass to the pipeline.
+    #[builder(setter(name = "_variables"), default, private)]
+    variables: BTreeMap<Cow<'a, str>, Cow<'a, str>>,
+}
+
+impl<'a> TriggerPipeline<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TriggerPipelineBuilder<'a> {
+        TriggerPipelineBuilder::default()
+    }
+}
+
+impl<'a> TriggerPipelineBuilder<'a> {
+    /// Add a variable.
+    pub fn variable<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.variables
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Add multiple variables.
+    pub fn variables<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.variables
+            .get_or_insert_with(BTreeMap::new)
+            .extend(iter.map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+}
+
+impl<'a> Endpoint for TriggerPipeline<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/trigger/pipeline", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("token", &self.token)
+            .push("ref", &self.ref_)
+            .extend(
+                self.variables
+                    .iter()
+                    .map(|(key, value)| (format!("variables[{}]", key), value)),
+            );
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for TriggerPipeline<'a> {
+    type Response = types::Pipeline;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::pipelines::{TriggerPipeline, TriggerPipelineBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_token_and_ref_are_needed() {
+        let err = TriggerPipeline::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = TriggerPipeline::builder()
+            .token("secret")
+            .ref_("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineBuilderError, "project");
+    }
+
+    #[test]
+    fn token_is_needed() {
+        let err = TriggerPipeline::builder()
+            .project(1)
+            .ref_("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineBuilderError, "token");
+    }
+
+    #[test]
+    fn ref_is_needed() {
+        let err = TriggerPipeline::builder()
+            .project(1)
+            .token("secret")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineBuilderError, "ref_");
+    }
+
+    #[test]
+    fn project_token_and_ref_are_sufficient() {
+        TriggerPipeline::builder()
+            .project(1)
+            .token("secret")
+            .ref_("master")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/trigger/pipeline")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("token=secret", "&ref=master"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TriggerPipeline::builder()
+            .project("simple/project")
+            .token("secret")
+            .ref_("master")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_variables() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/trigger/pipeline")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "token=secret",
+                "&ref=master",
+                "&variables%5BDEPLOY%5D=true",
+                "&variables%5BTARGET%5D=staging",
+                "&variables%5BUPSTREAM%5D=a%26b",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TriggerPipeline::builder()
+            .project("simple/project")
+            .token("secret")
+            .ref_("master")
+            .variable("TARGET", "staging")
+            .variables([("UPSTREAM", "a&b"), ("DEPLOY", "true")].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
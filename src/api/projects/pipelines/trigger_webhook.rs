@@ -0,0 +1,201 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Trigger a new pipeline on a project using the webhook form of a trigger token.
+///
+/// All information is passed in the URL, which makes this suitable for use as a webhook in
+/// other services. As with `TriggerPipeline`, the trigger token authenticates the request, so
+/// this endpoint may be used with an unauthenticated client.
+#[derive(Debug, Builder, Clone)]
+pub struct TriggerPipelineWebhook<'a> {
+    /// The project to create the pipeline within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ref to create the pipeline for.
+    #[builder(setter(into))]
+    ref_: Cow<'a, str>,
+    /// The trigger token.
+    #[builder(setter(into))]
+    token: Cow<'a, str>,
+
+    /// Variables to pass to the pipeline.
+    #[builder(setter(name = "_variables"), default, private)]
+    variables: BTreeMap<Cow<'a, str>, Cow<'a, str>>,
+}
+
+impl<'a> TriggerPipelineWebhook<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TriggerPipelineWebhookBuilder<'a> {
+        TriggerPipelineWebhookBuilder::default()
+    }
+}
+
+impl<'a> TriggerPipelineWebhookBuilder<'a> {
+    /// Add a variable.
+    pub fn variable<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.variables
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Add multiple variables.
+    pub fn variables<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.variables
+            .get_or_insert_with(BTreeMap::new)
+            .extend(iter.map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+}
+
+impl<'a> Endpoint for TriggerPipelineWebhook<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/ref/{}/trigger/pipeline",
+            self.project,
+            common::path_escaped(&self.ref_),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push("token", &self.token).extend(
+            self.variables
+                .iter()
+                .map(|(key, value)| (format!("variables[{}]", key), value)),
+        );
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for TriggerPipelineWebhook<'a> {
+    type Response = types::Pipeline;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::pipelines::{
+        TriggerPipelineWebhook, TriggerPipelineWebhookBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_ref_and_token_are_needed() {
+        let err = TriggerPipelineWebhook::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineWebhookBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = TriggerPipelineWebhook::builder()
+            .ref_("master")
+            .token("secret")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineWebhookBuilderError, "project");
+    }
+
+    #[test]
+    fn ref_is_needed() {
+        let err = TriggerPipelineWebhook::builder()
+            .project(1)
+            .token("secret")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineWebhookBuilderError, "ref_");
+    }
+
+    #[test]
+    fn token_is_needed() {
+        let err = TriggerPipelineWebhook::builder()
+            .project(1)
+            .ref_("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineWebhookBuilderError, "token");
+    }
+
+    #[test]
+    fn project_ref_and_token_are_sufficient() {
+        TriggerPipelineWebhook::builder()
+            .project(1)
+            .ref_("master")
+            .token("secret")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/ref/topic%2Fbranch/trigger/pipeline")
+            .add_query_params(&[("token", "secret")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TriggerPipelineWebhook::builder()
+            .project("simple/project")
+            .ref_("topic/branch")
+            .token("secret")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_variables() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/ref/master/trigger/pipeline")
+            .add_query_params(&[
+                ("token", "secret"),
+                ("variables[DEPLOY]", "true"),
+                ("variables[TARGET]", "staging"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TriggerPipelineWebhook::builder()
+            .project("simple/project")
+            .ref_("master")
+            .token("secret")
+            .variable("TARGET", "staging")
+            .variable("DEPLOY", "true")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for the variables of a pipeline.
 #[derive(Debug, Builder, Clone)]
@@ -40,6 +41,10 @@ impl<'a> Endpoint for PipelineVariables<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for PipelineVariables<'a> {
+    type Response = Vec<types::PipelineVariable>;
+}
+
 impl<'a> Pageable for PipelineVariables<'a> {}
 
 #[cfg(test)]
@@ -0,0 +1,482 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::thread;
+use std::time::Duration;
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::pipelines::{Pipeline, PipelineJobs};
+use crate::api::projects::wait::{self, Poller, WaitError};
+use crate::api::{self, AsyncClient, AsyncQuery, Client, Paged, Pagination, Query};
+use crate::types::{self, JobId, StatusState};
+
+/// Wait for a pipeline to finish.
+///
+/// The pipeline is polled until it succeeds, fails, is canceled, or is skipped. Note that a
+/// pipeline which is blocked on a manual job will not finish on its own; use a timeout to avoid
+/// waiting on it forever.
+///
+/// ```rust,no_run
+/// # use gitlab::api::projects::pipelines::PipelineWaiter;
+/// # use std::time::Duration;
+/// # let client = gitlab::Gitlab::new("gitlab.com", "private-token").unwrap();
+/// let waiter = PipelineWaiter::builder()
+///     .project("group/project")
+///     .pipeline(1)
+///     .interval(Duration::from_secs(5))
+///     .backoff(1.5)
+///     .max_interval(Duration::from_secs(60))
+///     .timeout(Duration::from_secs(3600))
+///     .build()
+///     .unwrap();
+/// let pipeline = waiter
+///     .wait_with(&client, |job| println!("{}: {:?}", job.name, job.status))
+///     .unwrap();
+/// println!("pipeline finished: {:?}", pipeline.status);
+/// ```
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct PipelineWaiter<'a> {
+    /// The project of the pipeline.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the pipeline.
+    pipeline: u64,
+
+    /// How long to wait between the first polls.
+    ///
+    /// Defaults to 3 seconds.
+    #[builder(default = "Duration::from_secs(3)")]
+    interval: Duration,
+    /// The scale parameter for the interval after each poll.
+    ///
+    /// Must be finite and at least `1.0`. Defaults to `1.0` (a constant interval).
+    #[builder(default = "1.0")]
+    backoff: f64,
+    /// The longest interval to wait between polls.
+    #[builder(default)]
+    max_interval: Option<Duration>,
+    /// How long to wait for the pipeline to finish.
+    ///
+    /// By default, there is no timeout.
+    #[builder(default)]
+    timeout: Option<Duration>,
+}
+
+impl<'a> PipelineWaiterBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        wait::validate_backoff(self.backoff)
+    }
+}
+
+impl<'a> PipelineWaiter<'a> {
+    /// Create a builder for the waiter.
+    pub fn builder() -> PipelineWaiterBuilder<'a> {
+        PipelineWaiterBuilder::default()
+    }
+
+    /// Wait for the pipeline to finish.
+    ///
+    /// Blocks the current thread between polls.
+    pub fn wait<C>(&self, client: &C) -> Result<types::Pipeline, WaitError<C::Error>>
+    where
+        C: Client,
+    {
+        self.wait_impl(client, None::<fn(&types::Job)>)
+    }
+
+    /// Wait for the pipeline to finish, reporting job status changes.
+    ///
+    /// The callback is called with each job of the pipeline when it is first seen and whenever
+    /// its status changes. Blocks the current thread between polls.
+    pub fn wait_with<C, F>(
+        &self,
+        client: &C,
+        on_job: F,
+    ) -> Result<types::Pipeline, WaitError<C::Error>>
+    where
+        C: Client,
+        F: FnMut(&types::Job),
+    {
+        self.wait_impl(client, Some(on_job))
+    }
+
+    /// Wait for the pipeline to finish asynchronously.
+    ///
+    /// The `sleep` function is used to wait between polls so that any async runtime may be used
+    /// (e.g., `tokio::time::sleep`).
+    pub async fn wait_async<C, S, Fut>(
+        &self,
+        client: &C,
+        sleep: S,
+    ) -> Result<types::Pipeline, WaitError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        S: Fn(Duration) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        self.wait_async_impl(client, sleep, None::<fn(&types::Job)>)
+            .await
+    }
+
+    /// Wait for the pipeline to finish asynchronously, reporting job status changes.
+    ///
+    /// See `wait_with` and `wait_async`.
+    pub async fn wait_with_async<C, S, Fut, F>(
+        &self,
+        client: &C,
+        sleep: S,
+        on_job: F,
+    ) -> Result<types::Pipeline, WaitError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        S: Fn(Duration) -> Fut,
+        Fut: Future<Output = ()>,
+        F: FnMut(&types::Job),
+    {
+        self.wait_async_impl(client, sleep, Some(on_job)).await
+    }
+
+    fn wait_impl<C, F>(
+        &self,
+        client: &C,
+        mut on_job: Option<F>,
+    ) -> Result<types::Pipeline, WaitError<C::Error>>
+    where
+        C: Client,
+        F: FnMut(&types::Job),
+    {
+        let mut state = WaitState::new(self);
+
+        loop {
+            // Query the pipeline first so that all jobs are seen in their final state.
+            let pipeline: types::Pipeline = state.pipeline.query(client)?;
+            if let Some(on_job) = on_job.as_mut() {
+                let jobs: Vec<types::Job> = state.jobs.query(client)?;
+                state.report(jobs, on_job);
+            }
+            if wait::is_finished(pipeline.status) {
+                return Ok(pipeline);
+            }
+
+            thread::sleep(state.poller.next_delay(pipeline.status)?);
+        }
+    }
+
+    async fn wait_async_impl<C, S, Fut, F>(
+        &self,
+        client: &C,
+        sleep: S,
+        mut on_job: Option<F>,
+    ) -> Result<types::Pipeline, WaitError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        S: Fn(Duration) -> Fut,
+        Fut: Future<Output = ()>,
+        F: FnMut(&types::Job),
+    {
+        let mut state = WaitState::new(self);
+
+        loop {
+            // Query the pipeline first so that all jobs are seen in their final state.
+            let pipeline: types::Pipeline = state.pipeline.query_async(client).await?;
+            if let Some(on_job) = on_job.as_mut() {
+                let jobs: Vec<types::Job> = state.jobs.query_async(client).await?;
+                state.report(jobs, on_job);
+            }
+            if wait::is_finished(pipeline.status) {
+                return Ok(pipeline);
+            }
+
+            sleep(state.poller.next_delay(pipeline.status)?).await;
+        }
+    }
+}
+
+struct WaitState<'a> {
+    pipeline: Pipeline<'a>,
+    jobs: Paged<PipelineJobs<'a>>,
+    poller: Poller,
+    statuses: HashMap<JobId, StatusState>,
+}
+
+impl<'a> WaitState<'a> {
+    fn new(waiter: &PipelineWaiter<'a>) -> Self {
+        let pipeline = Pipeline::builder()
+            .project(waiter.project.clone())
+            .pipeline(waiter.pipeline)
+            .build()
+            .expect("the project and pipeline are always set");
+        let jobs = PipelineJobs::builder()
+            .project(waiter.project.clone())
+            .pipeline(waiter.pipeline)
+            .build()
+            .expect("the project and pipeline are always set");
+
+        Self {
+            pipeline,
+            jobs: api::paged(jobs, Pagination::All),
+            poller: Poller::new(
+                waiter.interval,
+                waiter.backoff,
+                waiter.max_interval,
+                waiter.timeout,
+            ),
+            statuses: HashMap::new(),
+        }
+    }
+
+    fn report<F>(&mut self, jobs: Vec<types::Job>, on_job: &mut F)
+    where
+        F: FnMut(&types::Job),
+    {
+        for job in jobs {
+            if self.statuses.insert(job.id, job.status) != Some(job.status) {
+                on_job(&job);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http::{Method, StatusCode};
+    use serde_json::{json, Value};
+
+    use crate::api::projects::pipelines::{
+        PipelineWaiter, PipelineWaiterBuilder, PipelineWaiterBuilderError,
+    };
+    use crate::api::projects::WaitError;
+    use crate::testing::mock::{MockClient, MockResponse, Route};
+    use crate::types::{JobId, StatusState};
+
+    const PIPELINE: &str = "projects/simple%2Fproject/pipelines/1";
+    const JOBS: &str = "projects/simple%2Fproject/pipelines/1/jobs";
+
+    fn waiter() -> PipelineWaiterBuilder<'static> {
+        let mut builder = PipelineWaiter::builder();
+        builder
+            .project("simple/project")
+            .pipeline(1)
+            .interval(Duration::from_secs(0));
+        builder
+    }
+
+    fn pipeline(status: &str) -> MockResponse {
+        let mut pipeline: Value =
+            serde_json::from_str(include_str!("../../../../data/pipeline.json")).unwrap();
+        pipeline["status"] = json!(status);
+        MockResponse::json(&pipeline)
+    }
+
+    fn jobs(statuses: &[(u64, &str)]) -> MockResponse {
+        let jobs: Vec<Value> =
+            serde_json::from_str(include_str!("../../../../data/job.json")).unwrap();
+        let jobs: Vec<_> = statuses
+            .iter()
+            .map(|(id, status)| {
+                let mut job = jobs[0].clone();
+                job["id"] = json!(id);
+                job["status"] = json!(status);
+                job
+            })
+            .collect();
+        MockResponse::json(&jobs)
+    }
+
+    #[test]
+    fn project_and_pipeline_are_needed() {
+        let err = PipelineWaiter::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineWaiterBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = PipelineWaiter::builder().pipeline(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineWaiterBuilderError, "project");
+    }
+
+    #[test]
+    fn pipeline_is_needed() {
+        let err = PipelineWaiter::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineWaiterBuilderError, "pipeline");
+    }
+
+    #[test]
+    fn project_and_pipeline_are_sufficient() {
+        PipelineWaiter::builder()
+            .project(1)
+            .pipeline(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn backoff_must_not_shrink() {
+        let err = PipelineWaiter::builder()
+            .project(1)
+            .pipeline(1)
+            .backoff(0.5)
+            .build()
+            .unwrap_err();
+        if let PipelineWaiterBuilderError::ValidationError(message) = err {
+            assert_eq!(
+                message,
+                "the backoff must be a finite number of at least 1.0, not 0.5",
+            );
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn backoff_must_be_finite() {
+        let err = PipelineWaiter::builder()
+            .project(1)
+            .pipeline(1)
+            .backoff(f64::NAN)
+            .build()
+            .unwrap_err();
+        if let PipelineWaiterBuilderError::ValidationError(_) = err {
+            // expected
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn wait() {
+        let client = MockClient::new();
+        let route = client.add(
+            Route::new(Method::GET, PIPELINE)
+                .respond(pipeline("pending"))
+                .respond(pipeline("running"))
+                .respond(pipeline("success")),
+        );
+        let jobs_route = client.add(Route::new(Method::GET, JOBS));
+
+        let pipeline = waiter().build().unwrap().wait(&client).unwrap();
+        assert_eq!(pipeline.status, StatusState::Success);
+        assert_eq!(client.calls(route), 3);
+        assert_eq!(client.calls(jobs_route), 0);
+    }
+
+    #[test]
+    fn wait_with_jobs() {
+        let client = MockClient::new();
+        client.add(
+            Route::new(Method::GET, PIPELINE)
+                .respond(pipeline("running"))
+                .respond(pipeline("running"))
+                .respond(pipeline("failed")),
+        );
+        client.add(
+            Route::new(Method::GET, JOBS)
+                .respond(jobs(&[(1, "running"), (2, "created")]))
+                .respond(jobs(&[(1, "running"), (2, "created")]))
+                .respond(jobs(&[(1, "success"), (2, "failed")])),
+        );
+
+        let mut changes = Vec::new();
+        let pipeline = waiter()
+            .build()
+            .unwrap()
+            .wait_with(&client, |job| changes.push((job.id, job.status)))
+            .unwrap();
+        assert_eq!(pipeline.status, StatusState::Failed);
+        assert_eq!(
+            changes,
+            [
+                (JobId::new(1), StatusState::Running),
+                (JobId::new(2), StatusState::Created),
+                (JobId::new(1), StatusState::Success),
+                (JobId::new(2), StatusState::Failed),
+            ],
+        );
+    }
+
+    #[test]
+    fn wait_timeout() {
+        let client = MockClient::new();
+        let route = client.add(Route::new(Method::GET, PIPELINE).respond(pipeline("manual")));
+
+        let err = waiter()
+            .timeout(Duration::from_secs(0))
+            .build()
+            .unwrap()
+            .wait(&client)
+            .unwrap_err();
+        if let WaitError::Timeout {
+            status, ..
+        } = err
+        {
+            assert_eq!(status, StatusState::Manual);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        assert_eq!(client.calls(route), 1);
+    }
+
+    #[test]
+    fn wait_error() {
+        let client = MockClient::new();
+        client.add(
+            Route::new(Method::GET, PIPELINE)
+                .respond(MockResponse::error(StatusCode::NOT_FOUND, "404 Not found")),
+        );
+
+        let err = waiter().build().unwrap().wait(&client).unwrap_err();
+        if let WaitError::Api {
+            source,
+        } = err
+        {
+            assert_eq!(source.status(), Some(StatusCode::NOT_FOUND));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn wait_with_async() {
+        let client = MockClient::new();
+        client.add(
+            Route::new(Method::GET, PIPELINE)
+                .respond(pipeline("running"))
+                .respond(pipeline("canceled")),
+        );
+        client.add(
+            Route::new(Method::GET, JOBS)
+                .respond(jobs(&[(1, "running")]))
+                .respond(jobs(&[(1, "canceled")])),
+        );
+
+        let mut changes = Vec::new();
+        let pipeline = waiter()
+            .build()
+            .unwrap()
+            .wait_with_async(
+                &client,
+                |_| async {},
+                |job| changes.push((job.id, job.status)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(pipeline.status, StatusState::Canceled);
+        assert_eq!(
+            changes,
+            [
+                (JobId::new(1), StatusState::Running),
+                (JobId::new(1), StatusState::Canceled),
+            ],
+        );
+    }
+}
@@ -12,6 +12,7 @@ use derive_builder::Builder;
 use crate::api::common::{AccessLevel, CommaSeparatedList, SortOrder, VisibilityLevel};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Keys project results may be ordered by.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -293,6 +294,10 @@ impl<'a> Endpoint for Projects<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Projects<'a> {
+    type Response = Vec<types::Project>;
+}
+
 impl<'a> Pageable for Projects<'a> {
     fn use_keyset_pagination(&self) -> bool {
         self.order_by
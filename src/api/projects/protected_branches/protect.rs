@@ -11,6 +11,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 #[deprecated(note = "use `api/common/ProtectedAccessLevel` instead")]
 pub use crate::api::common::ProtectedAccessLevel;
@@ -174,6 +175,10 @@ impl<'a> Endpoint for ProtectBranch<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for ProtectBranch<'a> {
+    type Response = types::ProtectedRepoBranch;
+}
+
 #[cfg(test)]
 mod tests {
     use std::cmp;
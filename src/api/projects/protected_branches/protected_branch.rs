@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{self, NameOrId};
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query a protected branch of a project.
 #[derive(Debug, Clone, Builder)]
@@ -47,6 +48,10 @@ impl<'a> Endpoint for ProtectedBranch<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for ProtectedBranch<'a> {
+    type Response = types::ProtectedRepoBranch;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::protected_branches::{ProtectedBranch, ProtectedBranchBuilderError};
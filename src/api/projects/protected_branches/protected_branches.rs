@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query protected branches of a project.
 #[derive(Debug, Clone, Builder)]
@@ -49,6 +50,10 @@ impl<'a> Endpoint for ProtectedBranches<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for ProtectedBranches<'a> {
+    type Response = Vec<types::ProtectedRepoBranch>;
+}
+
 impl<'a> Pageable for ProtectedBranches<'a> {}
 
 #[cfg(test)]
@@ -11,6 +11,7 @@ use derive_builder::Builder;
 use crate::api::common::{NameOrId, ProtectedAccessLevel};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::protected_tags::ProtectedAccess;
+use crate::types;
 
 /// Protect a tag or set of tags on a project.
 #[derive(Debug, Builder, Clone)]
@@ -71,6 +72,10 @@ impl<'a> Endpoint for ProtectTag<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for ProtectTag<'a> {
+    type Response = types::ProtectedTag;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{self, NameOrId};
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query a protected tag of a project.
 #[derive(Debug, Clone, Builder)]
@@ -47,6 +48,10 @@ impl<'a> Endpoint for ProtectedTag<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for ProtectedTag<'a> {
+    type Response = types::ProtectedTag;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::protected_tags::{ProtectedTag, ProtectedTagBuilderError};
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query protected tags of a project.
 #[derive(Debug, Clone, Builder)]
@@ -38,6 +39,10 @@ impl<'a> Endpoint for ProtectedTags<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for ProtectedTags<'a> {
+    type Response = Vec<types::ProtectedTag>;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::protected_tags::{ProtectedTags, ProtectedTagsBuilderError};
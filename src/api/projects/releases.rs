@@ -6,11 +6,40 @@
 
 //! Project release API endpoints.
 //!
-//! These endpoints are used for querying project releases.
+//! These endpoints are used for querying and modifying project releases.
 
+mod create;
+mod delete;
+mod latest;
+pub mod links;
+mod release;
 mod releases;
+mod update;
+
+pub use self::create::CreateProjectRelease;
+pub use self::create::CreateProjectReleaseBuilder;
+pub use self::create::CreateProjectReleaseBuilderError;
+pub use self::create::ReleaseAssetLink;
+pub use self::create::ReleaseAssetLinkBuilder;
+pub use self::create::ReleaseAssetLinkBuilderError;
+
+pub use self::delete::DeleteProjectRelease;
+pub use self::delete::DeleteProjectReleaseBuilder;
+pub use self::delete::DeleteProjectReleaseBuilderError;
+
+pub use self::latest::LatestProjectRelease;
+pub use self::latest::LatestProjectReleaseBuilder;
+pub use self::latest::LatestProjectReleaseBuilderError;
+
+pub use self::release::ProjectRelease;
+pub use self::release::ProjectReleaseBuilder;
+pub use self::release::ProjectReleaseBuilderError;
 
 pub use self::releases::ProjectReleaseOrderBy;
 pub use self::releases::ProjectReleases;
 pub use self::releases::ProjectReleasesBuilder;
 pub use self::releases::ProjectReleasesBuilderError;
+
+pub use self::update::UpdateProjectRelease;
+pub use self::update::UpdateProjectReleaseBuilder;
+pub use self::update::UpdateProjectReleaseBuilderError;
@@ -0,0 +1,439 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::releases::links::ReleaseLinkType;
+use crate::types;
+
+/// An asset link to attach to a new release.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ReleaseAssetLink<'a> {
+    /// The name of the link.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The URL of the link.
+    #[builder(setter(into))]
+    url: Cow<'a, str>,
+
+    /// The path for a permanent link to the asset through the release.
+    #[builder(setter(into), default)]
+    direct_asset_path: Option<Cow<'a, str>>,
+    /// The type of the link.
+    #[builder(default)]
+    link_type: Option<ReleaseLinkType>,
+}
+
+impl<'a> ReleaseAssetLink<'a> {
+    /// Create a builder for an asset link.
+    pub fn builder() -> ReleaseAssetLinkBuilder<'a> {
+        ReleaseAssetLinkBuilder::default()
+    }
+
+    fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
+        params
+            .push("assets[links][][name]", &self.name)
+            .push("assets[links][][url]", &self.url)
+            .push_opt(
+                "assets[links][][direct_asset_path]",
+                self.direct_asset_path.as_ref(),
+            )
+            .push_opt("assets[links][][link_type]", self.link_type);
+    }
+}
+
+/// Create a release for a tag.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateProjectRelease<'a> {
+    /// The project to create the release in.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The tag to create the release for.
+    #[builder(setter(into))]
+    tag_name: Cow<'a, str>,
+
+    /// The name of the release.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// The description of the release (release notes).
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// The commit SHA or branch name to create the tag from if it does not exist.
+    #[builder(setter(into), default)]
+    ref_: Option<Cow<'a, str>>,
+    /// The message for an annotated tag if the tag is created.
+    #[builder(setter(into), default)]
+    tag_message: Option<Cow<'a, str>>,
+    /// The titles of milestones to associate with the release.
+    #[builder(setter(name = "_milestones"), default, private)]
+    milestones: Vec<Cow<'a, str>>,
+    /// Asset links to attach to the release.
+    #[builder(setter(name = "_assets"), default, private)]
+    assets: Vec<ReleaseAssetLink<'a>>,
+    /// When the release is (or was) made.
+    ///
+    /// Defaults to the current time.
+    #[builder(default)]
+    released_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> CreateProjectRelease<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateProjectReleaseBuilder<'a> {
+        CreateProjectReleaseBuilder::default()
+    }
+}
+
+impl<'a> CreateProjectReleaseBuilder<'a> {
+    /// Associate a milestone with the release.
+    pub fn milestone<M>(&mut self, milestone: M) -> &mut Self
+    where
+        M: Into<Cow<'a, str>>,
+    {
+        self.milestones
+            .get_or_insert_with(Vec::new)
+            .push(milestone.into());
+        self
+    }
+
+    /// Associate multiple milestones with the release.
+    pub fn milestones<I, M>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = M>,
+        M: Into<Cow<'a, str>>,
+    {
+        self.milestones
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    /// Attach an asset link to the release.
+    pub fn asset(&mut self, asset: ReleaseAssetLink<'a>) -> &mut Self {
+        self.assets.get_or_insert_with(Vec::new).push(asset);
+        self
+    }
+
+    /// Attach multiple asset links to the release.
+    pub fn assets<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = ReleaseAssetLink<'a>>,
+    {
+        self.assets.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+}
+
+impl<'a> Endpoint for CreateProjectRelease<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/releases", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("tag_name", &self.tag_name)
+            .push_opt("name", self.name.as_ref())
+            .push_opt("description", self.description.as_ref())
+            .push_opt("ref", self.ref_.as_ref())
+            .push_opt("tag_message", self.tag_message.as_ref())
+            .extend(self.milestones.iter().map(|value| ("milestones[]", value)))
+            .push_opt("released_at", self.released_at);
+
+        self.assets
+            .iter()
+            .for_each(|asset| asset.add_params(&mut params));
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for CreateProjectRelease<'a> {
+    type Response = types::Release;
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::projects::releases::links::ReleaseLinkType;
+    use crate::api::projects::releases::{
+        CreateProjectRelease, CreateProjectReleaseBuilderError, ReleaseAssetLink,
+        ReleaseAssetLinkBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn asset_name_and_url_are_needed() {
+        let err = ReleaseAssetLink::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ReleaseAssetLinkBuilderError, "name");
+    }
+
+    #[test]
+    fn asset_name_is_needed() {
+        let err = ReleaseAssetLink::builder()
+            .url("https://example.com/binary")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ReleaseAssetLinkBuilderError, "name");
+    }
+
+    #[test]
+    fn asset_url_is_needed() {
+        let err = ReleaseAssetLink::builder()
+            .name("binary")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ReleaseAssetLinkBuilderError, "url");
+    }
+
+    #[test]
+    fn asset_name_and_url_are_sufficient() {
+        ReleaseAssetLink::builder()
+            .name("binary")
+            .url("https://example.com/binary")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_and_tag_name_are_needed() {
+        let err = CreateProjectRelease::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectReleaseBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateProjectRelease::builder()
+            .tag_name("v1.0")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectReleaseBuilderError, "project");
+    }
+
+    #[test]
+    fn tag_name_is_needed() {
+        let err = CreateProjectRelease::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectReleaseBuilderError, "tag_name");
+    }
+
+    #[test]
+    fn project_and_tag_name_are_sufficient() {
+        CreateProjectRelease::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/releases")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("tag_name=v1.0")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/releases")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("tag_name=v1.0", "&name=Version+1.0"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .name("Version 1.0")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/releases")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("tag_name=v1.0", "&description=notes"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .description("notes")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_ref() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/releases")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("tag_name=v1.0", "&ref=main"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .ref_("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_tag_message() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/releases")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("tag_name=v1.0", "&tag_message=message"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .tag_message("message")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_milestones() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/releases")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "tag_name=v1.0",
+                "&milestones%5B%5D=v1.0",
+                "&milestones%5B%5D=v1.0-rc1",
+                "&milestones%5B%5D=v1.0-rc2",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .milestone("v1.0")
+            .milestones(["v1.0-rc1", "v1.0-rc2"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_assets() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/releases")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "tag_name=v1.0",
+                "&assets%5Blinks%5D%5B%5D%5Bname%5D=binary",
+                "&assets%5Blinks%5D%5B%5D%5Burl%5D=https%3A%2F%2Fexample.com%2Fbinary",
+                "&assets%5Blinks%5D%5B%5D%5Bname%5D=docs",
+                "&assets%5Blinks%5D%5B%5D%5Burl%5D=https%3A%2F%2Fexample.com%2Fdocs",
+                "&assets%5Blinks%5D%5B%5D%5Bdirect_asset_path%5D=%2Fdocs",
+                "&assets%5Blinks%5D%5B%5D%5Blink_type%5D=runbook",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .asset(
+                ReleaseAssetLink::builder()
+                    .name("binary")
+                    .url("https://example.com/binary")
+                    .build()
+                    .unwrap(),
+            )
+            .assets(
+                [ReleaseAssetLink::builder()
+                    .name("docs")
+                    .url("https://example.com/docs")
+                    .direct_asset_path("/docs")
+                    .link_type(ReleaseLinkType::Runbook)
+                    .build()
+                    .unwrap()]
+                .iter()
+                .cloned(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_released_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/releases")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "tag_name=v1.0",
+                "&released_at=2020-01-01T00%3A00%3A00Z",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .released_at(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
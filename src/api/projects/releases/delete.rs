@@ -0,0 +1,109 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Delete a release.
+///
+/// The tag of the release is not deleted.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteProjectRelease<'a> {
+    /// The project of the release.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The tag of the release.
+    #[builder(setter(into))]
+    tag_name: Cow<'a, str>,
+}
+
+impl<'a> DeleteProjectRelease<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteProjectReleaseBuilder<'a> {
+        DeleteProjectReleaseBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteProjectRelease<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/releases/{}",
+            self.project,
+            common::path_escaped(&self.tag_name),
+        )
+        .into()
+    }
+}
+
+impl<'a> TypedEndpoint for DeleteProjectRelease<'a> {
+    type Response = types::Release;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::releases::{DeleteProjectRelease, DeleteProjectReleaseBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_tag_name_are_needed() {
+        let err = DeleteProjectRelease::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectReleaseBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteProjectRelease::builder()
+            .tag_name("v1.0")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectReleaseBuilderError, "project");
+    }
+
+    #[test]
+    fn tag_name_is_needed() {
+        let err = DeleteProjectRelease::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectReleaseBuilderError, "tag_name");
+    }
+
+    #[test]
+    fn project_and_tag_name_are_sufficient() {
+        DeleteProjectRelease::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/releases/release%2Fv1.0")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("release/v1.0")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,75 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Query for the latest release of a project.
+///
+/// The latest release is the one with the most recent release date.
+#[derive(Debug, Builder, Clone)]
+pub struct LatestProjectRelease<'a> {
+    /// The project to query for its latest release.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> LatestProjectRelease<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestProjectReleaseBuilder<'a> {
+        LatestProjectReleaseBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for LatestProjectRelease<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/releases/permalink/latest", self.project).into()
+    }
+}
+
+impl<'a> TypedEndpoint for LatestProjectRelease<'a> {
+    type Response = types::Release;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::releases::{LatestProjectRelease, LatestProjectReleaseBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = LatestProjectRelease::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, LatestProjectReleaseBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        LatestProjectRelease::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/releases/permalink/latest")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = LatestProjectRelease::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Release link API endpoints.
+//!
+//! These endpoints are used for querying and modifying the asset links of a release.
+
+mod create;
+mod delete;
+mod link;
+mod links;
+mod update;
+
+pub use self::create::CreateReleaseLink;
+pub use self::create::CreateReleaseLinkBuilder;
+pub use self::create::CreateReleaseLinkBuilderError;
+pub use self::create::ReleaseLinkType;
+
+pub use self::delete::DeleteReleaseLink;
+pub use self::delete::DeleteReleaseLinkBuilder;
+pub use self::delete::DeleteReleaseLinkBuilderError;
+
+pub use self::link::ReleaseLink;
+pub use self::link::ReleaseLinkBuilder;
+pub use self::link::ReleaseLinkBuilderError;
+
+pub use self::links::ReleaseLinks;
+pub use self::links::ReleaseLinksBuilder;
+pub use self::links::ReleaseLinksBuilderError;
+
+pub use self::update::UpdateReleaseLink;
+pub use self::update::UpdateReleaseLinkBuilder;
+pub use self::update::UpdateReleaseLinkBuilderError;
@@ -0,0 +1,279 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+use crate::types;
+
+/// The type of an asset link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseLinkType {
+    /// A link to some other resource.
+    Other,
+    /// A link to a runbook.
+    Runbook,
+    /// A link to an image.
+    Image,
+    /// A link to a package.
+    Package,
+}
+
+impl Default for ReleaseLinkType {
+    fn default() -> Self {
+        ReleaseLinkType::Other
+    }
+}
+
+impl ReleaseLinkType {
+    /// The link type as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            ReleaseLinkType::Other => "other",
+            ReleaseLinkType::Runbook => "runbook",
+            ReleaseLinkType::Image => "image",
+            ReleaseLinkType::Package => "package",
+        }
+    }
+}
+
+impl ParamValue<'static> for ReleaseLinkType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Add an asset link to a release.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateReleaseLink<'a> {
+    /// The project of the release.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The tag name of the release.
+    #[builder(setter(into))]
+    tag_name: Cow<'a, str>,
+    /// The name of the link.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The URL of the link.
+    #[builder(setter(into))]
+    url: Cow<'a, str>,
+
+    /// The path for a permanent link to the asset through the release.
+    #[builder(setter(into), default)]
+    direct_asset_path: Option<Cow<'a, str>>,
+    /// The type of the link.
+    #[builder(default)]
+    link_type: Option<ReleaseLinkType>,
+}
+
+impl<'a> CreateReleaseLink<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateReleaseLinkBuilder<'a> {
+        CreateReleaseLinkBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateReleaseLink<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/releases/{}/assets/links",
+            self.project,
+            common::path_escaped(&self.tag_name),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", &self.name)
+            .push("url", &self.url)
+            .push_opt("direct_asset_path", self.direct_asset_path.as_ref())
+            .push_opt("link_type", self.link_type);
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for CreateReleaseLink<'a> {
+    type Response = types::ReleaseLink;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::releases::links::{
+        CreateReleaseLink, CreateReleaseLinkBuilderError, ReleaseLinkType,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn release_link_type_default() {
+        assert_eq!(ReleaseLinkType::default(), ReleaseLinkType::Other);
+    }
+
+    #[test]
+    fn release_link_type_as_str() {
+        let items = &[
+            (ReleaseLinkType::Other, "other"),
+            (ReleaseLinkType::Runbook, "runbook"),
+            (ReleaseLinkType::Image, "image"),
+            (ReleaseLinkType::Package, "package"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = CreateReleaseLink::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateReleaseLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateReleaseLink::builder()
+            .tag_name("v1.0")
+            .name("binary")
+            .url("https://example.com/binary")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateReleaseLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn tag_name_is_needed() {
+        let err = CreateReleaseLink::builder()
+            .project(1)
+            .name("binary")
+            .url("https://example.com/binary")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateReleaseLinkBuilderError, "tag_name");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateReleaseLink::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .url("https://example.com/binary")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateReleaseLinkBuilderError, "name");
+    }
+
+    #[test]
+    fn url_is_needed() {
+        let err = CreateReleaseLink::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .name("binary")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateReleaseLinkBuilderError, "url");
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        CreateReleaseLink::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .name("binary")
+            .url("https://example.com/binary")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/releases/release%2Fv1.0/assets/links")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=binary",
+                "&url=https%3A%2F%2Fexample.com%2Fbinary",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateReleaseLink::builder()
+            .project("simple/project")
+            .tag_name("release/v1.0")
+            .name("binary")
+            .url("https://example.com/binary")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_direct_asset_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/releases/v1.0/assets/links")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=binary",
+                "&url=https%3A%2F%2Fexample.com%2Fbinary",
+                "&direct_asset_path=%2Fbin%2Fbinary",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateReleaseLink::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .name("binary")
+            .url("https://example.com/binary")
+            .direct_asset_path("/bin/binary")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_link_type() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/releases/v1.0/assets/links")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=binary",
+                "&url=https%3A%2F%2Fexample.com%2Fbinary",
+                "&link_type=package",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateReleaseLink::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .name("binary")
+            .url("https://example.com/binary")
+            .link_type(ReleaseLinkType::Package)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,118 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Delete an asset link of a release.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteReleaseLink<'a> {
+    /// The project of the release.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The tag name of the release.
+    #[builder(setter(into))]
+    tag_name: Cow<'a, str>,
+    /// The ID of the link.
+    link: u64,
+}
+
+impl<'a> DeleteReleaseLink<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteReleaseLinkBuilder<'a> {
+        DeleteReleaseLinkBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteReleaseLink<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/releases/{}/assets/links/{}",
+            self.project,
+            common::path_escaped(&self.tag_name),
+            self.link,
+        )
+        .into()
+    }
+}
+
+impl<'a> TypedEndpoint for DeleteReleaseLink<'a> {
+    type Response = types::ReleaseLink;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::releases::links::{DeleteReleaseLink, DeleteReleaseLinkBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteReleaseLink::builder()
+            .tag_name("v1.0")
+            .link(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteReleaseLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn tag_name_is_needed() {
+        let err = DeleteReleaseLink::builder()
+            .project(1)
+            .link(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteReleaseLinkBuilderError, "tag_name");
+    }
+
+    #[test]
+    fn link_is_needed() {
+        let err = DeleteReleaseLink::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteReleaseLinkBuilderError, "link");
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        DeleteReleaseLink::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .link(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/releases/release%2Fv1.0/assets/links/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteReleaseLink::builder()
+            .project("simple/project")
+            .tag_name("release/v1.0")
+            .link(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,115 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Query for an asset link of a release.
+#[derive(Debug, Builder, Clone)]
+pub struct ReleaseLink<'a> {
+    /// The project of the release.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The tag name of the release.
+    #[builder(setter(into))]
+    tag_name: Cow<'a, str>,
+    /// The ID of the link.
+    link: u64,
+}
+
+impl<'a> ReleaseLink<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ReleaseLinkBuilder<'a> {
+        ReleaseLinkBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ReleaseLink<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/releases/{}/assets/links/{}",
+            self.project,
+            common::path_escaped(&self.tag_name),
+            self.link,
+        )
+        .into()
+    }
+}
+
+impl<'a> TypedEndpoint for ReleaseLink<'a> {
+    type Response = types::ReleaseLink;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::releases::links::{ReleaseLink, ReleaseLinkBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ReleaseLink::builder()
+            .tag_name("v1.0")
+            .link(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ReleaseLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn tag_name_is_needed() {
+        let err = ReleaseLink::builder()
+            .project(1)
+            .link(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ReleaseLinkBuilderError, "tag_name");
+    }
+
+    #[test]
+    fn link_is_needed() {
+        let err = ReleaseLink::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ReleaseLinkBuilderError, "link");
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        ReleaseLink::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .link(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/releases/release%2Fv1.0/assets/links/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ReleaseLink::builder()
+            .project("simple/project")
+            .tag_name("release/v1.0")
+            .link(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
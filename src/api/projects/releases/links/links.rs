@@ -0,0 +1,97 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Query for the asset links of a release.
+#[derive(Debug, Builder, Clone)]
+pub struct ReleaseLinks<'a> {
+    /// The project of the release.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The tag name of the release.
+    #[builder(setter(into))]
+    tag_name: Cow<'a, str>,
+}
+
+impl<'a> ReleaseLinks<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ReleaseLinksBuilder<'a> {
+        ReleaseLinksBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ReleaseLinks<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/releases/{}/assets/links",
+            self.project,
+            common::path_escaped(&self.tag_name),
+        )
+        .into()
+    }
+}
+
+impl<'a> TypedEndpoint for ReleaseLinks<'a> {
+    type Response = Vec<types::ReleaseLink>;
+}
+
+impl<'a> Pageable for ReleaseLinks<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::releases::links::{ReleaseLinks, ReleaseLinksBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ReleaseLinks::builder()
+            .tag_name("v1.0")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ReleaseLinksBuilderError, "project");
+    }
+
+    #[test]
+    fn tag_name_is_needed() {
+        let err = ReleaseLinks::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ReleaseLinksBuilderError, "tag_name");
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        ReleaseLinks::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/releases/release%2Fv1.0/assets/links")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ReleaseLinks::builder()
+            .project("simple/project")
+            .tag_name("release/v1.0")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
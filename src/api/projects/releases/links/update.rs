@@ -0,0 +1,238 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::releases::links::ReleaseLinkType;
+use crate::types;
+
+/// Edit an asset link of a release.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct UpdateReleaseLink<'a> {
+    /// The project of the release.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The tag name of the release.
+    #[builder(setter(into))]
+    tag_name: Cow<'a, str>,
+    /// The ID of the link.
+    link: u64,
+
+    /// The new name of the link.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// The new URL of the link.
+    #[builder(setter(into), default)]
+    url: Option<Cow<'a, str>>,
+    /// The path for a permanent link to the asset through the release.
+    #[builder(setter(into), default)]
+    direct_asset_path: Option<Cow<'a, str>>,
+    /// The type of the link.
+    #[builder(default)]
+    link_type: Option<ReleaseLinkType>,
+}
+
+impl<'a> UpdateReleaseLink<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UpdateReleaseLinkBuilder<'a> {
+        UpdateReleaseLinkBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UpdateReleaseLink<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/releases/{}/assets/links/{}",
+            self.project,
+            common::path_escaped(&self.tag_name),
+            self.link,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("name", self.name.as_ref())
+            .push_opt("url", self.url.as_ref())
+            .push_opt("direct_asset_path", self.direct_asset_path.as_ref())
+            .push_opt("link_type", self.link_type);
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for UpdateReleaseLink<'a> {
+    type Response = types::ReleaseLink;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::releases::links::{
+        ReleaseLinkType, UpdateReleaseLink, UpdateReleaseLinkBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_tag_name_and_link_are_needed() {
+        let err = UpdateReleaseLink::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateReleaseLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = UpdateReleaseLink::builder()
+            .tag_name("v1.0")
+            .link(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateReleaseLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn tag_name_is_needed() {
+        let err = UpdateReleaseLink::builder()
+            .project(1)
+            .link(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateReleaseLinkBuilderError, "tag_name");
+    }
+
+    #[test]
+    fn link_is_needed() {
+        let err = UpdateReleaseLink::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateReleaseLinkBuilderError, "link");
+    }
+
+    #[test]
+    fn project_tag_name_and_link_are_sufficient() {
+        UpdateReleaseLink::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .link(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/releases/release%2Fv1.0/assets/links/1")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateReleaseLink::builder()
+            .project("simple/project")
+            .tag_name("release/v1.0")
+            .link(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/releases/v1.0/assets/links/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=binary")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateReleaseLink::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .link(1)
+            .name("binary")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/releases/v1.0/assets/links/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("url=https%3A%2F%2Fexample.com%2Fbinary")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateReleaseLink::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .link(1)
+            .url("https://example.com/binary")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_direct_asset_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/releases/v1.0/assets/links/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("direct_asset_path=%2Fbin%2Fbinary")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateReleaseLink::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .link(1)
+            .direct_asset_path("/bin/binary")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_link_type() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/releases/v1.0/assets/links/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("link_type=image")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateReleaseLink::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .link(1)
+            .link_type(ReleaseLinkType::Image)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,132 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Query for a release by its tag.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ProjectRelease<'a> {
+    /// The project of the release.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The tag of the release.
+    #[builder(setter(into))]
+    tag_name: Cow<'a, str>,
+
+    /// Whether to include an HTML render of the description or not.
+    #[builder(default)]
+    include_html_description: Option<bool>,
+}
+
+impl<'a> ProjectRelease<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectReleaseBuilder<'a> {
+        ProjectReleaseBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectRelease<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/releases/{}",
+            self.project,
+            common::path_escaped(&self.tag_name),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("include_html_description", self.include_html_description);
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for ProjectRelease<'a> {
+    type Response = types::Release;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::releases::{ProjectRelease, ProjectReleaseBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_tag_name_are_needed() {
+        let err = ProjectRelease::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectReleaseBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectRelease::builder()
+            .tag_name("v1.0")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectReleaseBuilderError, "project");
+    }
+
+    #[test]
+    fn tag_name_is_needed() {
+        let err = ProjectRelease::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectReleaseBuilderError, "tag_name");
+    }
+
+    #[test]
+    fn project_and_tag_name_are_sufficient() {
+        ProjectRelease::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/releases/release%2Fv1.0")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("release/v1.0")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_html_description() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/releases/v1.0")
+            .add_query_params(&[("include_html_description", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .include_html_description(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
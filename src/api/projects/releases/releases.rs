@@ -9,6 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::{NameOrId, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Keys group results may be ordered by.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -89,6 +90,10 @@ impl<'a> Endpoint for ProjectReleases<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for ProjectReleases<'a> {
+    type Response = Vec<types::Release>;
+}
+
 impl<'a> Pageable for ProjectReleases<'a> {}
 
 #[cfg(test)]
@@ -0,0 +1,245 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Edit a release.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct UpdateProjectRelease<'a> {
+    /// The project of the release.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The tag of the release.
+    #[builder(setter(into))]
+    tag_name: Cow<'a, str>,
+
+    /// The new name of the release.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// The new description of the release (release notes).
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// The titles of milestones to associate with the release.
+    #[builder(setter(name = "_milestones"), default, private)]
+    milestones: Vec<Cow<'a, str>>,
+    /// When the release is (or was) made.
+    #[builder(default)]
+    released_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> UpdateProjectRelease<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UpdateProjectReleaseBuilder<'a> {
+        UpdateProjectReleaseBuilder::default()
+    }
+}
+
+impl<'a> UpdateProjectReleaseBuilder<'a> {
+    /// Associate a milestone with the release.
+    pub fn milestone<M>(&mut self, milestone: M) -> &mut Self
+    where
+        M: Into<Cow<'a, str>>,
+    {
+        self.milestones
+            .get_or_insert_with(Vec::new)
+            .push(milestone.into());
+        self
+    }
+
+    /// Associate multiple milestones with the release.
+    pub fn milestones<I, M>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = M>,
+        M: Into<Cow<'a, str>>,
+    {
+        self.milestones
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for UpdateProjectRelease<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/releases/{}",
+            self.project,
+            common::path_escaped(&self.tag_name),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("name", self.name.as_ref())
+            .push_opt("description", self.description.as_ref())
+            .extend(self.milestones.iter().map(|value| ("milestones[]", value)))
+            .push_opt("released_at", self.released_at);
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for UpdateProjectRelease<'a> {
+    type Response = types::Release;
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::projects::releases::{UpdateProjectRelease, UpdateProjectReleaseBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_tag_name_are_needed() {
+        let err = UpdateProjectRelease::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateProjectReleaseBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = UpdateProjectRelease::builder()
+            .tag_name("v1.0")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateProjectReleaseBuilderError, "project");
+    }
+
+    #[test]
+    fn tag_name_is_needed() {
+        let err = UpdateProjectRelease::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateProjectReleaseBuilderError, "tag_name");
+    }
+
+    #[test]
+    fn project_and_tag_name_are_sufficient() {
+        UpdateProjectRelease::builder()
+            .project(1)
+            .tag_name("v1.0")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/releases/release%2Fv1.0")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("release/v1.0")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/releases/v1.0")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=Version+1.0")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .name("Version 1.0")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/releases/v1.0")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("description=notes")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .description("notes")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_milestones() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/releases/v1.0")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "milestones%5B%5D=v1.0",
+                "&milestones%5B%5D=v1.0-rc1",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .milestone("v1.0")
+            .milestones(["v1.0-rc1"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_released_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/releases/v1.0")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("released_at=2020-01-01T00%3A00%3A00Z")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProjectRelease::builder()
+            .project("simple/project")
+            .tag_name("v1.0")
+            .released_at(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
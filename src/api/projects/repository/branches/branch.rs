@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{self, NameOrId};
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for a specific branch in a project.
 #[derive(Debug, Builder, Clone)]
@@ -42,6 +43,10 @@ impl<'a> Endpoint for Branch<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Branch<'a> {
+    type Response = types::RepoBranch;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::repository::branches::{Branch, BranchBuilderError};
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for a specific branch in a project.
 #[derive(Debug, Builder, Clone)]
@@ -46,6 +47,10 @@ impl<'a> Endpoint for Branches<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Branches<'a> {
+    type Response = Vec<types::RepoBranch>;
+}
+
 impl<'a> Pageable for Branches<'a> {}
 
 #[cfg(test)]
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Create a branch on a project.
 #[derive(Debug, Builder, Clone)]
@@ -48,6 +49,10 @@ impl<'a> Endpoint for CreateBranch<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateBranch<'a> {
+    type Response = types::RepoBranch;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
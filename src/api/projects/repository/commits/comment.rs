@@ -9,6 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::{self, NameOrId};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Line types within a diff.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -93,6 +94,10 @@ impl<'a> Endpoint for CommentOnCommit<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CommentOnCommit<'a> {
+    type Response = types::CommitNote;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{self, NameOrId};
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for comments on a specific commit in a project.
 #[derive(Debug, Builder, Clone)]
@@ -42,6 +43,10 @@ impl<'a> Endpoint for CommitComments<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CommitComments<'a> {
+    type Response = Vec<types::CommitNote>;
+}
+
 impl<'a> Pageable for CommitComments<'a> {}
 
 #[cfg(test)]
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{self, NameOrId};
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for a specific commit in a project.
 #[derive(Debug, Builder, Clone)]
@@ -55,6 +56,10 @@ impl<'a> Endpoint for Commit<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Commit<'a> {
+    type Response = types::RepoCommitDetail;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::repository::commits::{Commit, CommitBuilderError};
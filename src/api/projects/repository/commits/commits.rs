@@ -10,6 +10,7 @@ use derive_builder::Builder;
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Orders commits may be ordered by.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -114,6 +115,10 @@ impl<'a> Endpoint for Commits<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Commits<'a> {
+    type Response = Vec<types::RepoCommit>;
+}
+
 impl<'a> Pageable for Commits<'a> {}
 
 #[cfg(test)]
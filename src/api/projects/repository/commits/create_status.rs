@@ -9,6 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::{self, NameOrId};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// The state a commit status may have.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -117,6 +118,10 @@ impl<'a> Endpoint for CreateCommitStatus<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateCommitStatus<'a> {
+    type Response = types::CommitStatus;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
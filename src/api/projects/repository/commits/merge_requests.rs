@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Get a list of merge requests related to the specified commit.
 #[derive(Debug, Builder, Clone)]
@@ -44,6 +45,10 @@ impl<'a> Endpoint for MergeRequests<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for MergeRequests<'a> {
+    type Response = Vec<types::MergeRequestBasic>;
+}
+
 impl<'a> Pageable for MergeRequests<'a> {}
 
 #[cfg(test)]
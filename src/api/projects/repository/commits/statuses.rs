@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{self, NameOrId};
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for statuses on a specific commit in a project.
 #[derive(Debug, Builder, Clone)]
@@ -68,6 +69,10 @@ impl<'a> Endpoint for CommitStatuses<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CommitStatuses<'a> {
+    type Response = Vec<types::CommitStatus>;
+}
+
 impl<'a> Pageable for CommitStatuses<'a> {}
 
 #[cfg(test)]
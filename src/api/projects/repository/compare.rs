@@ -0,0 +1,179 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Compare two refs in a repository.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Compare<'a> {
+    /// The ID or URL-encoded path of the project.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ref to compare from.
+    #[builder(setter(into))]
+    from: Cow<'a, str>,
+    /// The ref to compare to.
+    #[builder(setter(into))]
+    to: Cow<'a, str>,
+
+    /// The project to look up `from` in.
+    ///
+    /// Defaults to the project itself.
+    #[builder(default)]
+    from_project_id: Option<u64>,
+    /// Compare the refs directly rather than from their merge base.
+    ///
+    /// This is `from..to` rather than `from...to` in Git terms.
+    #[builder(default)]
+    straight: Option<bool>,
+}
+
+impl<'a> Compare<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CompareBuilder<'a> {
+        CompareBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Compare<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/repository/compare", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("from", &self.from)
+            .push("to", &self.to)
+            .push_opt("from_project_id", self.from_project_id)
+            .push_opt("straight", self.straight);
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for Compare<'a> {
+    type Response = types::RepoCompare;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::repository::{Compare, CompareBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_from_and_to_are_needed() {
+        let err = Compare::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CompareBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = Compare::builder()
+            .from("main")
+            .to("topic")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CompareBuilderError, "project");
+    }
+
+    #[test]
+    fn from_is_needed() {
+        let err = Compare::builder()
+            .project(1)
+            .to("topic")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CompareBuilderError, "from");
+    }
+
+    #[test]
+    fn to_is_needed() {
+        let err = Compare::builder()
+            .project(1)
+            .from("main")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CompareBuilderError, "to");
+    }
+
+    #[test]
+    fn project_from_and_to_are_sufficient() {
+        Compare::builder()
+            .project(1)
+            .from("main")
+            .to("topic")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/compare")
+            .add_query_params(&[("from", "main"), ("to", "topic")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Compare::builder()
+            .project("simple/project")
+            .from("main")
+            .to("topic")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_from_project_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/compare")
+            .add_query_params(&[("from", "main"), ("to", "topic"), ("from_project_id", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Compare::builder()
+            .project("simple/project")
+            .from("main")
+            .to("topic")
+            .from_project_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_straight() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/compare")
+            .add_query_params(&[("from", "main"), ("to", "topic"), ("straight", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Compare::builder()
+            .project("simple/project")
+            .from("main")
+            .to("topic")
+            .straight(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
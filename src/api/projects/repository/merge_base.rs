@@ -0,0 +1,182 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Find the common ancestor of a set of refs.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct MergeBase<'a> {
+    /// The ID or URL-encoded path of the project.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The refs to find the common ancestor of.
+    ///
+    /// At least two refs are required.
+    #[builder(setter(name = "_refs"), private)]
+    refs: Vec<Cow<'a, str>>,
+}
+
+impl<'a> MergeBase<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MergeBaseBuilder<'a> {
+        MergeBaseBuilder::default()
+    }
+}
+
+impl<'a> MergeBaseBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        match &self.refs {
+            Some(refs) if refs.len() < 2 => {
+                Err(format!(
+                    "at least two refs are required, not {}",
+                    refs.len(),
+                ))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Add a ref to find the common ancestor of.
+    pub fn ref_<R>(&mut self, ref_: R) -> &mut Self
+    where
+        R: Into<Cow<'a, str>>,
+    {
+        self.refs.get_or_insert_with(Vec::new).push(ref_.into());
+        self
+    }
+
+    /// Add multiple refs to find the common ancestor of.
+    pub fn refs<I, R>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = R>,
+        R: Into<Cow<'a, str>>,
+    {
+        self.refs
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for MergeBase<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/repository/merge_base", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.extend(self.refs.iter().map(|value| ("refs[]", value)));
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for MergeBase<'a> {
+    type Response = types::RepoCommit;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::repository::{MergeBase, MergeBaseBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_refs_are_needed() {
+        let err = MergeBase::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, MergeBaseBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = MergeBase::builder()
+            .ref_("main")
+            .ref_("topic")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeBaseBuilderError, "project");
+    }
+
+    #[test]
+    fn refs_are_needed() {
+        let err = MergeBase::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, MergeBaseBuilderError, "refs");
+    }
+
+    #[test]
+    fn one_ref_is_insufficient() {
+        let err = MergeBase::builder()
+            .project(1)
+            .ref_("main")
+            .build()
+            .unwrap_err();
+        if let MergeBaseBuilderError::ValidationError(message) = err {
+            assert_eq!(message, "at least two refs are required, not 1");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn empty_refs_are_insufficient() {
+        let err = MergeBase::builder()
+            .project(1)
+            .refs(std::iter::empty::<&str>())
+            .build()
+            .unwrap_err();
+        if let MergeBaseBuilderError::ValidationError(message) = err {
+            assert_eq!(message, "at least two refs are required, not 0");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn project_and_refs_are_sufficient() {
+        MergeBase::builder()
+            .project(1)
+            .ref_("main")
+            .ref_("topic")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/merge_base")
+            .add_query_params(&[
+                ("refs[]", "main"),
+                ("refs[]", "topic"),
+                ("refs[]", "0000000000000000000000000000000000000000"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeBase::builder()
+            .project("simple/project")
+            .ref_("main")
+            .refs(
+                ["topic", "0000000000000000000000000000000000000000"]
+                    .iter()
+                    .copied(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
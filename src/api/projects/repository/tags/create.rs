@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Create a tag on a project.
 #[derive(Debug, Builder, Clone)]
@@ -55,6 +56,10 @@ impl<'a> Endpoint for CreateTag<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for CreateTag<'a> {
+    type Response = types::Tag;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
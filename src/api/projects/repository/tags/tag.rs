@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{self, NameOrId};
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query for a specific branch in a project.
 #[derive(Debug, Builder, Clone)]
@@ -42,6 +43,10 @@ impl<'a> Endpoint for Tag<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Tag<'a> {
+    type Response = types::Tag;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::projects::repository::tags::tag::{Tag, TagBuilderError};
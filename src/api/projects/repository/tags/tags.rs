@@ -9,6 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::SortOrder;
 use crate::api::endpoint_prelude::*;
 use crate::api::{common::NameOrId, ParamValue};
+use crate::types;
 
 /// Orders commits may be ordered by.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -88,6 +89,10 @@ impl<'a> Endpoint for Tags<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Tags<'a> {
+    type Response = Vec<types::Tag>;
+}
+
 impl<'a> Pageable for Tags<'a> {}
 
 #[cfg(test)]
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Get the tree of a given path.
 #[derive(Debug, Builder, Clone)]
@@ -55,6 +56,10 @@ impl<'a> Endpoint for Tree<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Tree<'a> {
+    type Response = Vec<types::RepoTreeObject>;
+}
+
 impl<'a> Pageable for Tree<'a> {
     fn use_keyset_pagination(&self) -> bool {
         true
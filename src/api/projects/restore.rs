@@ -0,0 +1,76 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Restore a project which has been marked for deletion.
+#[derive(Debug, Builder, Clone)]
+pub struct RestoreProject<'a> {
+    /// The project to restore.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> RestoreProject<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RestoreProjectBuilder<'a> {
+        RestoreProjectBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for RestoreProject<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/restore", self.project).into()
+    }
+}
+
+impl<'a> TypedEndpoint for RestoreProject<'a> {
+    type Response = types::Project;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::{RestoreProject, RestoreProjectBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_necessary() {
+        let err = RestoreProject::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RestoreProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        RestoreProject::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/restore")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RestoreProject::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
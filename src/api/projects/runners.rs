@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project runner API endpoints.
+//!
+//! These endpoints are used for querying and assigning runners to projects.
+
+mod disable;
+mod enable;
+mod reset_registration_token;
+mod runners;
+
+pub use self::disable::DisableProjectRunner;
+pub use self::disable::DisableProjectRunnerBuilder;
+pub use self::disable::DisableProjectRunnerBuilderError;
+
+pub use self::enable::EnableProjectRunner;
+pub use self::enable::EnableProjectRunnerBuilder;
+pub use self::enable::EnableProjectRunnerBuilderError;
+
+pub use self::reset_registration_token::ResetProjectRunnerRegistrationToken;
+pub use self::reset_registration_token::ResetProjectRunnerRegistrationTokenBuilder;
+pub use self::reset_registration_token::ResetProjectRunnerRegistrationTokenBuilderError;
+
+pub use self::runners::ProjectRunners;
+pub use self::runners::ProjectRunnersBuilder;
+pub use self::runners::ProjectRunnersBuilderError;
@@ -0,0 +1,131 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Transfer a project to a new namespace.
+#[derive(Debug, Builder, Clone)]
+pub struct TransferProject<'a> {
+    /// The project to transfer.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The namespace to transfer the project into.
+    #[builder(setter(into))]
+    namespace: NameOrId<'a>,
+}
+
+impl<'a> TransferProject<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TransferProjectBuilder<'a> {
+        TransferProjectBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for TransferProject<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/transfer", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        match &self.namespace {
+            NameOrId::Id(id) => params.push("namespace", *id),
+            NameOrId::Name(name) => params.push("namespace", name),
+        };
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for TransferProject<'a> {
+    type Response = types::Project;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::{TransferProject, TransferProjectBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_namespace_are_necessary() {
+        let err = TransferProject::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, TransferProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = TransferProject::builder()
+            .namespace("group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TransferProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn namespace_is_necessary() {
+        let err = TransferProject::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, TransferProjectBuilderError, "namespace");
+    }
+
+    #[test]
+    fn project_and_namespace_are_sufficient() {
+        TransferProject::builder()
+            .project(1)
+            .namespace("group")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/transfer")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("namespace=new%2Fgroup")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TransferProject::builder()
+            .project("simple/project")
+            .namespace("new/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_namespace_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/transfer")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("namespace=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TransferProject::builder()
+            .project("simple/project")
+            .namespace(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
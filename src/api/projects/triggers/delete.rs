@@ -0,0 +1,98 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete a pipeline trigger.
+#[derive(Debug, Builder, Clone)]
+pub struct DeletePipelineTrigger<'a> {
+    /// The project of the trigger.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the trigger.
+    trigger: u64,
+}
+
+impl<'a> DeletePipelineTrigger<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeletePipelineTriggerBuilder<'a> {
+        DeletePipelineTriggerBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeletePipelineTrigger<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/triggers/{}", self.project, self.trigger).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::triggers::{
+        DeletePipelineTrigger, DeletePipelineTriggerBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_trigger_are_needed() {
+        let err = DeletePipelineTrigger::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeletePipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeletePipelineTrigger::builder()
+            .trigger(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeletePipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn trigger_is_needed() {
+        let err = DeletePipelineTrigger::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeletePipelineTriggerBuilderError, "trigger");
+    }
+
+    #[test]
+    fn project_and_trigger_are_sufficient() {
+        DeletePipelineTrigger::builder()
+            .project(1)
+            .trigger(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/triggers/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeletePipelineTrigger::builder()
+            .project("simple/project")
+            .trigger(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
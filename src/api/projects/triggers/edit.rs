@@ -0,0 +1,135 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Edit a pipeline trigger on a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditPipelineTrigger<'a> {
+    /// The project of the trigger.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the trigger.
+    trigger: u64,
+
+    /// The description of the trigger.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+}
+
+impl<'a> EditPipelineTrigger<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditPipelineTriggerBuilder<'a> {
+        EditPipelineTriggerBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditPipelineTrigger<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/triggers/{}", self.project, self.trigger).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt("description", self.description.as_ref());
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for EditPipelineTrigger<'a> {
+    type Response = types::PipelineTrigger;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::triggers::{EditPipelineTrigger, EditPipelineTriggerBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_trigger_are_needed() {
+        let err = EditPipelineTrigger::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditPipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditPipelineTrigger::builder()
+            .trigger(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditPipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn trigger_is_needed() {
+        let err = EditPipelineTrigger::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditPipelineTriggerBuilderError, "trigger");
+    }
+
+    #[test]
+    fn project_and_trigger_are_sufficient() {
+        EditPipelineTrigger::builder()
+            .project(1)
+            .trigger(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/triggers/1")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditPipelineTrigger::builder()
+            .project("simple/project")
+            .trigger(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/triggers/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("description=upstream")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditPipelineTrigger::builder()
+            .project("simple/project")
+            .trigger(1)
+            .description("upstream")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
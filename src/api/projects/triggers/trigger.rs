@@ -0,0 +1,92 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Query for a pipeline trigger within a project.
+#[derive(Debug, Builder, Clone)]
+pub struct PipelineTrigger<'a> {
+    /// The project of the trigger.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the trigger.
+    trigger: u64,
+}
+
+impl<'a> PipelineTrigger<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PipelineTriggerBuilder<'a> {
+        PipelineTriggerBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for PipelineTrigger<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/triggers/{}", self.project, self.trigger).into()
+    }
+}
+
+impl<'a> TypedEndpoint for PipelineTrigger<'a> {
+    type Response = types::PipelineTrigger;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::triggers::{PipelineTrigger, PipelineTriggerBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_trigger_are_needed() {
+        let err = PipelineTrigger::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = PipelineTrigger::builder().trigger(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn trigger_is_needed() {
+        let err = PipelineTrigger::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineTriggerBuilderError, "trigger");
+    }
+
+    #[test]
+    fn project_and_trigger_are_sufficient() {
+        PipelineTrigger::builder()
+            .project(1)
+            .trigger(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/triggers/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PipelineTrigger::builder()
+            .project("simple/project")
+            .trigger(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
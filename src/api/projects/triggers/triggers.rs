@@ -0,0 +1,75 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Query for pipeline triggers within a project.
+#[derive(Debug, Builder, Clone)]
+pub struct PipelineTriggers<'a> {
+    /// The project to query for pipeline triggers.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> PipelineTriggers<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PipelineTriggersBuilder<'a> {
+        PipelineTriggersBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for PipelineTriggers<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/triggers", self.project).into()
+    }
+}
+
+impl<'a> TypedEndpoint for PipelineTriggers<'a> {
+    type Response = Vec<types::PipelineTrigger>;
+}
+
+impl<'a> Pageable for PipelineTriggers<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::triggers::{PipelineTriggers, PipelineTriggersBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = PipelineTriggers::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineTriggersBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        PipelineTriggers::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/triggers")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PipelineTriggers::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,76 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Unarchive a project.
+#[derive(Debug, Builder, Clone)]
+pub struct UnarchiveProject<'a> {
+    /// The project to unarchive.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> UnarchiveProject<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UnarchiveProjectBuilder<'a> {
+        UnarchiveProjectBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UnarchiveProject<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/unarchive", self.project).into()
+    }
+}
+
+impl<'a> TypedEndpoint for UnarchiveProject<'a> {
+    type Response = types::Project;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::{UnarchiveProject, UnarchiveProjectBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_necessary() {
+        let err = UnarchiveProject::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnarchiveProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        UnarchiveProject::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/unarchive")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UnarchiveProject::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
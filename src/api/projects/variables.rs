@@ -6,16 +6,21 @@
 
 //! Project variable API endpoints.
 //!
-//! These endpoints are used for querying a project's variables.
+//! These endpoints are used for querying and modifying a project's variables.
 
 mod create;
+mod delete;
 mod update;
 mod variable;
+mod variables;
 
 pub use self::create::CreateProjectVariable;
 pub use self::create::CreateProjectVariableBuilder;
 pub use self::create::CreateProjectVariableBuilderError;
-pub use self::create::ProjectVariableType;
+
+pub use self::delete::DeleteProjectVariable;
+pub use self::delete::DeleteProjectVariableBuilder;
+pub use self::delete::DeleteProjectVariableBuilderError;
 
 pub use self::update::UpdateProjectVariable;
 pub use self::update::UpdateProjectVariableBuilder;
@@ -25,3 +30,9 @@ pub use self::variable::ProjectVariable;
 pub use self::variable::ProjectVariableBuilder;
 pub use self::variable::ProjectVariableBuilderError;
 pub use self::variable::ProjectVariableFilter;
+
+pub use self::variables::ProjectVariables;
+pub use self::variables::ProjectVariablesBuilder;
+pub use self::variables::ProjectVariablesBuilderError;
+
+pub use crate::api::common::VariableType as ProjectVariableType;
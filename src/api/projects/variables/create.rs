@@ -6,39 +6,9 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{NameOrId, VariableType};
 use crate::api::endpoint_prelude::*;
-use crate::api::ParamValue;
-
-/// The type of a project variable.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ProjectVariableType {
-    /// An environment variable.
-    ///
-    /// The value of the variable is available as the value of the named environment variable.
-    EnvVar,
-    /// A file variable.
-    ///
-    /// The value of the variable is available in a file given by the value of the named
-    /// environment variable.
-    File,
-}
-
-impl ProjectVariableType {
-    /// The variable type query parameter.
-    fn as_str(self) -> &'static str {
-        match self {
-            ProjectVariableType::EnvVar => "env_var",
-            ProjectVariableType::File => "file",
-        }
-    }
-}
-
-impl ParamValue<'static> for ProjectVariableType {
-    fn as_value(&self) -> Cow<'static, str> {
-        self.as_str().into()
-    }
-}
+use crate::types;
 
 /// Add a variable to a project.
 #[derive(Debug, Builder, Clone)]
@@ -55,13 +25,18 @@ pub struct CreateProjectVariable<'a> {
     value: Cow<'a, str>,
     /// The type of the variable.
     #[builder(default)]
-    variable_type: Option<ProjectVariableType>,
+    variable_type: Option<VariableType>,
     /// Whether the variable is protected.
     #[builder(default)]
     protected: Option<bool>,
     /// Whether the variable is masked.
     #[builder(default)]
     masked: Option<bool>,
+    /// Whether the variable is treated as a raw string.
+    ///
+    /// Raw variables do not expand references to other variables.
+    #[builder(default)]
+    raw: Option<bool>,
     /// The environment scope of the variable.
     #[builder(setter(into), default)]
     environment_scope: Option<Cow<'a, str>>,
@@ -92,34 +67,28 @@ impl<'a> Endpoint for CreateProjectVariable<'a> {
             .push_opt("variable_type", self.variable_type)
             .push_opt("protected", self.protected)
             .push_opt("masked", self.masked)
+            .push_opt("raw", self.raw)
             .push_opt("environment_scope", self.environment_scope.as_ref());
 
         params.into_body()
     }
 }
 
+impl<'a> TypedEndpoint for CreateProjectVariable<'a> {
+    type Response = types::Variable;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
 
-    use crate::api::projects::variables::create::{
-        CreateProjectVariable, CreateProjectVariableBuilderError, ProjectVariableType,
+    use crate::api::common::VariableType;
+    use crate::api::projects::variables::{
+        CreateProjectVariable, CreateProjectVariableBuilderError,
     };
     use crate::api::{self, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
 
-    #[test]
-    fn project_variable_type_as_str() {
-        let items = &[
-            (ProjectVariableType::EnvVar, "env_var"),
-            (ProjectVariableType::File, "file"),
-        ];
-
-        for (i, s) in items {
-            assert_eq!(i.as_str(), *s);
-        }
-    }
-
     #[test]
     fn all_parameters_are_needed() {
         let err = CreateProjectVariable::builder().build().unwrap_err();
@@ -205,7 +174,7 @@ mod tests {
             .project("simple/project")
             .key("testkey")
             .value("testvalue")
-            .variable_type(ProjectVariableType::File)
+            .variable_type(VariableType::File)
             .build()
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
@@ -257,6 +226,27 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_raw() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/variables")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("key=testkey", "&value=testvalue", "&raw=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectVariable::builder()
+            .project("simple/project")
+            .key("testkey")
+            .value("testvalue")
+            .raw(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     fn endpoint_environment_scope() {
         let endpoint = ExpectedUrl::builder()
@@ -6,9 +6,10 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, VariableType};
 use crate::api::endpoint_prelude::*;
-use crate::api::projects::variables::{ProjectVariableFilter, ProjectVariableType};
+use crate::api::projects::variables::ProjectVariableFilter;
+use crate::types;
 
 /// Edit a variable of a project.
 #[derive(Debug, Builder, Clone)]
@@ -25,13 +26,18 @@ pub struct UpdateProjectVariable<'a> {
     value: Cow<'a, str>,
     /// The type of the variable.
     #[builder(default)]
-    variable_type: Option<ProjectVariableType>,
+    variable_type: Option<VariableType>,
     /// Whether the variable is protected.
     #[builder(default)]
     protected: Option<bool>,
     /// Whether the variable is masked.
     #[builder(default)]
     masked: Option<bool>,
+    /// Whether the variable is treated as a raw string.
+    ///
+    /// Raw variables do not expand references to other variables.
+    #[builder(default)]
+    raw: Option<bool>,
     /// The environment scope of the variable.
     #[builder(setter(into), default)]
     environment_scope: Option<Cow<'a, str>>,
@@ -69,6 +75,7 @@ impl<'a> Endpoint for UpdateProjectVariable<'a> {
             .push_opt("variable_type", self.variable_type)
             .push_opt("protected", self.protected)
             .push_opt("masked", self.masked)
+            .push_opt("raw", self.raw)
             .push_opt("environment_scope", self.environment_scope.as_ref());
 
         if let Some(filter) = self.filter.as_ref() {
@@ -79,13 +86,17 @@ impl<'a> Endpoint for UpdateProjectVariable<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for UpdateProjectVariable<'a> {
+    type Response = types::Variable;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
 
-    use crate::api::projects::variables::update::{
-        ProjectVariableFilter, ProjectVariableType, UpdateProjectVariable,
-        UpdateProjectVariableBuilderError,
+    use crate::api::common::VariableType;
+    use crate::api::projects::variables::{
+        ProjectVariableFilter, UpdateProjectVariable, UpdateProjectVariableBuilderError,
     };
     use crate::api::{self, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
@@ -171,7 +182,7 @@ mod tests {
             .project("simple/project")
             .key("testkey")
             .value("testvalue")
-            .variable_type(ProjectVariableType::File)
+            .variable_type(VariableType::File)
             .build()
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
@@ -219,6 +230,27 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_raw() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/variables/testkey")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=testvalue", "&raw=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProjectVariable::builder()
+            .project("simple/project")
+            .key("testkey")
+            .value("testvalue")
+            .raw(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     fn endpoint_environment_scope() {
         let endpoint = ExpectedUrl::builder()
@@ -8,6 +8,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{self, NameOrId};
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Filter parameters.
 #[derive(Debug, Clone, Builder)]
@@ -27,6 +28,10 @@ impl<'a> ProjectVariableFilter<'a> {
     pub(crate) fn add_query<'b>(&'b self, params: &mut FormParams<'b>) {
         params.push_opt("filter[environment_scope]", self.environment_scope.as_ref());
     }
+
+    pub(crate) fn add_query_params<'b>(&'b self, params: &mut QueryParams<'b>) {
+        params.push_opt("filter[environment_scope]", self.environment_scope.as_ref());
+    }
 }
 
 /// Get the variable from a project.
@@ -76,6 +81,10 @@ impl<'a> Endpoint for ProjectVariable<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for ProjectVariable<'a> {
+    type Response = types::Variable;
+}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
@@ -0,0 +1,219 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support for waiting on pipelines and jobs.
+
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+use thiserror::Error;
+
+use crate::api::ApiError;
+use crate::types::StatusState;
+
+/// Errors which may occur when waiting for a pipeline or job to finish.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum WaitError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// A query failed.
+    #[error("{}", source)]
+    Api {
+        /// The source of the error.
+        #[from]
+        source: ApiError<E>,
+    },
+    /// The pipeline or job did not finish in time.
+    #[error("timed out after {:?} with status {:?}", elapsed, status)]
+    Timeout {
+        /// The last status which was seen.
+        status: StatusState,
+        /// How long was spent waiting.
+        elapsed: Duration,
+    },
+}
+
+/// Whether a status will no longer change on its own.
+pub(crate) fn is_finished(status: StatusState) -> bool {
+    matches!(
+        status,
+        StatusState::Success | StatusState::Failed | StatusState::Canceled | StatusState::Skipped,
+    )
+}
+
+/// Check that a backoff factor will not shrink or break the interval between polls.
+pub(crate) fn validate_backoff(backoff: Option<f64>) -> Result<(), String> {
+    match backoff {
+        Some(backoff) if !backoff.is_finite() || backoff < 1.0 => {
+            Err(format!(
+                "the backoff must be a finite number of at least 1.0, not {}",
+                backoff,
+            ))
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Tracks how long to wait between polls.
+pub(crate) struct Poller {
+    start: Instant,
+    delay: Duration,
+    backoff: f64,
+    max_interval: Option<Duration>,
+    timeout: Option<Duration>,
+}
+
+impl Poller {
+    pub(crate) fn new(
+        interval: Duration,
+        backoff: f64,
+        max_interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            start: Instant::now(),
+            delay: interval,
+            backoff,
+            max_interval,
+            timeout,
+        }
+    }
+
+    /// How long to wait before the next poll.
+    ///
+    /// Waits are cut short so that the last poll happens at the timeout. Once the timeout has
+    /// passed, an error is returned instead.
+    pub(crate) fn next_delay<E>(&mut self, status: StatusState) -> Result<Duration, WaitError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        let elapsed = self.start.elapsed();
+        let delay = if let Some(timeout) = self.timeout {
+            match timeout.checked_sub(elapsed) {
+                Some(remaining) if remaining > Duration::from_secs(0) => self.delay.min(remaining),
+                _ => {
+                    return Err(WaitError::Timeout {
+                        status,
+                        elapsed,
+                    });
+                },
+            }
+        } else {
+            self.delay
+        };
+
+        // Saturate rather than overflow when backing off for a long time.
+        let secs = self.delay.as_secs_f64() * self.backoff;
+        let next = if secs >= Duration::MAX.as_secs_f64() {
+            Duration::MAX
+        } else {
+            Duration::from_secs_f64(secs)
+        };
+        self.delay = if let Some(max_interval) = self.max_interval {
+            next.min(max_interval)
+        } else {
+            next
+        };
+
+        Ok(delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::api::projects::wait::{self, Poller, WaitError};
+    use crate::test::client::TestClientError;
+    use crate::types::StatusState;
+
+    fn delay(poller: &mut Poller) -> Duration {
+        poller
+            .next_delay::<TestClientError>(StatusState::Running)
+            .unwrap()
+    }
+
+    #[test]
+    fn constant_interval() {
+        let mut poller = Poller::new(Duration::from_secs(2), 1.0, None, None);
+        assert_eq!(delay(&mut poller), Duration::from_secs(2));
+        assert_eq!(delay(&mut poller), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let mut poller = Poller::new(
+            Duration::from_secs(1),
+            2.0,
+            Some(Duration::from_secs(5)),
+            None,
+        );
+        assert_eq!(delay(&mut poller), Duration::from_secs(1));
+        assert_eq!(delay(&mut poller), Duration::from_secs(2));
+        assert_eq!(delay(&mut poller), Duration::from_secs(4));
+        assert_eq!(delay(&mut poller), Duration::from_secs(5));
+        assert_eq!(delay(&mut poller), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn backoff_saturates() {
+        let mut poller = Poller::new(Duration::from_secs(1), 10.0, None, None);
+        for _ in 0..100 {
+            delay(&mut poller);
+        }
+        assert_eq!(delay(&mut poller), Duration::MAX);
+    }
+
+    #[test]
+    fn backoff_validation() {
+        assert!(wait::validate_backoff(None).is_ok());
+        assert!(wait::validate_backoff(Some(1.0)).is_ok());
+        assert!(wait::validate_backoff(Some(1.5)).is_ok());
+        assert_eq!(
+            wait::validate_backoff(Some(0.5)).unwrap_err(),
+            "the backoff must be a finite number of at least 1.0, not 0.5",
+        );
+        assert!(wait::validate_backoff(Some(-1.0)).is_err());
+        assert!(wait::validate_backoff(Some(f64::NAN)).is_err());
+        assert!(wait::validate_backoff(Some(f64::INFINITY)).is_err());
+    }
+
+    #[test]
+    fn delay_is_limited_by_timeout() {
+        let mut poller = Poller::new(
+            Duration::from_secs(3600),
+            1.0,
+            None,
+            Some(Duration::from_secs(60)),
+        );
+        let delay = delay(&mut poller);
+        assert!(delay <= Duration::from_secs(60));
+        assert!(delay > Duration::from_secs(50));
+    }
+
+    #[test]
+    fn timeout() {
+        let mut poller = Poller::new(
+            Duration::from_secs(1),
+            1.0,
+            None,
+            Some(Duration::from_secs(0)),
+        );
+        let err = poller
+            .next_delay::<TestClientError>(StatusState::Pending)
+            .unwrap_err();
+        if let WaitError::Timeout {
+            status, ..
+        } = err
+        {
+            assert_eq!(status, StatusState::Pending);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -45,9 +45,13 @@ where
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
                 v
             } else {
-                return Err(ApiError::server_error(rsp.status(), rsp.body()));
+                return Err(ApiError::server_error(
+                    rsp.status(),
+                    rsp.headers(),
+                    rsp.body(),
+                ));
             };
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(rsp.status(), rsp.headers(), v));
         }
 
         Ok(rsp.into_body().as_ref().into())
@@ -78,9 +82,13 @@ where
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
                 v
             } else {
-                return Err(ApiError::server_error(rsp.status(), rsp.body()));
+                return Err(ApiError::server_error(
+                    rsp.status(),
+                    rsp.headers(),
+                    rsp.body(),
+                ));
             };
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(rsp.status(), rsp.headers(), v));
         }
 
         Ok(rsp.into_body().as_ref().into())
@@ -156,7 +164,7 @@ mod tests {
     fn test_gitlab_error_detection() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
-            .status(StatusCode::NOT_FOUND)
+            .status(StatusCode::BAD_REQUEST)
             .build()
             .unwrap();
         let client = SingleTestClient::new_json(
@@ -168,9 +176,11 @@ mod tests {
 
         let err = api::raw(Dummy).query(&client).unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
@@ -178,12 +188,37 @@ mod tests {
     }
 
     #[test]
-    fn test_gitlab_error_detection_legacy() {
+    fn test_gitlab_error_not_found() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let err = api::raw(Dummy).query(&client).unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_detection_legacy() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
         let client = SingleTestClient::new_json(
             endpoint,
             &json!({
@@ -193,9 +228,11 @@ mod tests {
 
         let err = api::raw(Dummy).query(&client).unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
@@ -203,12 +240,37 @@ mod tests {
     }
 
     #[test]
-    fn test_gitlab_error_detection_unknown() {
+    fn test_gitlab_error_not_found_legacy() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "error": "dummy error message",
+            }),
+        );
+
+        let err = api::raw(Dummy).query(&client).unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_detection_unknown() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
         let err_obj = json!({
             "bogus": "dummy error message",
         });
@@ -216,12 +278,37 @@ mod tests {
 
         let err = api::raw(Dummy).query(&client).unwrap_err();
         if let ApiError::GitlabUnrecognized {
+            status,
             obj,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(obj, err_obj);
         } else {
             panic!("unexpected error: {}", err);
         }
     }
+
+    #[test]
+    fn test_gitlab_error_not_found_unknown() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let err_obj = json!({
+            "bogus": "dummy error message",
+        });
+        let client = SingleTestClient::new_json(endpoint, &err_obj);
+
+        let err = api::raw(Dummy).query(&client).unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.body(), &err_obj);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
 }
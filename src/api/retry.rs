@@ -390,11 +390,11 @@ mod test {
 
         let res: Result<DummyResult, _> = Dummy.query(&client);
         let err = res.unwrap_err();
-        if let ApiError::Gitlab {
-            msg,
+        if let ApiError::NotFound {
+            error,
         } = err
         {
-            assert_eq!(msg, "dummy error message");
+            assert_eq!(error.message(), Some("dummy error message"));
         } else {
             panic!("unexpected error: {}", err);
         }
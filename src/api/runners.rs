@@ -0,0 +1,62 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Runner API endpoints.
+//!
+//! These endpoints are used for querying and modifying CI runners. Runners may also be queried
+//! and assigned within projects and groups.
+
+mod all;
+mod delete;
+mod edit;
+mod jobs;
+mod register;
+mod reset_registration_token;
+mod runner;
+mod runners;
+mod unregister;
+
+pub use self::all::AllRunners;
+pub use self::all::AllRunnersBuilder;
+pub use self::all::AllRunnersBuilderError;
+
+pub use self::delete::DeleteRunner;
+pub use self::delete::DeleteRunnerBuilder;
+pub use self::delete::DeleteRunnerBuilderError;
+
+pub use self::edit::EditRunner;
+pub use self::edit::EditRunnerBuilder;
+pub use self::edit::EditRunnerBuilderError;
+pub use self::edit::RunnerAccessLevel;
+
+pub use self::jobs::RunnerJobStatus;
+pub use self::jobs::RunnerJobs;
+pub use self::jobs::RunnerJobsBuilder;
+pub use self::jobs::RunnerJobsBuilderError;
+
+pub use self::register::RegisterRunner;
+pub use self::register::RegisterRunnerBuilder;
+pub use self::register::RegisterRunnerBuilderError;
+
+pub use self::reset_registration_token::ResetRunnerRegistrationToken;
+pub use self::reset_registration_token::ResetRunnerRegistrationTokenBuilder;
+pub use self::reset_registration_token::ResetRunnerRegistrationTokenBuilderError;
+
+pub use self::runner::Runner;
+pub use self::runner::RunnerBuilder;
+pub use self::runner::RunnerBuilderError;
+
+pub use self::runners::RunnerStatus;
+pub use self::runners::RunnerType;
+pub use self::runners::Runners;
+pub use self::runners::RunnersBuilder;
+pub use self::runners::RunnersBuilderError;
+
+pub use self::unregister::UnregisterRunner;
+pub use self::unregister::UnregisterRunnerBuilder;
+pub use self::unregister::UnregisterRunnerBuilderError;
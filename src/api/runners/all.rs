@@ -0,0 +1,180 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::CommaSeparatedList;
+use crate::api::endpoint_prelude::*;
+use crate::api::runners::{RunnerStatus, RunnerType};
+use crate::types;
+
+/// Query for all runners on an instance.
+///
+/// Requires administrator access.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct AllRunners<'a> {
+    /// Filter runners by type.
+    #[builder(default)]
+    type_: Option<RunnerType>,
+    /// Filter runners by status.
+    #[builder(default)]
+    status: Option<RunnerStatus>,
+    /// Filter runners by whether they are paused or not.
+    #[builder(default)]
+    paused: Option<bool>,
+    /// Filter runners to those with all of the given tags.
+    #[builder(setter(name = "_tag_list"), default, private)]
+    tag_list: Option<CommaSeparatedList<Cow<'a, str>>>,
+}
+
+impl<'a> AllRunners<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AllRunnersBuilder<'a> {
+        AllRunnersBuilder::default()
+    }
+}
+
+impl<'a> AllRunnersBuilder<'a> {
+    /// Filter runners to those with a tag.
+    pub fn tag<T>(&mut self, tag: T) -> &mut Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(tag.into());
+        self
+    }
+
+    /// Filter runners to those with all of a set of tags.
+    pub fn tags<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for AllRunners<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "runners/all".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("type", self.type_)
+            .push_opt("status", self.status)
+            .push_opt("paused", self.paused)
+            .push_opt("tag_list", self.tag_list.as_ref());
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for AllRunners<'a> {
+    type Response = Vec<types::Runner>;
+}
+
+impl<'a> Pageable for AllRunners<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::runners::{AllRunners, RunnerStatus, RunnerType};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        AllRunners::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/all")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AllRunners::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_type() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/all")
+            .add_query_params(&[("type", "group_type")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AllRunners::builder()
+            .type_(RunnerType::Group)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_status() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/all")
+            .add_query_params(&[("status", "offline")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AllRunners::builder()
+            .status(RunnerStatus::Offline)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_paused() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/all")
+            .add_query_params(&[("paused", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AllRunners::builder().paused(true).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_tag_list() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/all")
+            .add_query_params(&[("tag_list", "linux,docker,gpu")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AllRunners::builder()
+            .tag("linux")
+            .tags(["docker", "gpu"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
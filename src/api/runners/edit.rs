@@ -0,0 +1,246 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+use crate::types;
+
+/// The refs a runner may run jobs for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RunnerAccessLevel {
+    /// The runner may run jobs for any ref.
+    NotProtected,
+    /// The runner may only run jobs for protected refs.
+    RefProtected,
+}
+
+impl RunnerAccessLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            RunnerAccessLevel::NotProtected => "not_protected",
+            RunnerAccessLevel::RefProtected => "ref_protected",
+        }
+    }
+}
+
+impl ParamValue<'static> for RunnerAccessLevel {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Edit the details of a runner.
+///
+/// Runners may be paused and resumed using the `paused` parameter.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditRunner<'a> {
+    /// The ID of the runner.
+    runner: u64,
+
+    /// The description of the runner.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// Whether the runner should ignore new jobs or not.
+    #[builder(default)]
+    paused: Option<bool>,
+    /// The tags of the runner.
+    #[builder(setter(name = "_tag_list"), default, private)]
+    tag_list: Option<BTreeSet<Cow<'a, str>>>,
+    /// Whether the runner should run jobs without tags.
+    #[builder(default)]
+    run_untagged: Option<bool>,
+    /// Whether the runner is locked to its current projects.
+    #[builder(default)]
+    locked: Option<bool>,
+    /// The refs the runner may run jobs for.
+    #[builder(default)]
+    access_level: Option<RunnerAccessLevel>,
+    /// The maximum timeout for jobs on the runner (in seconds).
+    #[builder(default)]
+    maximum_timeout: Option<u64>,
+    /// A note for maintainers of the runner.
+    #[builder(setter(into), default)]
+    maintenance_note: Option<Cow<'a, str>>,
+}
+
+impl<'a> EditRunner<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditRunnerBuilder<'a> {
+        EditRunnerBuilder::default()
+    }
+}
+
+impl<'a> EditRunnerBuilder<'a> {
+    /// Add a tag to the runner.
+    ///
+    /// The tags replace any existing tags of the runner.
+    pub fn tag<T>(&mut self, tag: T) -> &mut Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert(None)
+            .get_or_insert_with(BTreeSet::new)
+            .insert(tag.into());
+        self
+    }
+
+    /// Add multiple tags to the runner.
+    ///
+    /// The tags replace any existing tags of the runner.
+    pub fn tags<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert(None)
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for EditRunner<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("runners/{}", self.runner).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("description", self.description.as_ref())
+            .push_opt("paused", self.paused)
+            .push_opt("run_untagged", self.run_untagged)
+            .push_opt("locked", self.locked)
+            .push_opt("access_level", self.access_level)
+            .push_opt("maximum_timeout", self.maximum_timeout)
+            .push_opt("maintenance_note", self.maintenance_note.as_ref());
+
+        if let Some(tag_list) = self.tag_list.as_ref() {
+            params.extend(tag_list.iter().map(|value| ("tag_list[]", value)));
+        }
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for EditRunner<'a> {
+    type Response = types::RunnerDetails;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::runners::{EditRunner, EditRunnerBuilderError, RunnerAccessLevel};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn runner_access_level_as_str() {
+        let items = &[
+            (RunnerAccessLevel::NotProtected, "not_protected"),
+            (RunnerAccessLevel::RefProtected, "ref_protected"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn runner_is_needed() {
+        let err = EditRunner::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditRunnerBuilderError, "runner");
+    }
+
+    #[test]
+    fn runner_is_sufficient() {
+        EditRunner::builder().runner(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("runners/1")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditRunner::builder().runner(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_paused() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("runners/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("paused=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditRunner::builder()
+            .runner(1)
+            .paused(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_all() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("runners/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "description=desc",
+                "&paused=false",
+                "&run_untagged=false",
+                "&locked=true",
+                "&access_level=ref_protected",
+                "&maximum_timeout=3600",
+                "&maintenance_note=note",
+                "&tag_list%5B%5D=docker",
+                "&tag_list%5B%5D=linux",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditRunner::builder()
+            .runner(1)
+            .description("desc")
+            .paused(false)
+            .tag("linux")
+            .tags(["docker"].iter().copied())
+            .run_untagged(false)
+            .locked(true)
+            .access_level(RunnerAccessLevel::RefProtected)
+            .maximum_timeout(3600)
+            .maintenance_note("note")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,172 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::SortOrder;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+use crate::types;
+
+/// Statuses of jobs run by a runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RunnerJobStatus {
+    /// Jobs which are currently running.
+    Running,
+    /// Jobs which succeeded.
+    Success,
+    /// Jobs which failed.
+    Failed,
+    /// Jobs which were canceled.
+    Canceled,
+}
+
+impl RunnerJobStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            RunnerJobStatus::Running => "running",
+            RunnerJobStatus::Success => "success",
+            RunnerJobStatus::Failed => "failed",
+            RunnerJobStatus::Canceled => "canceled",
+        }
+    }
+}
+
+impl ParamValue<'static> for RunnerJobStatus {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for jobs which have been run by a runner.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct RunnerJobs {
+    /// The ID of the runner.
+    runner: u64,
+
+    /// Filter jobs by status.
+    #[builder(default)]
+    status: Option<RunnerJobStatus>,
+    /// The sort order for returned jobs.
+    ///
+    /// Jobs are ordered by their IDs.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl RunnerJobs {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RunnerJobsBuilder {
+        RunnerJobsBuilder::default()
+    }
+}
+
+impl Endpoint for RunnerJobs {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("runners/{}/jobs", self.runner).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("status", self.status)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl TypedEndpoint for RunnerJobs {
+    type Response = Vec<types::Job>;
+}
+
+impl Pageable for RunnerJobs {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::SortOrder;
+    use crate::api::runners::{RunnerJobStatus, RunnerJobs, RunnerJobsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn runner_job_status_as_str() {
+        let items = &[
+            (RunnerJobStatus::Running, "running"),
+            (RunnerJobStatus::Success, "success"),
+            (RunnerJobStatus::Failed, "failed"),
+            (RunnerJobStatus::Canceled, "canceled"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn runner_is_needed() {
+        let err = RunnerJobs::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RunnerJobsBuilderError, "runner");
+    }
+
+    #[test]
+    fn runner_is_sufficient() {
+        RunnerJobs::builder().runner(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/1/jobs")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RunnerJobs::builder().runner(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_status() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/1/jobs")
+            .add_query_params(&[("status", "failed")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RunnerJobs::builder()
+            .runner(1)
+            .status(RunnerJobStatus::Failed)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/1/jobs")
+            .add_query_params(&[("sort", "asc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RunnerJobs::builder()
+            .runner(1)
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,186 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::runners::RunnerAccessLevel;
+use crate::types;
+
+/// Register a new runner.
+///
+/// The registration token authenticates the request, so this endpoint may be used with an
+/// unauthenticated client.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct RegisterRunner<'a> {
+    /// The registration token of the instance, group, or project.
+    #[builder(setter(into))]
+    token: Cow<'a, str>,
+
+    /// The description of the runner.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// Whether the runner should ignore new jobs or not.
+    #[builder(default)]
+    paused: Option<bool>,
+    /// Whether the runner is locked to its current projects.
+    #[builder(default)]
+    locked: Option<bool>,
+    /// Whether the runner should run jobs without tags.
+    #[builder(default)]
+    run_untagged: Option<bool>,
+    /// The tags of the runner.
+    #[builder(setter(name = "_tag_list"), default, private)]
+    tag_list: BTreeSet<Cow<'a, str>>,
+    /// The refs the runner may run jobs for.
+    #[builder(default)]
+    access_level: Option<RunnerAccessLevel>,
+    /// The maximum timeout for jobs on the runner (in seconds).
+    #[builder(default)]
+    maximum_timeout: Option<u64>,
+    /// A note for maintainers of the runner.
+    #[builder(setter(into), default)]
+    maintenance_note: Option<Cow<'a, str>>,
+}
+
+impl<'a> RegisterRunner<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RegisterRunnerBuilder<'a> {
+        RegisterRunnerBuilder::default()
+    }
+}
+
+impl<'a> RegisterRunnerBuilder<'a> {
+    /// Add a tag to the runner.
+    pub fn tag<T>(&mut self, tag: T) -> &mut Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert_with(BTreeSet::new)
+            .insert(tag.into());
+        self
+    }
+
+    /// Add multiple tags to the runner.
+    pub fn tags<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for RegisterRunner<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "runners".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("token", &self.token)
+            .push_opt("description", self.description.as_ref())
+            .push_opt("paused", self.paused)
+            .push_opt("locked", self.locked)
+            .push_opt("run_untagged", self.run_untagged)
+            .extend(self.tag_list.iter().map(|value| ("tag_list[]", value)))
+            .push_opt("access_level", self.access_level)
+            .push_opt("maximum_timeout", self.maximum_timeout)
+            .push_opt("maintenance_note", self.maintenance_note.as_ref());
+
+        params.into_body()
+    }
+}
+
+impl<'a> TypedEndpoint for RegisterRunner<'a> {
+    type Response = types::RegisteredRunner;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::runners::{RegisterRunner, RegisterRunnerBuilderError, RunnerAccessLevel};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn token_is_needed() {
+        let err = RegisterRunner::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RegisterRunnerBuilderError, "token");
+    }
+
+    #[test]
+    fn token_is_sufficient() {
+        RegisterRunner::builder().token("secret").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("runners")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("token=secret")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RegisterRunner::builder().token("secret").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_all() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("runners")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "token=secret",
+                "&description=desc",
+                "&paused=true",
+                "&locked=false",
+                "&run_untagged=true",
+                "&tag_list%5B%5D=docker",
+                "&tag_list%5B%5D=linux",
+                "&access_level=not_protected",
+                "&maximum_timeout=600",
+                "&maintenance_note=note",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RegisterRunner::builder()
+            .token("secret")
+            .description("desc")
+            .paused(true)
+            .locked(false)
+            .run_untagged(true)
+            .tag("linux")
+            .tags(["docker"].iter().copied())
+            .access_level(RunnerAccessLevel::NotProtected)
+            .maximum_timeout(600)
+            .maintenance_note("note")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,64 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::types;
+
+/// Reset the runner registration token of the instance.
+///
+/// Requires administrator access.
+#[derive(Debug, Builder, Clone, Copy)]
+pub struct ResetRunnerRegistrationToken {}
+
+impl ResetRunnerRegistrationToken {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ResetRunnerRegistrationTokenBuilder {
+        ResetRunnerRegistrationTokenBuilder::default()
+    }
+}
+
+impl Endpoint for ResetRunnerRegistrationToken {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "runners/reset_registration_token".into()
+    }
+}
+
+impl TypedEndpoint for ResetRunnerRegistrationToken {
+    type Response = types::RunnerRegistrationToken;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::runners::ResetRunnerRegistrationToken;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        ResetRunnerRegistrationToken::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("runners/reset_registration_token")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ResetRunnerRegistrationToken::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,260 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::CommaSeparatedList;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+use crate::types;
+
+/// Types of runners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RunnerType {
+    /// Runners available to all projects on the instance.
+    Instance,
+    /// Runners available to all projects within a group.
+    Group,
+    /// Runners available to specific projects.
+    Project,
+}
+
+impl RunnerType {
+    fn as_str(self) -> &'static str {
+        match self {
+            RunnerType::Instance => "instance_type",
+            RunnerType::Group => "group_type",
+            RunnerType::Project => "project_type",
+        }
+    }
+}
+
+impl ParamValue<'static> for RunnerType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Connection statuses of runners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RunnerStatus {
+    /// Runners which have contacted the instance recently.
+    Online,
+    /// Runners which have not contacted the instance recently.
+    Offline,
+    /// Runners which have not contacted the instance in a long time.
+    Stale,
+    /// Runners which have never contacted the instance.
+    NeverContacted,
+}
+
+impl RunnerStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            RunnerStatus::Online => "online",
+            RunnerStatus::Offline => "offline",
+            RunnerStatus::Stale => "stale",
+            RunnerStatus::NeverContacted => "never_contacted",
+        }
+    }
+}
+
+impl ParamValue<'static> for RunnerStatus {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for runners available to the current user.
+///
+/// See `AllRunners` to query all runners on an instance.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Runners<'a> {
+    /// Filter runners by type.
+    #[builder(default)]
+    type_: Option<RunnerType>,
+    /// Filter runners by status.
+    #[builder(default)]
+    status: Option<RunnerStatus>,
+    /// Filter runners by whether they are paused or not.
+    #[builder(default)]
+    paused: Option<bool>,
+    /// Filter runners to those with all of the given tags.
+    #[builder(setter(name = "_tag_list"), default, private)]
+    tag_list: Option<CommaSeparatedList<Cow<'a, str>>>,
+}
+
+impl<'a> Runners<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RunnersBuilder<'a> {
+        RunnersBuilder::default()
+    }
+}
+
+impl<'a> RunnersBuilder<'a> {
+    /// Filter runners to those with a tag.
+    pub fn tag<T>(&mut self, tag: T) -> &mut Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(tag.into());
+        self
+    }
+
+    /// Filter runners to those with all of a set of tags.
+    pub fn tags<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for Runners<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "runners".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("type", self.type_)
+            .push_opt("status", self.status)
+            .push_opt("paused", self.paused)
+            .push_opt("tag_list", self.tag_list.as_ref());
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for Runners<'a> {
+    type Response = Vec<types::Runner>;
+}
+
+impl<'a> Pageable for Runners<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::runners::{RunnerStatus, RunnerType, Runners};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn runner_type_as_str() {
+        let items = &[
+            (RunnerType::Instance, "instance_type"),
+            (RunnerType::Group, "group_type"),
+            (RunnerType::Project, "project_type"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn runner_status_as_str() {
+        let items = &[
+            (RunnerStatus::Online, "online"),
+            (RunnerStatus::Offline, "offline"),
+            (RunnerStatus::Stale, "stale"),
+            (RunnerStatus::NeverContacted, "never_contacted"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Runners::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder().endpoint("runners").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Runners::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_type() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners")
+            .add_query_params(&[("type", "group_type")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Runners::builder().type_(RunnerType::Group).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_status() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners")
+            .add_query_params(&[("status", "offline")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Runners::builder()
+            .status(RunnerStatus::Offline)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_paused() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners")
+            .add_query_params(&[("paused", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Runners::builder().paused(true).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_tag_list() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners")
+            .add_query_params(&[("tag_list", "linux,docker,gpu")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Runners::builder()
+            .tag("linux")
+            .tags(["docker", "gpu"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
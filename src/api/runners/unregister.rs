@@ -0,0 +1,80 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Delete a registered runner using its authentication token.
+///
+/// The authentication token authenticates the request, so this endpoint may be used with an
+/// unauthenticated client.
+#[derive(Debug, Builder, Clone)]
+pub struct UnregisterRunner<'a> {
+    /// The authentication token of the runner.
+    #[builder(setter(into))]
+    token: Cow<'a, str>,
+}
+
+impl<'a> UnregisterRunner<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UnregisterRunnerBuilder<'a> {
+        UnregisterRunnerBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UnregisterRunner<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "runners".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("token", &self.token);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::runners::{UnregisterRunner, UnregisterRunnerBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn token_is_needed() {
+        let err = UnregisterRunner::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnregisterRunnerBuilderError, "token");
+    }
+
+    #[test]
+    fn token_is_sufficient() {
+        UnregisterRunner::builder().token("secret").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("runners")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("token=secret")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UnregisterRunner::builder().token("secret").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
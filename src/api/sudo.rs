@@ -86,6 +86,13 @@ where
     }
 }
 
+impl<'a, E> TypedEndpoint for Sudo<'a, E>
+where
+    E: TypedEndpoint,
+{
+    type Response = E::Response;
+}
+
 #[cfg(test)]
 mod tests {
     use http::StatusCode;
@@ -182,7 +189,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
             .add_query_params(&[("sudo", "user")])
-            .status(StatusCode::NOT_FOUND)
+            .status(StatusCode::BAD_REQUEST)
             .build()
             .unwrap();
         let client = SingleTestClient::new_json(
@@ -195,9 +202,11 @@ mod tests {
         let res: Result<DummyResult, _> = api::sudo(Dummy, "user").query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
@@ -205,13 +214,40 @@ mod tests {
     }
 
     #[test]
-    fn test_gitlab_error_detection_legacy() {
+    fn test_gitlab_error_not_found() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
             .add_query_params(&[("sudo", "user")])
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let res: Result<DummyResult, _> = api::sudo(Dummy, "user").query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_detection_legacy() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("sudo", "user")])
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
         let client = SingleTestClient::new_json(
             endpoint,
             &json!({
@@ -222,9 +258,11 @@ mod tests {
         let res: Result<DummyResult, _> = api::sudo(Dummy, "user").query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
+            status,
             msg,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(msg, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
@@ -232,13 +270,40 @@ mod tests {
     }
 
     #[test]
-    fn test_gitlab_error_detection_unknown() {
+    fn test_gitlab_error_not_found_legacy() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
             .add_query_params(&[("sudo", "user")])
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "error": "dummy error message",
+            }),
+        );
+
+        let res: Result<DummyResult, _> = api::sudo(Dummy, "user").query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_detection_unknown() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("sudo", "user")])
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
         let err_obj = json!({
             "bogus": "dummy error message",
         });
@@ -247,15 +312,42 @@ mod tests {
         let res: Result<DummyResult, _> = api::sudo(Dummy, "user").query(&client);
         let err = res.unwrap_err();
         if let ApiError::GitlabUnrecognized {
+            status,
             obj,
         } = err
         {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
             assert_eq!(obj, err_obj);
         } else {
             panic!("unexpected error: {}", err);
         }
     }
 
+    #[test]
+    fn test_gitlab_error_not_found_unknown() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("sudo", "user")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let err_obj = json!({
+            "bogus": "dummy error message",
+        });
+        let client = SingleTestClient::new_json(endpoint, &err_obj);
+
+        let res: Result<DummyResult, _> = api::sudo(Dummy, "user").query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.body(), &err_obj);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
     #[test]
     fn test_bad_deserialization() {
         let endpoint = ExpectedUrl::builder()
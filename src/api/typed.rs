@@ -0,0 +1,172 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+
+use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Query};
+
+/// A trait which associates a default response type with a query.
+///
+/// Endpoints implementing this trait may be queried using `fetch` without naming the type of
+/// the result. The generic `Query` trait remains available for deserializing into custom
+/// structures.
+#[async_trait]
+pub trait TypedEndpoint {
+    /// The type the endpoint returns by default.
+    type Response: DeserializeOwned + Send + 'static;
+
+    /// Perform the query against the client, returning the default response type.
+    fn fetch<C>(&self, client: &C) -> Result<Self::Response, ApiError<C::Error>>
+    where
+        C: Client,
+        Self: Query<Self::Response, C>,
+    {
+        self.query(client)
+    }
+
+    /// Perform the query asynchronously against the client, returning the default response type.
+    async fn fetch_async<C>(&self, client: &C) -> Result<Self::Response, ApiError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        Self: AsyncQuery<Self::Response, C> + Sync,
+    {
+        self.query_async(client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, TypedEndpoint};
+    use crate::test::client::{ExpectedUrl, PagedTestClient, SingleTestClient};
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    impl Pageable for Dummy {}
+
+    #[derive(Debug, Deserialize)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    impl TypedEndpoint for Dummy {
+        type Response = Vec<DummyResult>;
+    }
+
+    #[test]
+    fn test_fetch() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!([
+                {
+                    "value": 0,
+                },
+            ]),
+        );
+
+        let res = Dummy.fetch(&client).unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].value, 0);
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let err = Dummy.fetch(&client).unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("dummy error message"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_fetch_sudo() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("sudo", "user")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!([
+                {
+                    "value": 1,
+                },
+            ]),
+        );
+
+        let res = api::sudo(Dummy, "user").fetch(&client).unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].value, 1);
+    }
+
+    #[test]
+    fn test_fetch_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client =
+            PagedTestClient::new_raw(endpoint, (0..=255).map(|value| json!({ "value": value })));
+
+        let res = api::paged(Dummy, api::Pagination::All)
+            .fetch(&client)
+            .unwrap();
+        assert_eq!(res.len(), 256);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i as u8);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_async() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!([
+                {
+                    "value": 0,
+                },
+            ]),
+        );
+
+        let res = Dummy.fetch_async(&client).await.unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].value, 0);
+    }
+}
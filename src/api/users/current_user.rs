@@ -7,6 +7,7 @@
 use derive_builder::Builder;
 
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query information about the API calling user.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -29,6 +30,10 @@ impl Endpoint for CurrentUser {
     }
 }
 
+impl TypedEndpoint for CurrentUser {
+    type Response = types::UserPublic;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::users::CurrentUser;
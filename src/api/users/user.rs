@@ -7,6 +7,7 @@
 use derive_builder::Builder;
 
 use crate::api::endpoint_prelude::*;
+use crate::types;
 
 /// Query a user by ID.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -45,6 +46,10 @@ impl Endpoint for User {
     }
 }
 
+impl TypedEndpoint for User {
+    type Response = types::UserBasic;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::users::{User, UserBuilderError};
@@ -12,6 +12,7 @@ use derive_builder::Builder;
 use crate::api::common::{EnableState, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
+use crate::types;
 
 /// Keys user results may be ordered by.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -227,6 +228,10 @@ impl<'a> Endpoint for Users<'a> {
     }
 }
 
+impl<'a> TypedEndpoint for Users<'a> {
+    type Response = Vec<types::UserBasic>;
+}
+
 impl<'a> Pageable for Users<'a> {}
 
 #[cfg(test)]
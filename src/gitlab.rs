@@ -7,9 +7,11 @@
 use std::any;
 use std::convert::TryInto;
 use std::fmt::{self, Debug};
+use std::io;
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures_util::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use graphql_client::{GraphQLQuery, QueryBody, Response};
 use http::{HeaderMap, Response as HttpResponse};
 use itertools::Itertools;
@@ -347,17 +349,42 @@ impl api::Client for Gitlab {
             let request = http_request.try_into()?;
             let rsp = self.client.execute(request)?;
 
-            let mut http_rsp = HttpResponse::builder()
-                .status(rsp.status())
-                .version(rsp.version());
-            let headers = http_rsp.headers_mut().unwrap();
-            for (key, value) in rsp.headers() {
-                headers.insert(key, value.clone());
-            }
+            let http_rsp = response_builder(rsp.status(), rsp.version(), rsp.headers());
             Ok(http_rsp.body(rsp.bytes()?)?)
         };
         call().map_err(api::ApiError::client)
     }
+
+    fn rest_stream(
+        &self,
+        mut request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse<Box<dyn io::Read + Send>>, api::ApiError<Self::Error>> {
+        let call = || -> Result<_, RestError> {
+            self.auth.set_header(request.headers_mut().unwrap())?;
+            let http_request = request.body(body)?;
+            let request = http_request.try_into()?;
+            let rsp = self.client.execute(request)?;
+
+            let http_rsp = response_builder(rsp.status(), rsp.version(), rsp.headers());
+            Ok(http_rsp.body(Box::new(rsp) as Box<dyn io::Read + Send>)?)
+        };
+        call().map_err(api::ApiError::client)
+    }
+}
+
+/// Start an `http` response with the status line and headers of a `reqwest` response.
+fn response_builder(
+    status: http::StatusCode,
+    version: http::Version,
+    headers: &HeaderMap,
+) -> http::response::Builder {
+    let mut http_rsp = HttpResponse::builder().status(status).version(version);
+    let rsp_headers = http_rsp.headers_mut().unwrap();
+    for (key, value) in headers {
+        rsp_headers.insert(key, value.clone());
+    }
+    http_rsp
 }
 
 pub struct GitlabBuilder {
@@ -504,18 +531,40 @@ impl api::AsyncClient for AsyncGitlab {
                 let request = http_request.try_into()?;
                 let rsp = self.client.execute(request).await?;
 
-                let mut http_rsp = HttpResponse::builder()
-                    .status(rsp.status())
-                    .version(rsp.version());
-                let headers = http_rsp.headers_mut().unwrap();
-                for (key, value) in rsp.headers() {
-                    headers.insert(key, value.clone());
-                }
+                let http_rsp = response_builder(rsp.status(), rsp.version(), rsp.headers());
                 Ok(http_rsp.body(rsp.bytes().await?)?)
             }
         };
         call().map_err(api::ApiError::client).await
     }
+
+    async fn rest_async_stream(
+        &self,
+        mut request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<
+        HttpResponse<BoxStream<'static, Result<Bytes, api::ApiError<RestError>>>>,
+        api::ApiError<RestError>,
+    > {
+        use futures_util::TryFutureExt;
+        let call = || {
+            async {
+                self.auth.set_header(request.headers_mut().unwrap())?;
+                let http_request = request.body(body)?;
+                let request = http_request.try_into()?;
+                let rsp = self.client.execute(request).await?;
+
+                let http_rsp = response_builder(rsp.status(), rsp.version(), rsp.headers());
+                let chunks = stream::try_unfold(rsp, |mut rsp| {
+                    async move { Ok(rsp.chunk().await?.map(|chunk| (chunk, rsp))) }
+                })
+                .map_err(|err: reqwest::Error| api::ApiError::client(err.into()))
+                .boxed();
+                Ok(http_rsp.body(chunks)?)
+            }
+        };
+        call().map_err(api::ApiError::client).await
+    }
 }
 
 impl AsyncGitlab {
@@ -0,0 +1,567 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! CI job log processing
+//!
+//! GitLab job logs (traces) contain ANSI escape sequences for colors and markers which delimit
+//! collapsible sections. This module contains utilities for removing them so that the plain text
+//! of the log remains and for parsing a log into its sections.
+//!
+//! ```rust
+//! use gitlab::joblog::JobLog;
+//!
+//! let trace = concat!(
+//!     "\x1b[0Ksection_start:1560896352:build_script[collapsed=true]\r\x1b[0KBuilding\n",
+//!     "make: *** [all] Error 1\n",
+//!     "\x1b[0Ksection_end:1560896592:build_script\r\x1b[0K\n",
+//! );
+//! let log = JobLog::parse(trace.as_bytes());
+//! let section = log.find_section("build_script").unwrap();
+//! assert!(section.collapsed);
+//! assert_eq!(section.duration().unwrap().num_minutes(), 4);
+//! assert_eq!(section.lines().last(), Some("make: *** [all] Error 1"));
+//! ```
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+const ESCAPE: u8 = 0x1b;
+const SECTION_START: &[u8] = b"section_start:";
+const SECTION_END: &[u8] = b"section_end:";
+
+/// Remove an ANSI escape sequence from the start of the data.
+///
+/// Returns the length of the escape sequence.
+fn escape_len(data: &[u8]) -> usize {
+    match data.get(1) {
+        // Control sequence introducer: parameter and intermediate bytes followed by a final byte.
+        Some(b'[') => {
+            data.iter()
+                .skip(2)
+                .position(|byte| (0x40..=0x7e).contains(byte))
+                .map_or(data.len(), |pos| pos + 3)
+        },
+        Some(_) => 2,
+        None => 1,
+    }
+}
+
+/// Remove ANSI escape sequences from data.
+fn strip_escapes(data: &[u8]) -> Vec<u8> {
+    let mut stripped = Vec::with_capacity(data.len());
+    let mut idx = 0;
+    while idx < data.len() {
+        if data[idx] == ESCAPE {
+            idx += escape_len(&data[idx..]);
+        } else {
+            stripped.push(data[idx]);
+            idx += 1;
+        }
+    }
+    stripped
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MarkerKind {
+    Start,
+    End,
+}
+
+#[derive(Debug)]
+struct SectionMarker<'a> {
+    kind: MarkerKind,
+    timestamp: DateTime<Utc>,
+    name: &'a [u8],
+    options: &'a [u8],
+}
+
+/// Parse a section marker at the start of the data.
+///
+/// Markers look like `section_start:<timestamp>:<name>[<options>]\r` where the options are
+/// optional. Section end markers do not have options. Returns the marker and its length.
+fn parse_section_marker(data: &[u8]) -> Option<(SectionMarker<'_>, usize)> {
+    let (kind, prefix_len) = if data.starts_with(SECTION_START) {
+        (MarkerKind::Start, SECTION_START.len())
+    } else if data.starts_with(SECTION_END) {
+        (MarkerKind::End, SECTION_END.len())
+    } else {
+        return None;
+    };
+
+    let rest = &data[prefix_len..];
+    let timestamp_len = rest.iter().take_while(|byte| byte.is_ascii_digit()).count();
+    if timestamp_len == 0 || rest.get(timestamp_len) != Some(&b':') {
+        return None;
+    }
+    let timestamp = String::from_utf8_lossy(&rest[..timestamp_len])
+        .parse()
+        .ok()
+        .and_then(|secs| Utc.timestamp_opt(secs, 0).single())?;
+
+    let rest = &rest[timestamp_len + 1..];
+    let name_len = rest
+        .iter()
+        .take_while(|&&byte| byte != b'[' && byte != b'\r' && byte != b'\n')
+        .count();
+    if name_len == 0 {
+        return None;
+    }
+    let name = &rest[..name_len];
+
+    let rest = &rest[name_len..];
+    let options_len = if rest.first() == Some(&b'[') {
+        rest.iter().position(|&byte| byte == b']')? + 1
+    } else {
+        0
+    };
+    if rest.get(options_len) != Some(&b'\r') {
+        return None;
+    }
+    let options = if options_len > 0 {
+        &rest[1..options_len - 1]
+    } else {
+        &[]
+    };
+
+    let marker = SectionMarker {
+        kind,
+        timestamp,
+        name,
+        options,
+    };
+    Some((
+        marker,
+        prefix_len + timestamp_len + 1 + name_len + options_len + 1,
+    ))
+}
+
+/// Remove ANSI escape sequences and section markers from job log output.
+///
+/// Escape sequences and markers do not span lines, so this may be used on any data which is split
+/// at line boundaries.
+pub fn clean(data: &[u8]) -> Vec<u8> {
+    let without_escapes = strip_escapes(data);
+
+    let mut cleaned = Vec::with_capacity(without_escapes.len());
+    let mut idx = 0;
+    while idx < without_escapes.len() {
+        if let Some((_, len)) = parse_section_marker(&without_escapes[idx..]) {
+            idx += len;
+        } else {
+            cleaned.push(without_escapes[idx]);
+            idx += 1;
+        }
+    }
+
+    cleaned
+}
+
+/// A cleaner for job log output which arrives in arbitrary chunks.
+///
+/// Only complete lines are cleaned; any trailing partial line is kept until the rest of it
+/// arrives (or [`LogCleaner::finish`] is called).
+#[derive(Debug, Default, Clone)]
+pub struct LogCleaner {
+    pending: Vec<u8>,
+}
+
+impl LogCleaner {
+    /// Create a new cleaner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add data to the cleaner.
+    ///
+    /// Returns the cleaned text of any lines which have been completed.
+    pub fn push(&mut self, data: &[u8]) -> Vec<u8> {
+        self.pending.extend_from_slice(data);
+
+        if let Some(end) = self.pending.iter().rposition(|&byte| byte == b'\n') {
+            let rest = self.pending.split_off(end + 1);
+            let complete = std::mem::replace(&mut self.pending, rest);
+            clean(&complete)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Clean any remaining partial line.
+    pub fn finish(&mut self) -> Vec<u8> {
+        clean(&std::mem::take(&mut self.pending))
+    }
+}
+
+/// An item within a job log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogItem {
+    /// A line of text.
+    Line(String),
+    /// A section of the log.
+    Section(Section),
+}
+
+/// A named section of a job log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// The name of the section.
+    pub name: String,
+    /// When the section started.
+    pub started_at: DateTime<Utc>,
+    /// When the section ended.
+    ///
+    /// Sections which were not closed (e.g., because the job is still running or was killed) do
+    /// not have an end time.
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Whether the section is collapsed by default.
+    pub collapsed: bool,
+    /// The options given for the section (e.g., `collapsed=true`).
+    pub options: Vec<(String, String)>,
+    /// The lines and subsections of the section.
+    pub items: Vec<LogItem>,
+}
+
+impl Section {
+    fn new(marker: &SectionMarker) -> Self {
+        let options: Vec<(String, String)> = String::from_utf8_lossy(marker.options)
+            .split(',')
+            .filter(|option| !option.is_empty())
+            .map(|option| {
+                let mut parts = option.splitn(2, '=');
+                let key = parts.next().unwrap_or_default();
+                let value = parts.next().unwrap_or_default();
+                (key.into(), value.into())
+            })
+            .collect();
+        let collapsed = options
+            .iter()
+            .any(|(key, value)| key == "collapsed" && value == "true");
+
+        Self {
+            name: String::from_utf8_lossy(marker.name).into(),
+            started_at: marker.timestamp,
+            finished_at: None,
+            collapsed,
+            options,
+            items: Vec::new(),
+        }
+    }
+
+    /// How long the section took.
+    pub fn duration(&self) -> Option<Duration> {
+        self.finished_at
+            .map(|finished_at| finished_at - self.started_at)
+    }
+
+    /// The lines directly within the section.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        lines(&self.items)
+    }
+
+    /// The sections directly within the section.
+    pub fn sections(&self) -> impl Iterator<Item = &Section> {
+        sections(&self.items)
+    }
+}
+
+fn lines(items: &[LogItem]) -> impl Iterator<Item = &str> {
+    items.iter().filter_map(|item| {
+        if let LogItem::Line(line) = item {
+            Some(line.as_str())
+        } else {
+            None
+        }
+    })
+}
+
+fn sections(items: &[LogItem]) -> impl Iterator<Item = &Section> {
+    items.iter().filter_map(|item| {
+        if let LogItem::Section(section) = item {
+            Some(section)
+        } else {
+            None
+        }
+    })
+}
+
+fn find_section<'a>(items: &'a [LogItem], name: &str) -> Option<&'a Section> {
+    sections(items).find_map(|section| {
+        if section.name == name {
+            Some(section)
+        } else {
+            find_section(&section.items, name)
+        }
+    })
+}
+
+/// The text of a line as it would be displayed.
+///
+/// Carriage returns are used to overwrite a line (e.g., for progress output), so only the text
+/// after the last one is kept.
+fn display_text(text: &[u8]) -> String {
+    let text = text.strip_suffix(b"\r").unwrap_or(text);
+    let text = text
+        .iter()
+        .rposition(|&byte| byte == b'\r')
+        .map_or(text, |pos| &text[pos + 1..]);
+    String::from_utf8_lossy(text).into()
+}
+
+/// The items of the innermost open section.
+fn current<'a>(root: &'a mut Vec<LogItem>, stack: &'a mut [Section]) -> &'a mut Vec<LogItem> {
+    stack.last_mut().map_or(root, |section| &mut section.items)
+}
+
+/// Close the innermost open section.
+fn close(root: &mut Vec<LogItem>, stack: &mut Vec<Section>) {
+    if let Some(section) = stack.pop() {
+        current(root, stack).push(LogItem::Section(section));
+    }
+}
+
+/// A job log parsed into its sections.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JobLog {
+    /// The lines and sections of the log.
+    pub items: Vec<LogItem>,
+}
+
+impl JobLog {
+    /// Parse the raw output of a job.
+    ///
+    /// Lines are cleaned of ANSI escape sequences. Section end markers close any unclosed
+    /// sections nested within the section they end; end markers for unknown sections are ignored.
+    pub fn parse(data: &[u8]) -> Self {
+        let mut root = Vec::new();
+        let mut stack: Vec<Section> = Vec::new();
+
+        if data.is_empty() {
+            return Self::default();
+        }
+
+        let data = data.strip_suffix(b"\n").unwrap_or(data);
+        for raw_line in data.split(|&byte| byte == b'\n') {
+            let line = strip_escapes(raw_line);
+
+            let mut text_start = 0;
+            let mut idx = 0;
+            let mut had_marker = false;
+            while idx < line.len() {
+                let (marker, len) = if let Some(parsed) = parse_section_marker(&line[idx..]) {
+                    parsed
+                } else {
+                    idx += 1;
+                    continue;
+                };
+
+                let text = &line[text_start..idx];
+                if !text.is_empty() {
+                    current(&mut root, &mut stack).push(LogItem::Line(display_text(text)));
+                }
+                had_marker = true;
+
+                match marker.kind {
+                    MarkerKind::Start => stack.push(Section::new(&marker)),
+                    MarkerKind::End => {
+                        let name = String::from_utf8_lossy(marker.name);
+                        if let Some(pos) = stack.iter().rposition(|section| section.name == name) {
+                            stack[pos].finished_at = Some(marker.timestamp);
+                            while stack.len() > pos {
+                                close(&mut root, &mut stack);
+                            }
+                        }
+                    },
+                }
+
+                idx += len;
+                text_start = idx;
+            }
+
+            let text = &line[text_start..];
+            if !text.is_empty() || !had_marker {
+                current(&mut root, &mut stack).push(LogItem::Line(display_text(text)));
+            }
+        }
+
+        while !stack.is_empty() {
+            close(&mut root, &mut stack);
+        }
+
+        Self {
+            items: root,
+        }
+    }
+
+    /// The lines outside of any section.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        lines(&self.items)
+    }
+
+    /// The top-level sections of the log.
+    pub fn sections(&self) -> impl Iterator<Item = &Section> {
+        sections(&self.items)
+    }
+
+    /// Find a section by name anywhere in the log.
+    ///
+    /// The first matching section (in depth-first order) is returned.
+    pub fn find_section(&self, name: &str) -> Option<&Section> {
+        find_section(&self.items, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+
+    use crate::joblog::{self, JobLog, LogCleaner, LogItem};
+
+    #[test]
+    fn clean_ansi() {
+        let data = b"\x1b[32;1mSuccess\x1b[0;m and \x1b[0Kdone\n";
+        assert_eq!(joblog::clean(data), b"Success and done\n");
+    }
+
+    #[test]
+    fn clean_truncated_escape() {
+        assert_eq!(joblog::clean(b"text\x1b[32"), b"text");
+        assert_eq!(joblog::clean(b"text\x1b"), b"text");
+    }
+
+    #[test]
+    fn clean_section_markers() {
+        let data = concat!(
+            "\x1b[0Ksection_start:1560896352:build_script[collapsed=true]\r\x1b[0K",
+            "\x1b[0K\x1b[36;1mExecuting script\x1b[0;m\n",
+            "make\n",
+            "\x1b[0Ksection_end:1560896353:build_script\r\x1b[0K\n",
+        );
+        assert_eq!(
+            joblog::clean(data.as_bytes()),
+            b"Executing script\nmake\n\n".as_ref(),
+        );
+    }
+
+    #[test]
+    fn clean_keeps_lookalikes() {
+        let data = b"section_start:not a marker\rsection_end:1:\r\n";
+        assert_eq!(joblog::clean(data), data.as_ref());
+    }
+
+    #[test]
+    fn cleaner_partial_lines() {
+        let mut cleaner = LogCleaner::new();
+        assert_eq!(cleaner.push(b"first \x1b[3"), b"");
+        assert_eq!(cleaner.push(b"1mline\nsecond"), b"first line\n");
+        assert_eq!(cleaner.push(b" line\x1b[0m"), b"");
+        assert_eq!(cleaner.finish(), b"second line");
+        assert_eq!(cleaner.finish(), b"");
+    }
+
+    #[test]
+    fn parse_plain() {
+        let log = JobLog::parse(b"first\n\nthird\n");
+        assert_eq!(log.lines().collect::<Vec<_>>(), ["first", "", "third"]);
+        assert_eq!(log.sections().count(), 0);
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert!(JobLog::parse(b"").items.is_empty());
+        assert_eq!(JobLog::parse(b"\n").items, [LogItem::Line(String::new())]);
+    }
+
+    #[test]
+    fn parse_carriage_returns() {
+        let log = JobLog::parse(b"10%\r50%\r100%\r\ndone\r\n");
+        assert_eq!(log.lines().collect::<Vec<_>>(), ["100%", "done"]);
+    }
+
+    #[test]
+    fn parse_sections() {
+        let data = concat!(
+            "\x1b[32;1mRunning with gitlab-runner\x1b[0;m\n",
+            "\x1b[0Ksection_start:1560896352:prepare_script[collapsed=true,hide_duration=true]\r",
+            "\x1b[0K\x1b[0K\x1b[36;1mPreparing environment\x1b[0;m\n",
+            "Running on runner-1\n",
+            "\x1b[0Ksection_end:1560896353:prepare_script\r\x1b[0K\n",
+            "\x1b[0Ksection_start:1560896353:build_script\r\x1b[0KBuilding\n",
+            "\x1b[0Ksection_start:1560896360:compile\r\x1b[0K\n",
+            "make\n",
+            "\x1b[0Ksection_end:1560896600:compile\r\x1b[0K\n",
+            "\x1b[31;1mERROR: Job failed\x1b[0;m\n",
+        );
+        let log = JobLog::parse(data.as_bytes());
+
+        assert_eq!(
+            log.lines().collect::<Vec<_>>(),
+            ["Running with gitlab-runner"],
+        );
+        let sections: Vec<_> = log.sections().collect();
+        assert_eq!(sections.len(), 2);
+
+        let prepare = sections[0];
+        assert_eq!(prepare.name, "prepare_script");
+        assert_eq!(
+            prepare.started_at,
+            Utc.timestamp_opt(1560896352, 0).unwrap()
+        );
+        assert_eq!(
+            prepare.finished_at,
+            Some(Utc.timestamp_opt(1560896353, 0).unwrap()),
+        );
+        assert_eq!(prepare.duration(), Some(Duration::seconds(1)));
+        assert!(prepare.collapsed);
+        assert_eq!(
+            prepare.options,
+            [
+                ("collapsed".into(), "true".into()),
+                ("hide_duration".into(), "true".into()),
+            ],
+        );
+        assert_eq!(
+            prepare.lines().collect::<Vec<_>>(),
+            ["Preparing environment", "Running on runner-1"],
+        );
+        assert_eq!(prepare.sections().count(), 0);
+
+        // The build section is never closed.
+        let build = sections[1];
+        assert_eq!(build.name, "build_script");
+        assert_eq!(build.finished_at, None);
+        assert_eq!(build.duration(), None);
+        assert!(!build.collapsed);
+        assert_eq!(
+            build.lines().collect::<Vec<_>>(),
+            ["Building", "ERROR: Job failed"],
+        );
+
+        let compile = log.find_section("compile").unwrap();
+        assert_eq!(build.sections().next(), Some(compile));
+        assert_eq!(compile.duration(), Some(Duration::seconds(240)));
+        assert_eq!(compile.lines().collect::<Vec<_>>(), ["make"]);
+        assert!(log.find_section("missing").is_none());
+    }
+
+    #[test]
+    fn parse_mismatched_end() {
+        let data = concat!(
+            "section_start:1:outer\r\n",
+            "section_start:2:inner\r\n",
+            "inner text\n",
+            "section_end:3:unknown\r\n",
+            "section_end:4:outer\r\n",
+            "after\n",
+        );
+        let log = JobLog::parse(data.as_bytes());
+
+        assert_eq!(log.lines().collect::<Vec<_>>(), ["after"]);
+        let outer = log.find_section("outer").unwrap();
+        assert_eq!(outer.duration(), Some(Duration::seconds(3)));
+        let inner = log.find_section("inner").unwrap();
+        assert_eq!(inner.finished_at, None);
+        assert_eq!(inner.lines().collect::<Vec<_>>(), ["inner text"]);
+    }
+}
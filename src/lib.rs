@@ -15,6 +15,7 @@ mod macros;
 mod gitlab;
 
 pub mod hooks;
+pub mod joblog;
 pub mod systemhooks;
 pub mod types;
 pub mod webhooks;
@@ -24,6 +25,9 @@ pub mod api;
 #[cfg(feature = "client_api")]
 mod auth;
 
+#[cfg(any(feature = "testing", all(test, feature = "client_api")))]
+pub mod testing;
+
 #[cfg(feature = "client_api")]
 pub use crate::auth::AuthError;
 #[cfg(feature = "client_api")]
@@ -620,6 +620,55 @@ fn test_read_project() {
     check_project_c(&project);
 }
 
+#[test]
+fn test_read_project_fork() {
+    let project: Project = read_test_file("project_fork");
+
+    assert_eq!(project.id, ProjectId::new(4219));
+    assert_eq!(project.path_with_namespace, "ben.boeckel/rust-gitlab");
+    assert_eq!(project.namespace.id(), NamespaceId::User(UserId::new(13)));
+
+    let forked_from = project.forked_from_project.unwrap();
+    assert_eq!(forked_from.id, ProjectId::new(855));
+    assert_eq!(forked_from.name, "rust-gitlab");
+    assert_eq!(forked_from.name_with_namespace, "Utils / rust-gitlab");
+    assert_eq!(forked_from.path, "rust-gitlab");
+    assert_eq!(forked_from.path_with_namespace, "utils/rust-gitlab");
+    assert_eq!(
+        forked_from.http_url_to_repo,
+        "https://gitlab.kitware.com/utils/rust-gitlab.git",
+    );
+    assert_eq!(
+        forked_from.web_url,
+        "https://gitlab.kitware.com/utils/rust-gitlab",
+    );
+    assert_eq!(
+        forked_from.description.as_ref().unwrap(),
+        "Rust library for communicating with a Gitlab instance.",
+    );
+    assert_eq!(forked_from.default_branch.as_ref().unwrap(), "master");
+    assert_eq!(
+        forked_from.ssh_url_to_repo.as_ref().unwrap(),
+        "git@gitlab.kitware.com:utils/rust-gitlab.git",
+    );
+    assert_eq!(
+        forked_from.readme_url.as_ref().unwrap(),
+        "https://gitlab.kitware.com/utils/rust-gitlab/-/blob/master/README.md",
+    );
+    assert_eq!(forked_from.avatar_url, None);
+    check_namespace_utils(forked_from.namespace.as_ref().unwrap());
+    assert_eq!(
+        forked_from.created_at.unwrap(),
+        datetime((2016, 6, 29), (17, 35, 12, 495)),
+    );
+    assert_eq!(
+        forked_from.last_activity_at.unwrap(),
+        datetime((2022, 8, 22), (12, 56, 48, 154)),
+    );
+    assert_eq!(forked_from.star_count, Some(16));
+    assert_eq!(forked_from.forks_count, Some(59));
+}
+
 #[test]
 fn test_read_project_hook() {
     let project_hook: ProjectHook = read_test_file("project_hook");
@@ -927,6 +976,232 @@ fn test_read_pipeline() {
     );
 }
 
+#[test]
+fn test_read_bridge() {
+    let bridge: Bridge = read_test_file("bridge");
+
+    assert_eq!(bridge.id, JobId::new(4_000_000));
+    assert_eq!(bridge.status, StatusState::Running);
+    assert_eq!(bridge.stage, "deploy");
+    assert_eq!(bridge.name, "trigger:downstream");
+    assert_eq!(bridge.ref_.as_ref().unwrap(), "master");
+    assert!(!bridge.tag);
+    assert_eq!(bridge.coverage, None);
+    assert_eq!(bridge.created_at, datetime((2020, 1, 1), (0, 0, 0, 0)));
+    assert_eq!(
+        bridge.started_at.unwrap(),
+        datetime((2020, 1, 1), (0, 0, 30, 0)),
+    );
+    assert_eq!(bridge.finished_at, None);
+    assert_eq!(bridge.duration, Some(60.5));
+    assert_eq!(bridge.user.as_ref().unwrap().username, "buildbot");
+    assert_eq!(
+        bridge.commit.id.value(),
+        "0028f47612b928d94e5e1a4329f3e74d6fdd7032",
+    );
+    assert_eq!(bridge.pipeline.id, PipelineId::new(168_478));
+    assert!(!bridge.allow_failure);
+    assert_eq!(
+        bridge.web_url,
+        "https://gitlab.kitware.com/utils/rust-gitlab/-/jobs/4000000",
+    );
+    let downstream = bridge.downstream_pipeline.as_ref().unwrap();
+    assert_eq!(downstream.id, PipelineId::new(145_500));
+    assert_eq!(downstream.project_id, ProjectId::new(6));
+    assert_eq!(downstream.status, StatusState::Running);
+}
+
+#[test]
+fn test_read_test_report() {
+    let report: TestReport = read_test_file("test_report");
+
+    assert_eq!(report.total_time, 1.5);
+    assert_eq!(report.total_count, 4);
+    assert_eq!(report.success_count, 2);
+    assert_eq!(report.failed_count, 1);
+    assert_eq!(report.skipped_count, 1);
+    assert_eq!(report.error_count, 0);
+    assert_eq!(report.test_suites.len(), 1);
+    let suite = &report.test_suites[0];
+    assert_eq!(suite.name, "test:linux");
+    assert_eq!(suite.total_count, 4);
+    assert_eq!(suite.suite_error, None);
+    assert!(suite.build_ids.is_empty());
+    assert_eq!(suite.test_cases.len(), 4);
+    let case = &suite.test_cases[0];
+    assert_eq!(case.status, TestCaseStatus::Success);
+    assert_eq!(case.name, "test_create_project");
+    assert_eq!(case.classname, "gitlab::api::projects");
+    assert_eq!(case.file.as_ref().unwrap(), "src/api/projects.rs");
+    assert_eq!(case.execution_time, 0.25);
+    assert!(case.recent_failures.is_none());
+    let case = &suite.test_cases[1];
+    assert_eq!(case.status, TestCaseStatus::Failed);
+    assert_eq!(
+        case.stack_trace.as_ref().unwrap(),
+        "assertion failed: left == right",
+    );
+    let recent_failures = case.recent_failures.as_ref().unwrap();
+    assert_eq!(recent_failures.count, 3);
+    assert_eq!(recent_failures.base_branch, "master");
+    assert_eq!(suite.test_cases[2].status, TestCaseStatus::Skipped);
+}
+
+#[test]
+fn test_read_test_report_summary() {
+    let summary: TestReportSummary = read_test_file("test_report_summary");
+
+    assert_eq!(summary.total.time, 1.5);
+    assert_eq!(summary.total.count, 4);
+    assert_eq!(summary.total.success, 2);
+    assert_eq!(summary.total.failed, 1);
+    assert_eq!(summary.total.skipped, 1);
+    assert_eq!(summary.total.error, 0);
+    assert_eq!(summary.total.suite_error, None);
+    assert_eq!(summary.test_suites.len(), 1);
+    let suite = &summary.test_suites[0];
+    assert_eq!(suite.name, "test:linux");
+    assert_eq!(suite.build_ids, [JobId::new(4_895_233)]);
+    assert!(suite.test_cases.is_empty());
+}
+
+#[test]
+fn test_read_repo_compare() {
+    let compare: RepoCompare = read_test_file("repo_compare");
+
+    let commit = compare.commit.as_ref().unwrap();
+    assert_eq!(
+        commit.id,
+        ObjectId::new("de4ac3cf96cb8a0893be22b03f5171d934f9d392"),
+    );
+    assert_eq!(commit.title, "Merge topic 'mr-awards'");
+    assert_eq!(compare.commits.len(), 2);
+    assert_eq!(
+        compare.commits[0].id,
+        ObjectId::new("a222c5539569cda6999b8069f1e51a5202c30711"),
+    );
+    assert_eq!(
+        compare.commits[0].parent_ids,
+        Some(vec![ObjectId::new(
+            "559f5f4a2bfe1f48e9e95afa09c029deb655cf7d",
+        )]),
+    );
+    assert_eq!(compare.commits[1].id, commit.id);
+    assert_eq!(compare.diffs.len(), 1);
+    let diff = &compare.diffs[0];
+    assert_eq!(diff.old_path, "src/gitlab.rs");
+    assert_eq!(diff.new_path, "src/gitlab.rs");
+    assert_eq!(diff.a_mode, "100644");
+    assert_eq!(diff.b_mode, "100644");
+    assert!(diff.diff.contains("pub fn merge_request_awards"));
+    assert!(!diff.new_file);
+    assert!(!diff.renamed_file);
+    assert!(!diff.deleted_file);
+    assert!(!compare.compare_timeout);
+    assert!(!compare.compare_same_ref);
+    assert_eq!(
+        compare.web_url.as_ref().unwrap(),
+        "https://gitlab.kitware.com/utils/rust-gitlab/-/compare/559f5f4a2bfe1f48e9e95afa09c029deb655cf7d...de4ac3cf96cb8a0893be22b03f5171d934f9d392",
+    );
+}
+
+#[test]
+fn test_read_ci_lint_result() {
+    let result: CiLintResult = read_test_file("ci_lint_result");
+
+    assert!(result.valid);
+    assert!(result.errors.is_empty());
+    assert_eq!(result.warnings.len(), 1);
+    assert!(result.warnings[0].starts_with("jobs:test may allow multiple pipelines"));
+    assert!(result
+        .merged_yaml
+        .as_ref()
+        .unwrap()
+        .contains("cmake --build build"));
+    assert_eq!(result.jobs.len(), 2);
+    let build = &result.jobs[0];
+    assert_eq!(build.name, "build");
+    assert_eq!(build.stage, "build");
+    assert!(build.before_script.is_empty());
+    assert_eq!(build.script, ["cmake --build build"]);
+    assert!(build.after_script.is_empty());
+    assert_eq!(build.tag_list, ["linux"]);
+    assert_eq!(
+        build.only,
+        Some(json!({
+            "refs": ["branches", "tags"],
+        })),
+    );
+    assert_eq!(build.except, None);
+    assert_eq!(build.environment, None);
+    assert_eq!(build.when, "on_success");
+    assert!(!build.allow_failure);
+    assert!(build.needs.is_empty());
+    let test = &result.jobs[1];
+    assert_eq!(test.name, "test");
+    assert_eq!(test.only, None);
+    assert_eq!(test.needs.len(), 1);
+    assert_eq!(test.needs[0].name, "build");
+}
+
+#[test]
+fn test_read_deployment() {
+    let deployment: Deployment = read_test_file("deployment");
+
+    assert_eq!(deployment.id, DeploymentId::new(1024));
+    assert_eq!(deployment.iid, 12);
+    assert_eq!(deployment.r#ref, "master");
+    assert_eq!(deployment.sha, "de4ac3cf96cb8a0893be22b03f5171d934f9d392");
+    assert_eq!(deployment.status.as_ref().unwrap(), "success");
+    check_user_buildbot(&deployment.user);
+    let deployable = deployment.deployable.as_ref().unwrap();
+    assert_eq!(deployable.status.as_ref().unwrap(), "success");
+    assert_eq!(
+        deployable.commit.id.as_ref().unwrap(),
+        "de4ac3cf96cb8a0893be22b03f5171d934f9d392",
+    );
+    assert_eq!(
+        deployable.commit.title.as_ref().unwrap(),
+        "Merge topic 'mr-awards'",
+    );
+    assert_eq!(deployment.approvals.len(), 2);
+    let approval = &deployment.approvals[0];
+    check_user_brad_king(&approval.user);
+    assert_eq!(approval.status, DeploymentApprovalStatus::Approved);
+    assert_eq!(
+        approval.created_at,
+        datetime((2016, 11, 8), (14, 44, 51, 320)),
+    );
+    assert_eq!(
+        approval.comment.as_ref().unwrap(),
+        "Release notes reviewed.",
+    );
+    let rejection = &deployment.approvals[1];
+    check_user_kwrobot(&rejection.user);
+    assert_eq!(rejection.status, DeploymentApprovalStatus::Rejected);
+    assert_eq!(rejection.comment, None);
+}
+
+#[test]
+fn test_read_environment() {
+    let environment: Environment = read_test_file("environment");
+
+    assert_eq!(environment.id, EnvironmentId::new(53));
+    assert_eq!(environment.name, "production");
+    assert_eq!(environment.slug, "production");
+    assert_eq!(
+        environment.external_url.as_ref().unwrap(),
+        "https://docs.rs/gitlab",
+    );
+    assert_eq!(environment.state.as_ref().unwrap(), "available");
+    assert_eq!(environment.tier.as_ref().unwrap(), "production");
+    let deployment = environment.last_deployment.as_ref().unwrap();
+    assert_eq!(deployment.id, DeploymentId::new(1025));
+    check_user_brad_king(&deployment.user);
+    assert!(deployment.deployable.is_none());
+    assert!(deployment.approvals.is_empty());
+}
+
 #[test]
 fn test_read_pipeline_variables() {
     let var: PipelineVariable = read_test_file("pipeline_variable");
@@ -936,6 +1211,138 @@ fn test_read_pipeline_variables() {
     assert_eq!(var.value, "true");
 }
 
+#[test]
+fn test_read_pipeline_schedule() {
+    let schedule: PipelineSchedule = read_test_file("pipeline_schedule");
+
+    assert_eq!(schedule.id, PipelineScheduleId::new(13));
+    assert_eq!(schedule.description, "Nightly build");
+    assert_eq!(schedule.ref_, "master");
+    assert_eq!(schedule.cron, "0 1 * * *");
+    assert_eq!(schedule.cron_timezone, "America/New_York");
+    assert_eq!(
+        schedule.next_run_at.unwrap(),
+        datetime((2020, 1, 3), (6, 0, 0, 0)),
+    );
+    assert!(schedule.active);
+    assert_eq!(schedule.created_at, datetime((2020, 1, 1), (0, 0, 0, 0)));
+    assert_eq!(schedule.updated_at, datetime((2020, 1, 2), (0, 0, 0, 0)));
+    assert_eq!(schedule.owner.as_ref().unwrap().username, "buildbot");
+    let last_pipeline = schedule.last_pipeline.as_ref().unwrap();
+    assert_eq!(last_pipeline.id, PipelineId::new(145_400));
+    assert_eq!(
+        last_pipeline.sha.value(),
+        "7ab5c0b0ad4af9b2e2e4f1a0e8e0c4b3f2d7e8a1",
+    );
+    assert_eq!(last_pipeline.ref_.as_ref().unwrap(), "master");
+    assert_eq!(last_pipeline.status, StatusState::Success);
+    assert_eq!(schedule.variables.len(), 1);
+    assert_eq!(schedule.variables[0].key, "RUN_NIGHTLY_BUILD");
+}
+
+#[test]
+fn test_read_pipeline_trigger() {
+    let trigger: PipelineTrigger = read_test_file("pipeline_trigger");
+
+    assert_eq!(trigger.id, PipelineTriggerId::new(10));
+    assert_eq!(trigger.description, "Nightly downstream builds");
+    assert_eq!(trigger.token, "6d056f63e50fe6f8c5f8f4aa10edb7");
+    assert_eq!(trigger.created_at, datetime((2020, 1, 1), (0, 0, 0, 0)));
+    assert_eq!(trigger.updated_at, datetime((2020, 1, 2), (0, 0, 0, 0)));
+    assert_eq!(trigger.last_used, None);
+    assert_eq!(trigger.owner.as_ref().unwrap().username, "buildbot");
+}
+
+#[test]
+fn test_read_variable() {
+    let var: Variable = read_test_file("variable");
+
+    assert_eq!(var.key, "DEPLOY_KEY");
+    assert_eq!(var.variable_type, PipelineVariableType::File);
+    assert_eq!(var.value, "secret");
+    assert!(var.protected);
+    assert!(!var.masked);
+    assert!(var.raw);
+    assert_eq!(var.environment_scope.as_ref().unwrap(), "production");
+}
+
+#[test]
+fn test_read_release() {
+    let release: Release = read_test_file("release");
+
+    assert_eq!(release.tag_name, "v1.0");
+    assert_eq!(release.name.as_ref().unwrap(), "Version 1.0");
+    assert_eq!(release.description.as_ref().unwrap(), "Initial release.");
+    assert_eq!(release.description_html, None);
+    assert_eq!(release.created_at, datetime((2020, 1, 1), (0, 0, 0, 0)));
+    assert_eq!(
+        release.released_at.unwrap(),
+        datetime((2020, 1, 2), (0, 0, 0, 0)),
+    );
+    assert!(!release.upcoming_release);
+    assert!(release.author.is_none());
+    assert!(release.commit.is_none());
+    assert!(release.milestones.is_empty());
+    assert_eq!(release.assets.count, 2);
+    assert_eq!(release.assets.sources.len(), 1);
+    let source = &release.assets.sources[0];
+    assert_eq!(source.format, "zip");
+    assert_eq!(
+        source.url,
+        "https://gitlab.kitware.com/utils/rust-gitlab/-/archive/v1.0/rust-gitlab-v1.0.zip",
+    );
+    assert_eq!(release.assets.links.len(), 1);
+    let link = &release.assets.links[0];
+    assert_eq!(link.id, ReleaseLinkId::new(1));
+    assert_eq!(link.name, "linux-x86_64");
+    assert_eq!(
+        link.url,
+        "https://example.com/rust-gitlab-v1.0-linux-x86_64.tar.gz",
+    );
+    assert_eq!(
+        link.direct_asset_url.as_ref().unwrap(),
+        "https://gitlab.kitware.com/utils/rust-gitlab/-/releases/v1.0/downloads/linux-x86_64",
+    );
+    assert_eq!(link.link_type, ReleaseLinkType::Package);
+}
+
+#[test]
+fn test_read_runner_details() {
+    let runner: RunnerDetails = read_test_file("runner_details");
+
+    assert_eq!(runner.id, RunnerId::new(156));
+    assert_eq!(runner.description.as_ref().unwrap(), "minmus.priv-x11");
+    assert!(runner.active);
+    assert_eq!(runner.paused, Some(false));
+    assert!(!runner.is_shared);
+    assert_eq!(runner.runner_type, Some(RunnerType::Project));
+    assert_eq!(runner.name.as_ref().unwrap(), "gitlab-runner");
+    assert_eq!(runner.online, Some(true));
+    assert_eq!(runner.status, Some(RunnerStatus::Online));
+    assert_eq!(runner.tag_list, ["linux", "x11"]);
+    assert!(!runner.run_untagged);
+    assert!(runner.locked);
+    assert_eq!(runner.access_level, RunnerAccessLevel::RefProtected);
+    assert_eq!(runner.maximum_timeout, Some(3600));
+    assert_eq!(runner.maintenance_note, None);
+    assert_eq!(
+        runner.contacted_at.unwrap(),
+        datetime((2020, 1, 2), (0, 0, 0, 0)),
+    );
+    assert_eq!(runner.architecture.as_ref().unwrap(), "amd64");
+    assert_eq!(runner.platform.as_ref().unwrap(), "linux");
+    assert_eq!(runner.revision.as_ref().unwrap(), "f761588f");
+    assert_eq!(runner.version.as_ref().unwrap(), "13.0.0");
+    assert_eq!(runner.projects.len(), 1);
+    let project = &runner.projects[0];
+    assert_eq!(project.id, ProjectId::new(855));
+    assert_eq!(project.name, "rust-gitlab");
+    assert_eq!(project.name_with_namespace, "Utils / rust-gitlab");
+    assert_eq!(project.path, "rust-gitlab");
+    assert_eq!(project.path_with_namespace, "utils/rust-gitlab");
+    assert!(runner.groups.is_empty());
+}
+
 #[test]
 fn test_read_group() {
     let group: Group = read_test_file("group");
@@ -1109,8 +1516,12 @@ fn test_read_success_job() {
     assert_eq!(runner.id, RunnerId::new(156));
     assert_eq!(runner.description.unwrap(), "minmus.priv-x11");
     assert!(runner.active);
+    assert_eq!(runner.paused, None);
     assert!(runner.is_shared);
+    assert_eq!(runner.runner_type, None);
     assert_eq!(runner.name.unwrap(), "gitlab-runner");
+    assert_eq!(runner.online, Some(true));
+    assert_eq!(runner.status, Some(RunnerStatus::Online));
     assert_eq!(job.pipeline.id, PipelineId::new(168_478));
     assert_eq!(job.pipeline.ref_.unwrap(), "master");
     assert_eq!(
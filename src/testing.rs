@@ -0,0 +1,16 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![warn(missing_docs)]
+
+//! Test support
+//!
+//! This module contains clients and a fake server which are useful for testing code which uses this
+//! crate without access to a GitLab instance. It is only available with the `testing` feature.
+
+pub mod cassette;
+pub mod mock;
+pub mod server;
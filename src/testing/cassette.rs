@@ -0,0 +1,928 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Record and replay clients
+//!
+//! A [`Recorder`] wraps a real client and records every interaction with GitLab into a
+//! [`Cassette`]. The cassette may be saved to a file and later loaded into a [`Replayer`] which
+//! answers the same requests offline.
+//!
+//! Requests are matched on their method, path (relative to the API root), and query parameters
+//! (in any order). When multiple interactions match a request (e.g., polling the same endpoint),
+//! they are replayed in the order in which they were recorded. Paginated requests are matched like
+//! any other request, so the `Link` headers recorded from the server drive pagination during
+//! replay as well.
+//!
+//! Sensitive values are redacted before they are stored. Query parameters, form fields, and JSON
+//! object members with a redacted name have their values replaced by [`REDACTED`]. Since the same
+//! redaction is applied to incoming requests before matching, redacted query parameters still
+//! match during replay.
+
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::path::Path;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::{header, HeaderValue, Method, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use url::Url;
+
+use crate::api::{self, ApiError};
+
+/// The replacement for redacted values.
+pub const REDACTED: &str = "[REDACTED]";
+
+const REPLAY_API_ROOT: &str = "https://gitlab.replay.invalid/api/v4/";
+
+/// Errors which may occur when using cassettes.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum CassetteError {
+    /// Failed to read or write a cassette file.
+    #[error("cassette I/O error: {}", source)]
+    Io {
+        /// The source of the error.
+        #[from]
+        source: io::Error,
+    },
+    /// Failed to parse or serialize a cassette.
+    #[error("cassette format error: {}", source)]
+    Format {
+        /// The source of the error.
+        #[from]
+        source: serde_json::Error,
+    },
+    /// No recorded interaction matches a request.
+    #[error("no recorded interaction for {} {}", method, path)]
+    NoMatch {
+        /// The method of the request.
+        method: Method,
+        /// The path and query of the request.
+        path: String,
+    },
+    /// The recorded response could not be reconstructed.
+    #[error("invalid recorded response: {}", source)]
+    Http {
+        /// The source of the error.
+        #[from]
+        source: http::Error,
+    },
+    /// A recorded binary body could not be decoded.
+    #[error("invalid recorded body: {}", source)]
+    Body {
+        /// The source of the error.
+        #[from]
+        source: base64::DecodeError,
+    },
+}
+
+impl CassetteError {
+    fn no_match(method: Method, path: String) -> Self {
+        CassetteError::NoMatch {
+            method,
+            path,
+        }
+    }
+}
+
+/// The body of a recorded request or response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CassetteBody {
+    /// There was no body.
+    Empty,
+    /// A UTF-8 body.
+    Text(String),
+    /// A binary body, encoded using base64.
+    Base64(String),
+}
+
+impl CassetteBody {
+    fn new(data: &[u8]) -> Self {
+        if data.is_empty() {
+            CassetteBody::Empty
+        } else if let Ok(text) = std::str::from_utf8(data) {
+            CassetteBody::Text(text.into())
+        } else {
+            CassetteBody::Base64(base64::encode(data))
+        }
+    }
+
+    /// The raw data of the body.
+    pub fn data(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        Ok(match self {
+            CassetteBody::Empty => Vec::new(),
+            CassetteBody::Text(text) => text.as_bytes().into(),
+            CassetteBody::Base64(data) => base64::decode(data)?,
+        })
+    }
+}
+
+/// A recorded request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CassetteRequest {
+    /// The HTTP method of the request.
+    pub method: String,
+    /// The path of the request relative to the API root.
+    pub path: String,
+    /// The query parameters of the request.
+    #[serde(default)]
+    pub query: Vec<(String, String)>,
+    /// The `Content-Type` of the request body.
+    #[serde(default)]
+    pub content_type: Option<String>,
+    /// The body of the request.
+    pub body: CassetteBody,
+}
+
+impl CassetteRequest {
+    fn matches(&self, method: &Method, path: &str, query: &[(String, String)]) -> bool {
+        if self.method != method.as_str() || self.path != path {
+            return false;
+        }
+
+        let mut expected = self.query.clone();
+        let mut actual = query.to_vec();
+        expected.sort();
+        actual.sort();
+        expected == actual
+    }
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CassetteResponse {
+    /// The HTTP status of the response.
+    pub status: u16,
+    /// The headers of the response.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// The body of the response.
+    pub body: CassetteBody,
+}
+
+/// A single request and its response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request sent to GitLab.
+    pub request: CassetteRequest,
+    /// The response from GitLab.
+    pub response: CassetteResponse,
+}
+
+/// A set of recorded interactions with a GitLab instance.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// The path of the API root on the recorded instance.
+    #[serde(default)]
+    api_path: Option<String>,
+    /// The interactions, in the order in which they occurred.
+    interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Create a new, empty cassette.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a cassette from a file.
+    pub fn load<P>(path: P) -> Result<Self, CassetteError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path)?;
+        Ok(serde_json::from_reader(BufReader::new(file))?)
+    }
+
+    /// Save the cassette to a file.
+    pub fn save<P>(&self, path: P) -> Result<(), CassetteError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::create(path)?;
+        serde_json::to_writer_pretty(BufWriter::new(file), self)?;
+        Ok(())
+    }
+
+    /// The recorded interactions.
+    pub fn interactions(&self) -> &[Interaction] {
+        &self.interactions
+    }
+
+    /// Add an interaction to the cassette.
+    pub fn push(&mut self, interaction: Interaction) {
+        self.interactions.push(interaction);
+    }
+
+    fn api_path(&self) -> &str {
+        self.api_path.as_deref().unwrap_or("/api/v4/")
+    }
+}
+
+/// The set of names whose values are redacted.
+#[derive(Debug, Clone)]
+pub struct Redactions {
+    names: BTreeSet<String>,
+}
+
+impl Default for Redactions {
+    fn default() -> Self {
+        Self {
+            names: [
+                "access_token",
+                "private_token",
+                "job_token",
+                "token",
+                "runners_token",
+                "runners_registration_token",
+                "password",
+            ]
+            .iter()
+            .map(|&name| name.into())
+            .collect(),
+        }
+    }
+}
+
+impl Redactions {
+    /// Redact nothing.
+    pub fn none() -> Self {
+        Self {
+            names: BTreeSet::new(),
+        }
+    }
+
+    /// Also redact values with the given name.
+    pub fn redact<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.names.insert(name.into());
+        self
+    }
+
+    fn is_redacted(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    fn redact_pairs(&self, pairs: &mut [(String, String)]) {
+        pairs
+            .iter_mut()
+            .filter(|(key, _)| self.is_redacted(key))
+            .for_each(|(_, value)| *value = REDACTED.into());
+    }
+
+    fn redact_json(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if self.is_redacted(key) && !value.is_null() {
+                        *value = Value::String(REDACTED.into());
+                    } else {
+                        self.redact_json(value);
+                    }
+                }
+            },
+            Value::Array(items) => items.iter_mut().for_each(|item| self.redact_json(item)),
+            _ => (),
+        }
+    }
+
+    fn redact_body(&self, content_type: Option<&str>, data: &[u8]) -> CassetteBody {
+        let is_json = content_type.map_or(false, |ct| ct.starts_with("application/json"));
+        let is_form = content_type == Some("application/x-www-form-urlencoded");
+
+        if is_form {
+            if let Ok(mut pairs) = serde_urlencoded::from_bytes::<Vec<(String, String)>>(data) {
+                self.redact_pairs(&mut pairs);
+                if let Ok(encoded) = serde_urlencoded::to_string(pairs) {
+                    return CassetteBody::new(encoded.as_bytes());
+                }
+            }
+        } else if is_json || content_type.is_none() {
+            if let Ok(mut value) = serde_json::from_slice::<Value>(data) {
+                self.redact_json(&mut value);
+                if let Ok(encoded) = serde_json::to_vec(&value) {
+                    return CassetteBody::new(&encoded);
+                }
+            }
+        }
+
+        CassetteBody::new(data)
+    }
+}
+
+/// The parts of a request used to match interactions.
+struct RequestKey {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    content_type: Option<String>,
+}
+
+impl RequestKey {
+    fn new(request: &RequestBuilder, api_path: &str, redactions: &Redactions) -> Self {
+        let method = request.method_ref().cloned().unwrap_or(Method::GET);
+        let url = request
+            .uri_ref()
+            .and_then(|uri| Url::parse(&uri.to_string()).ok())
+            .expect("requests should always have an absolute URL");
+        let path = url
+            .path()
+            .strip_prefix(api_path)
+            .unwrap_or_else(|| url.path())
+            .into();
+        let mut query: Vec<_> = url
+            .query_pairs()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        redactions.redact_pairs(&mut query);
+        let content_type = request
+            .headers_ref()
+            .and_then(|headers| headers.get(header::CONTENT_TYPE))
+            .and_then(|value| value.to_str().ok())
+            .map(Into::into);
+
+        Self {
+            method,
+            path,
+            query,
+            content_type,
+        }
+    }
+
+    fn describe(&self) -> String {
+        if self.query.is_empty() {
+            self.path.clone()
+        } else {
+            let query = serde_urlencoded::to_string(&self.query).unwrap_or_default();
+            format!("{}?{}", self.path, query)
+        }
+    }
+}
+
+/// A client wrapper which records all interactions into a cassette.
+pub struct Recorder<C> {
+    client: C,
+    redactions: Redactions,
+    cassette: Mutex<Cassette>,
+}
+
+impl<C> Recorder<C> {
+    /// Record interactions with a client using the default redactions.
+    pub fn new(client: C) -> Self {
+        Self::with_redactions(client, Redactions::default())
+    }
+
+    /// Record interactions with a client using the given redactions.
+    pub fn with_redactions(client: C, redactions: Redactions) -> Self {
+        Self {
+            client,
+            redactions,
+            cassette: Mutex::new(Cassette::new()),
+        }
+    }
+
+    /// The cassette recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        self.cassette.lock().expect("poisoned lock").clone()
+    }
+
+    /// Save the cassette recorded so far to a file.
+    pub fn save<P>(&self, path: P) -> Result<(), CassetteError>
+    where
+        P: AsRef<Path>,
+    {
+        self.cassette.lock().expect("poisoned lock").save(path)
+    }
+
+    fn record<E>(&self, key: RequestKey, body: &[u8], rsp: &Result<Response<Bytes>, ApiError<E>>)
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let rsp = if let Ok(rsp) = rsp {
+            rsp
+        } else {
+            // Client errors never reached GitLab; there is nothing to replay.
+            return;
+        };
+
+        let request = CassetteRequest {
+            method: key.method.as_str().into(),
+            path: key.path,
+            query: key.query,
+            body: self
+                .redactions
+                .redact_body(key.content_type.as_deref(), body),
+            content_type: key.content_type,
+        };
+        let response_content_type = rsp
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok());
+        let response = CassetteResponse {
+            status: rsp.status().as_u16(),
+            headers: rsp
+                .headers()
+                .iter()
+                .filter(|(name, _)| *name != header::SET_COOKIE)
+                .filter_map(|(name, value)| {
+                    value
+                        .to_str()
+                        .ok()
+                        .map(|value| (name.as_str().into(), value.into()))
+                })
+                .collect(),
+            body: self
+                .redactions
+                .redact_body(response_content_type, rsp.body()),
+        };
+
+        self.cassette
+            .lock()
+            .expect("poisoned lock")
+            .push(Interaction {
+                request,
+                response,
+            });
+    }
+}
+
+impl<C> Recorder<C>
+where
+    C: api::RestClient,
+{
+    fn request_key(&self, request: &RequestBuilder) -> RequestKey {
+        let api_path = {
+            let mut cassette = self.cassette.lock().expect("poisoned lock");
+            if cassette.api_path.is_none() {
+                cassette.api_path = self
+                    .client
+                    .rest_endpoint("")
+                    .ok()
+                    .map(|url| url.path().into());
+            }
+            cassette.api_path().to_string()
+        };
+        RequestKey::new(request, &api_path, &self.redactions)
+    }
+}
+
+impl<C> api::RestClient for Recorder<C>
+where
+    C: api::RestClient,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.rest_endpoint(endpoint)
+    }
+}
+
+impl<C> api::Client for Recorder<C>
+where
+    C: api::Client,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let key = self.request_key(&request);
+        let rsp = self.client.rest(request, body.clone());
+        self.record(key, &body, &rsp);
+        rsp
+    }
+}
+
+#[async_trait]
+impl<C> api::AsyncClient for Recorder<C>
+where
+    C: api::AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let key = self.request_key(&request);
+        let rsp = self.client.rest_async(request, body.clone()).await;
+        self.record(key, &body, &rsp);
+        rsp
+    }
+}
+
+/// A client which answers requests from a cassette.
+pub struct Replayer {
+    cassette: Cassette,
+    redactions: Redactions,
+    rest_url: Url,
+    used: Mutex<Vec<bool>>,
+}
+
+impl Replayer {
+    /// Replay a cassette using the default redactions.
+    pub fn new(cassette: Cassette) -> Self {
+        Self::with_redactions(cassette, Redactions::default())
+    }
+
+    /// Replay a cassette using the given redactions.
+    ///
+    /// These should be the same redactions used when recording.
+    pub fn with_redactions(cassette: Cassette, redactions: Redactions) -> Self {
+        let used = vec![false; cassette.interactions.len()];
+        let rest_url = Url::parse(REPLAY_API_ROOT)
+            .and_then(|url| url.join(cassette.api_path()))
+            .expect("the replay API root should be valid");
+
+        Self {
+            cassette,
+            redactions,
+            rest_url,
+            used: Mutex::new(used),
+        }
+    }
+
+    /// Load a cassette from a file and replay it.
+    pub fn load<P>(path: P) -> Result<Self, CassetteError>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(Self::new(Cassette::load(path)?))
+    }
+
+    /// The number of interactions which have not been replayed yet.
+    pub fn remaining(&self) -> usize {
+        self.used
+            .lock()
+            .expect("poisoned lock")
+            .iter()
+            .filter(|&&used| !used)
+            .count()
+    }
+
+    fn replay(&self, request: &RequestBuilder) -> Result<Response<Bytes>, CassetteError> {
+        let key = RequestKey::new(request, self.cassette.api_path(), &self.redactions);
+
+        let interaction = {
+            let mut used = self.used.lock().expect("poisoned lock");
+            let found = self.cassette.interactions.iter().zip(used.iter_mut()).find(
+                |(interaction, used)| {
+                    !**used
+                        && interaction
+                            .request
+                            .matches(&key.method, &key.path, &key.query)
+                },
+            );
+
+            if let Some((interaction, used)) = found {
+                *used = true;
+                interaction
+            } else {
+                return Err(CassetteError::no_match(key.method.clone(), key.describe()));
+            }
+        };
+
+        let mut rsp = Response::builder().status(
+            StatusCode::from_u16(interaction.response.status)
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+        );
+        for (name, value) in &interaction.response.headers {
+            if let Ok(value) = HeaderValue::from_str(value) {
+                rsp = rsp.header(name.as_str(), value);
+            }
+        }
+
+        Ok(rsp.body(interaction.response.body.data()?.into())?)
+    }
+}
+
+impl api::RestClient for Replayer {
+    type Error = CassetteError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.rest_url.join(endpoint)?)
+    }
+}
+
+impl api::Client for Replayer {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        _: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.replay(&request).map_err(ApiError::client)
+    }
+}
+
+#[async_trait]
+impl api::AsyncClient for Replayer {
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        _: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.replay(&request).map_err(ApiError::client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use http::Method;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, Pagination, Query};
+    use crate::test::client::{ExpectedUrl, PagedTestClient, SingleTestClient};
+    use crate::testing::cassette::{
+        Cassette, CassetteBody, CassetteError, Recorder, Redactions, Replayer, REDACTED,
+    };
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+
+        fn parameters(&self) -> QueryParams {
+            let mut params = QueryParams::default();
+            params.push("b", "2").push("a", "1");
+            params
+        }
+    }
+
+    struct DummyToken;
+
+    impl Endpoint for DummyToken {
+        fn method(&self) -> Method {
+            Method::POST
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+
+        fn parameters(&self) -> QueryParams {
+            let mut params = QueryParams::default();
+            params.push("private_token", "secret");
+            params
+        }
+
+        fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+            let mut params = FormParams::default();
+            params.push("token", "secret").push("ref", "main");
+            params.into_body()
+        }
+    }
+
+    impl Pageable for Dummy {
+        fn use_keyset_pagination(&self) -> bool {
+            true
+        }
+    }
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("b", "2"), ("a", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 3,
+            }),
+        );
+        let recorder = Recorder::new(client);
+
+        let res: DummyResult = Dummy.query(&recorder).unwrap();
+        assert_eq!(res.value, 3);
+
+        let cassette = recorder.cassette();
+        assert_eq!(cassette.interactions().len(), 1);
+        let interaction = &cassette.interactions()[0];
+        assert_eq!(interaction.request.method, "GET");
+        assert_eq!(interaction.request.path, "dummy");
+        assert_eq!(interaction.response.status, 200);
+
+        let replayer = Replayer::new(cassette);
+        assert_eq!(replayer.remaining(), 1);
+        let res: DummyResult = Dummy.query(&replayer).unwrap();
+        assert_eq!(res.value, 3);
+        assert_eq!(replayer.remaining(), 0);
+
+        // The interaction has been used up.
+        let err = api::ignore(Dummy).query(&replayer).unwrap_err();
+        if let ApiError::Client {
+            source:
+                CassetteError::NoMatch {
+                    method,
+                    path,
+                },
+        } = err
+        {
+            assert_eq!(method, Method::GET);
+            assert_eq!(path, "dummy?b=2&a=1");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn replay_paginated() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("b", "2"), ("a", "1")])
+            .paginated(true)
+            .build()
+            .unwrap();
+        let data: Vec<_> = (0..=255)
+            .map(|value| {
+                DummyResult {
+                    value,
+                }
+            })
+            .collect();
+        let client = PagedTestClient::new_raw(endpoint, data);
+        let recorder = Recorder::new(client);
+
+        let query = api::paged(Dummy, Pagination::All);
+        let res: Vec<DummyResult> = query.query(&recorder).unwrap();
+        assert_eq!(res.len(), 256);
+        assert_eq!(recorder.cassette().interactions().len(), 3);
+
+        let replayer = Replayer::new(recorder.cassette());
+        let replayed: Vec<DummyResult> = query.query(&replayer).unwrap();
+        assert_eq!(replayed, res);
+    }
+
+    #[tokio::test]
+    async fn replay_async() {
+        let mut cassette = Cassette::new();
+        cassette.push(super::Interaction {
+            request: super::CassetteRequest {
+                method: "GET".into(),
+                path: "dummy".into(),
+                query: vec![("a".into(), "1".into()), ("b".into(), "2".into())],
+                content_type: None,
+                body: CassetteBody::Empty,
+            },
+            response: super::CassetteResponse {
+                status: 200,
+                headers: Vec::new(),
+                body: CassetteBody::Text("{\"value\":7}".into()),
+            },
+        });
+
+        let replayer = Replayer::new(cassette);
+        let res: DummyResult = Dummy.query_async(&replayer).await.unwrap();
+        assert_eq!(res.value, 7);
+    }
+
+    #[test]
+    fn redaction() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("dummy")
+            .add_query_params(&[("private_token", "secret")])
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("token=secret&ref=main")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "id": 1,
+                "token": "secret",
+                "nested": [{"runners_token": "secret"}],
+            }),
+        );
+        let recorder = Recorder::new(client);
+        api::ignore(DummyToken).query(&recorder).unwrap();
+
+        let cassette = recorder.cassette();
+        let serialized = serde_json::to_string(&cassette).unwrap();
+        assert!(!serialized.contains("secret"));
+
+        let interaction = &cassette.interactions()[0];
+        assert_eq!(
+            interaction.request.query,
+            [("private_token".into(), REDACTED.into())],
+        );
+        assert_eq!(
+            interaction.request.body,
+            CassetteBody::Text("token=%5BREDACTED%5D&ref=main".into()),
+        );
+
+        // Redacted requests still match during replay.
+        let replayer = Replayer::new(cassette);
+        let res: serde_json::Value = DummyToken.query(&replayer).unwrap();
+        assert_eq!(res["id"], 1);
+        assert_eq!(res["token"], REDACTED);
+        assert_eq!(res["nested"][0]["runners_token"], REDACTED);
+    }
+
+    #[test]
+    fn redaction_none() {
+        let redactions = Redactions::none().redact("value");
+        let mut value = json!({
+            "token": "kept",
+            "value": 1,
+        });
+        redactions.redact_json(&mut value);
+        assert_eq!(value["token"], "kept");
+        assert_eq!(value["value"], REDACTED);
+    }
+
+    #[test]
+    fn binary_body() {
+        let body = CassetteBody::new(&[0xff, 0x00, 0x80]);
+        if let CassetteBody::Base64(_) = body {
+        } else {
+            panic!("unexpected body: {:?}", body);
+        }
+        assert_eq!(body.data().unwrap(), [0xff, 0x00, 0x80]);
+        assert_eq!(CassetteBody::new(b""), CassetteBody::Empty);
+    }
+
+    #[test]
+    fn replay_corrupt_body() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("b", "2"), ("a", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+        let recorder = Recorder::new(client);
+        api::ignore(Dummy).query(&recorder).unwrap();
+
+        let mut cassette = serde_json::to_value(recorder.cassette()).unwrap();
+        cassette["interactions"][0]["response"]["body"] = json!({
+            "base64": "not base64!",
+        });
+        let cassette: Cassette = serde_json::from_value(cassette).unwrap();
+
+        let replayer = Replayer::new(cassette);
+        let err = api::ignore(Dummy).query(&replayer).unwrap_err();
+        if let ApiError::Client {
+            source: CassetteError::Body {
+                ..
+            },
+        } = err
+        {
+            // expected
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn save_and_load() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("b", "2"), ("a", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 3,
+            }),
+        );
+        let recorder = Recorder::new(client);
+        api::ignore(Dummy).query(&recorder).unwrap();
+
+        let path = env::temp_dir().join(format!("gitlab-cassette-{}.json", std::process::id()));
+        recorder.save(&path).unwrap();
+        let replayer = Replayer::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        let res: DummyResult = Dummy.query(&replayer.unwrap()).unwrap();
+        assert_eq!(res.value, 3);
+    }
+}
@@ -0,0 +1,784 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Mock client
+//!
+//! The [`MockClient`] answers requests from a set of [`Route`]s. Each route matches a method, a
+//! path relative to the API root, and (optionally) a set of query parameters, request headers, and
+//! a request body.
+//! Routes may return a sequence of responses or serve a list of items using GitLab's pagination
+//! conventions, including `Link` headers for keyset pagination.
+//!
+//! # Example
+//!
+//! ```rust
+//! use gitlab::api::{self, projects, Query};
+//! use gitlab::testing::mock::{MockClient, MockResponse, Route};
+//! use http::Method;
+//! use serde_json::json;
+//!
+//! let client = MockClient::new();
+//! let route = client.add(
+//!     Route::new(Method::GET, "projects/1")
+//!         .respond(MockResponse::json(&json!({"name": "mock"}))),
+//! );
+//!
+//! let endpoint = projects::Project::builder().project(1).build().unwrap();
+//! let project: serde_json::Value = endpoint.query(&client).unwrap();
+//! assert_eq!(project["name"], "mock");
+//! assert_eq!(client.calls(route), 1);
+//! ```
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::{header, HeaderMap, HeaderValue, Method, Response, StatusCode};
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+use url::Url;
+
+use crate::api::{self, ApiError};
+
+const MOCK_API_ROOT: &str = "https://gitlab.mock.invalid/api/v4/";
+const MOCK_API_PATH: &str = "/api/v4/";
+const KEYSET_CURSOR: &str = "__mock_cursor";
+const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// Errors which may occur when using the mock client.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum MockError {
+    /// No route matches a request.
+    #[error("no route for {} {}", method, path)]
+    NoRoute {
+        /// The method of the request.
+        method: Method,
+        /// The path and query of the request.
+        path: String,
+    },
+    /// The request body does not match the expected body.
+    #[error(
+        "body mismatch for {} {}: expected `{}`, got `{}`",
+        method,
+        path,
+        String::from_utf8_lossy(expected),
+        String::from_utf8_lossy(actual)
+    )]
+    BodyMismatch {
+        /// The method of the request.
+        method: Method,
+        /// The path of the request.
+        path: String,
+        /// The expected request body.
+        expected: Vec<u8>,
+        /// The actual request body.
+        actual: Vec<u8>,
+    },
+    /// The response could not be constructed.
+    #[error("invalid mock response: {}", source)]
+    Http {
+        /// The source of the error.
+        #[from]
+        source: http::Error,
+    },
+}
+
+/// A response from a mock route.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: StatusCode,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl MockResponse {
+    /// A successful response with the given data.
+    pub fn raw<B>(body: B) -> Self
+    where
+        B: Into<Vec<u8>>,
+    {
+        Self {
+            status: StatusCode::OK,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// A successful response with the given data serialized as JSON.
+    pub fn json<T>(data: &T) -> Self
+    where
+        T: Serialize + ?Sized,
+    {
+        Self::raw(serde_json::to_vec(data).expect("mock response data should serialize"))
+            .header(header::CONTENT_TYPE.as_str(), "application/json")
+    }
+
+    /// An error response with a GitLab error message.
+    pub fn error<M>(status: StatusCode, message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Self::json(&serde_json::json!({
+            "message": message.into(),
+        }))
+        .status(status)
+    }
+
+    /// Set the status of the response.
+    pub fn status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Add a header to the response.
+    pub fn header<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    fn build(&self, extra_headers: Vec<(String, String)>) -> Result<Response<Bytes>, MockError> {
+        let mut rsp = Response::builder().status(self.status);
+        for (name, value) in self.headers.iter().chain(extra_headers.iter()) {
+            let value = HeaderValue::from_str(value).map_err(http::Error::from)?;
+            rsp = rsp.header(name.as_str(), value);
+        }
+        Ok(rsp.body(self.body.clone().into())?)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Responses {
+    Sequence(Vec<MockResponse>),
+    Paged(Vec<Value>),
+}
+
+/// A route for the mock client.
+#[derive(Debug, Clone)]
+pub struct Route {
+    method: Method,
+    path: String,
+    query: Option<Vec<(String, String)>>,
+    headers: Vec<(String, String)>,
+    body: Option<Vec<u8>>,
+    responses: Responses,
+}
+
+impl Route {
+    /// A route for the given method and path.
+    ///
+    /// The path is relative to the API root (e.g., `projects/1`). Path components must be
+    /// escaped the same way as endpoints escape them (e.g., `projects/group%2Fproject`).
+    ///
+    /// Without any responses, the route returns an empty JSON object.
+    pub fn new<P>(method: Method, path: P) -> Self
+    where
+        P: Into<String>,
+    {
+        Self {
+            method,
+            path: path.into(),
+            query: None,
+            headers: Vec::new(),
+            body: None,
+            responses: Responses::Sequence(Vec::new()),
+        }
+    }
+
+    /// A route which serves the given items as a paginated list.
+    ///
+    /// Both page number and keyset pagination are supported. Pagination query parameters are
+    /// ignored when matching the query of the request.
+    pub fn paged<P, I, T>(method: Method, path: P, items: I) -> Self
+    where
+        P: Into<String>,
+        I: IntoIterator<Item = T>,
+        T: Serialize,
+    {
+        let items = items
+            .into_iter()
+            .map(|item| serde_json::to_value(item).expect("mock page items should serialize"))
+            .collect();
+
+        Self {
+            responses: Responses::Paged(items),
+            ..Self::new(method, path)
+        }
+    }
+
+    /// Only match requests with exactly these query parameters (in any order).
+    pub fn query<K, V>(mut self, pairs: &[(K, V)]) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        self.query = Some(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.as_ref().into(), v.as_ref().into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Only match requests which have a header with the given value.
+    ///
+    /// Header names are case-insensitive.
+    pub fn header<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Require the request body to be exactly the given data.
+    pub fn body<B>(mut self, body: B) -> Self
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Require the request body to be the given form parameters (in order).
+    pub fn form<K, V>(self, pairs: &[(K, V)]) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let pairs: Vec<_> = pairs
+            .iter()
+            .map(|(k, v)| (k.as_ref(), v.as_ref()))
+            .collect();
+        let body = serde_urlencoded::to_string(pairs).expect("form parameters should encode");
+        self.body(body)
+    }
+
+    /// Add a response to the route.
+    ///
+    /// Responses are returned in order. Once the last response is reached, it is returned for
+    /// all subsequent requests.
+    pub fn respond(mut self, response: MockResponse) -> Self {
+        if let Responses::Sequence(responses) = &mut self.responses {
+            responses.push(response);
+        } else {
+            self.responses = Responses::Sequence(vec![response]);
+        }
+        self
+    }
+
+    fn is_pagination_key(&self, key: &str) -> bool {
+        matches!(self.responses, Responses::Paged(_))
+            && (key == "page" || key == "per_page" || key == "pagination" || key == KEYSET_CURSOR)
+    }
+
+    fn matches(
+        &self,
+        method: &Method,
+        path: &str,
+        query: &[(String, String)],
+        headers: Option<&HeaderMap>,
+    ) -> bool {
+        if self.method != method || self.path != path {
+            return false;
+        }
+
+        let has_headers = self.headers.iter().all(|(name, value)| {
+            headers
+                .into_iter()
+                .flat_map(|headers| headers.get_all(name.as_str()))
+                .any(|actual| actual == value.as_str())
+        });
+        if !has_headers {
+            return false;
+        }
+
+        if let Some(expected) = self.query.as_ref() {
+            let mut expected = expected.clone();
+            let mut actual: Vec<_> = query
+                .iter()
+                .filter(|(key, _)| !self.is_pagination_key(key))
+                .cloned()
+                .collect();
+            expected.sort();
+            actual.sort();
+            expected == actual
+        } else {
+            true
+        }
+    }
+
+    fn respond_to(&self, call: usize, url: &Url) -> Result<Response<Bytes>, MockError> {
+        match &self.responses {
+            Responses::Sequence(responses) => {
+                if let Some(rsp) = responses.get(call).or_else(|| responses.last()) {
+                    rsp.build(Vec::new())
+                } else {
+                    MockResponse::json(&serde_json::json!({})).build(Vec::new())
+                }
+            },
+            Responses::Paged(items) => Self::page(items, url),
+        }
+    }
+
+    fn page(items: &[Value], url: &Url) -> Result<Response<Bytes>, MockError> {
+        let mut keyset = false;
+        let mut cursor = 0;
+        let mut page = 1;
+        let mut per_page = DEFAULT_PAGE_SIZE;
+
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "pagination" => keyset = value == "keyset",
+                KEYSET_CURSOR => cursor = value.parse().unwrap_or(0),
+                "page" => page = value.parse().unwrap_or(1).max(1),
+                "per_page" => per_page = value.parse().unwrap_or(DEFAULT_PAGE_SIZE),
+                _ => (),
+            }
+        }
+
+        let start = if keyset {
+            cursor
+        } else {
+            (page - 1) * per_page
+        }
+        .min(items.len());
+        let end = (start + per_page).min(items.len());
+
+        let mut headers = Vec::new();
+        if end < items.len() {
+            let mut next_url = url.clone();
+            next_url.query_pairs_mut().clear().extend_pairs(
+                url.query_pairs()
+                    .filter(|(key, _)| key != KEYSET_CURSOR && key != "page"),
+            );
+            if keyset {
+                next_url
+                    .query_pairs_mut()
+                    .append_pair(KEYSET_CURSOR, &end.to_string());
+            } else {
+                next_url
+                    .query_pairs_mut()
+                    .append_pair("page", &(page + 1).to_string());
+            }
+            headers.push((
+                header::LINK.as_str().into(),
+                format!("<{}>; rel=\"next\"", next_url),
+            ));
+        }
+        if !keyset {
+            headers.push(("x-total".into(), items.len().to_string()));
+            headers.push(("x-page".into(), page.to_string()));
+            headers.push(("x-per-page".into(), per_page.to_string()));
+        }
+
+        MockResponse::json(&items[start..end]).build(headers)
+    }
+}
+
+/// An identifier for a route added to a mock client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RouteId(usize);
+
+struct RouteState {
+    route: Route,
+    calls: usize,
+}
+
+/// A client which answers requests from a set of routes.
+pub struct MockClient {
+    rest_url: Url,
+    routes: Mutex<Vec<RouteState>>,
+}
+
+impl Default for MockClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockClient {
+    /// Create a new mock client without any routes.
+    pub fn new() -> Self {
+        Self {
+            rest_url: Url::parse(MOCK_API_ROOT).expect("the mock API root should be valid"),
+            routes: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Add a route to the client.
+    ///
+    /// When multiple routes match a request, the first one added is used.
+    pub fn add(&self, route: Route) -> RouteId {
+        let mut routes = self.routes.lock().expect("poisoned lock");
+        routes.push(RouteState {
+            route,
+            calls: 0,
+        });
+        RouteId(routes.len() - 1)
+    }
+
+    /// The number of times a route has been requested.
+    pub fn calls(&self, route: RouteId) -> usize {
+        self.routes.lock().expect("poisoned lock")[route.0].calls
+    }
+
+    /// The number of requests which have been handled by any route.
+    pub fn total_calls(&self) -> usize {
+        self.routes
+            .lock()
+            .expect("poisoned lock")
+            .iter()
+            .map(|state| state.calls)
+            .sum()
+    }
+
+    /// The routes which have not been requested.
+    pub fn uncalled(&self) -> Vec<RouteId> {
+        self.routes
+            .lock()
+            .expect("poisoned lock")
+            .iter()
+            .enumerate()
+            .filter(|(_, state)| state.calls == 0)
+            .map(|(idx, _)| RouteId(idx))
+            .collect()
+    }
+
+    fn handle(&self, request: &RequestBuilder, body: &[u8]) -> Result<Response<Bytes>, MockError> {
+        let method = request.method_ref().cloned().unwrap_or(Method::GET);
+        let url = request
+            .uri_ref()
+            .and_then(|uri| Url::parse(&uri.to_string()).ok())
+            .expect("requests should always have an absolute URL");
+        let path = url
+            .path()
+            .strip_prefix(MOCK_API_PATH)
+            .unwrap_or_else(|| url.path())
+            .to_string();
+        let query: Vec<_> = url
+            .query_pairs()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+
+        let mut routes = self.routes.lock().expect("poisoned lock");
+        let state = routes.iter_mut().find(|state| {
+            state
+                .route
+                .matches(&method, &path, &query, request.headers_ref())
+        });
+        let state = if let Some(state) = state {
+            state
+        } else {
+            let path = if let Some(query) = url.query() {
+                format!("{}?{}", path, query)
+            } else {
+                path
+            };
+            return Err(MockError::NoRoute {
+                method,
+                path,
+            });
+        };
+
+        if let Some(expected) = state.route.body.as_ref() {
+            if expected != body {
+                return Err(MockError::BodyMismatch {
+                    method,
+                    path,
+                    expected: expected.clone(),
+                    actual: body.into(),
+                });
+            }
+        }
+
+        let call = state.calls;
+        state.calls += 1;
+        state.route.respond_to(call, &url)
+    }
+}
+
+impl api::RestClient for MockClient {
+    type Error = MockError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.rest_url.join(endpoint)?)
+    }
+}
+
+impl api::Client for MockClient {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.handle(&request, &body).map_err(ApiError::client)
+    }
+}
+
+#[async_trait]
+impl api::AsyncClient for MockClient {
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.handle(&request, &body).map_err(ApiError::client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{Method, StatusCode};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, Pagination, Query};
+    use crate::testing::mock::{MockClient, MockError, MockResponse, Route};
+
+    #[derive(Debug, Default)]
+    struct Dummy {
+        keyset: bool,
+    }
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+
+        fn parameters(&self) -> QueryParams {
+            let mut params = QueryParams::default();
+            params.push("scope", "all");
+            params
+        }
+    }
+
+    impl Pageable for Dummy {
+        fn use_keyset_pagination(&self) -> bool {
+            self.keyset
+        }
+    }
+
+    struct DummyCreate;
+
+    impl Endpoint for DummyCreate {
+        fn method(&self) -> Method {
+            Method::POST
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "group%2Fproject/items".into()
+        }
+
+        fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+            let mut params = FormParams::default();
+            params.push("name", "item").push("flag", true);
+            params.into_body()
+        }
+    }
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    #[test]
+    fn multiple_routes() {
+        let client = MockClient::new();
+        let get = client.add(
+            Route::new(Method::GET, "dummy")
+                .query(&[("scope", "all")])
+                .respond(MockResponse::json(&json!({"value": 1})))
+                .respond(MockResponse::json(&json!({"value": 2}))),
+        );
+        let create = client.add(
+            Route::new(Method::POST, "group%2Fproject/items")
+                .form(&[("name", "item"), ("flag", "true")])
+                .respond(MockResponse::json(&json!({"value": 3}))),
+        );
+        let unused = client.add(Route::new(Method::DELETE, "dummy"));
+
+        let res: DummyResult = Dummy::default().query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        let res: DummyResult = Dummy::default().query(&client).unwrap();
+        assert_eq!(res.value, 2);
+        // The last response repeats.
+        let res: DummyResult = Dummy::default().query(&client).unwrap();
+        assert_eq!(res.value, 2);
+        let res: DummyResult = DummyCreate.query(&client).unwrap();
+        assert_eq!(res.value, 3);
+
+        assert_eq!(client.calls(get), 3);
+        assert_eq!(client.calls(create), 1);
+        assert_eq!(client.calls(unused), 0);
+        assert_eq!(client.total_calls(), 4);
+        assert_eq!(client.uncalled(), [unused]);
+    }
+
+    #[test]
+    fn no_route() {
+        let client = MockClient::new();
+        client.add(Route::new(Method::GET, "dummy").query(&[("scope", "owned")]));
+
+        let err = api::ignore(Dummy::default()).query(&client).unwrap_err();
+        if let ApiError::Client {
+            source: MockError::NoRoute {
+                method,
+                path,
+            },
+        } = err
+        {
+            assert_eq!(method, Method::GET);
+            assert_eq!(path, "dummy?scope=all");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn body_mismatch() {
+        let client = MockClient::new();
+        let route = client
+            .add(Route::new(Method::POST, "group%2Fproject/items").form(&[("name", "other")]));
+
+        let err = api::ignore(DummyCreate).query(&client).unwrap_err();
+        if let ApiError::Client {
+            source:
+                MockError::BodyMismatch {
+                    expected,
+                    actual,
+                    ..
+                },
+        } = err
+        {
+            assert_eq!(expected, b"name=other");
+            assert_eq!(actual, b"name=item&flag=true");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        assert_eq!(client.calls(route), 0);
+    }
+
+    #[test]
+    fn header_matching() {
+        let client = MockClient::new();
+        let ranged = client.add(
+            Route::new(Method::GET, "dummy")
+                .header("range", "bytes=4-")
+                .respond(MockResponse::raw("rest").status(StatusCode::PARTIAL_CONTENT)),
+        );
+        let full = client.add(Route::new(Method::GET, "dummy").respond(MockResponse::raw("full")));
+
+        let req = http::Request::builder()
+            .method(Method::GET)
+            .uri("https://gitlab.mock.invalid/api/v4/dummy")
+            .header("Range", "bytes=4-");
+        let rsp = api::Client::rest(&client, req, Vec::new()).unwrap();
+        assert_eq!(rsp.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(rsp.body().as_ref(), b"rest");
+
+        let req = http::Request::builder()
+            .method(Method::GET)
+            .uri("https://gitlab.mock.invalid/api/v4/dummy");
+        let rsp = api::Client::rest(&client, req, Vec::new()).unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+        assert_eq!(rsp.body().as_ref(), b"full");
+
+        assert_eq!(client.calls(ranged), 1);
+        assert_eq!(client.calls(full), 1);
+    }
+
+    #[test]
+    fn error_response() {
+        let client = MockClient::new();
+        client.add(
+            Route::new(Method::GET, "dummy")
+                .respond(MockResponse::error(StatusCode::NOT_FOUND, "404 Not Found")),
+        );
+
+        let err = api::ignore(Dummy::default()).query(&client).unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("404 Not Found"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    fn paged_items() -> Vec<DummyResult> {
+        (0..=250)
+            .map(|value| {
+                DummyResult {
+                    value,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn paged_by_number() {
+        let client = MockClient::new();
+        let route = client
+            .add(Route::paged(Method::GET, "dummy", paged_items()).query(&[("scope", "all")]));
+
+        let res: Vec<DummyResult> = api::paged(Dummy::default(), Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(res, paged_items());
+        assert_eq!(client.calls(route), 3);
+
+        let res: Vec<DummyResult> = Dummy::default().query(&client).unwrap();
+        assert_eq!(res.len(), 20);
+    }
+
+    #[test]
+    fn paged_by_keyset() {
+        let client = MockClient::new();
+        let route = client.add(Route::paged(Method::GET, "dummy", paged_items()));
+        let endpoint = Dummy {
+            keyset: true,
+        };
+
+        let res: Vec<DummyResult> = api::paged(endpoint, Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(res, paged_items());
+        assert_eq!(client.calls(route), 3);
+    }
+
+    #[tokio::test]
+    async fn async_client() {
+        let client = MockClient::new();
+        client.add(Route::paged(Method::GET, "dummy", paged_items()));
+        client.add(
+            Route::new(Method::POST, "group%2Fproject/items")
+                .respond(MockResponse::json(&json!({"value": 3}))),
+        );
+
+        let res: Vec<DummyResult> = api::paged(Dummy::default(), Pagination::All)
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 251);
+        let res: DummyResult = DummyCreate.query_async(&client).await.unwrap();
+        assert_eq!(res.value, 3);
+    }
+}
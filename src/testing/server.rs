@@ -0,0 +1,2373 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A local fake GitLab server
+//!
+//! The [`FakeGitlab`] server listens on a local port and emulates a small, stateful subset of the
+//! GitLab REST API. It is intended for end-to-end tests of bots and other tools which need to use
+//! a real [`Gitlab`](crate::Gitlab) client without network access to a GitLab instance.
+//!
+//! The supported API is:
+//!
+//!   - the current user (`GET /user`) and users by ID;
+//!   - projects (list, get, and create);
+//!   - issues (list, get, create, and edit);
+//!   - merge requests (list, get, create, edit, and merge);
+//!   - notes on issues and merge requests (list and create);
+//!   - pipelines (list, get, and create); and
+//!   - project hooks (list, get, create, and delete).
+//!
+//! Changes made through the API are delivered to matching project hooks as webhooks in the
+//! background. Every delivery is also recorded so that tests may inspect them without running a
+//! receiver.
+//!
+//! Requests must be authenticated with the token of a user added with [`FakeGitlab::add_user`].
+//! Beyond that, no permissions are enforced.
+//!
+//! ```rust,no_run
+//! # use gitlab::api::{self, Query};
+//! # use gitlab::testing::server::FakeGitlab;
+//! # use gitlab::Gitlab;
+//! let server = FakeGitlab::start().unwrap();
+//! let user = server.add_user("bot", "bot-token");
+//! server.add_project(user, "group/project");
+//!
+//! let client = Gitlab::builder(server.host(), "bot-token")
+//!     .insecure()
+//!     .build()
+//!     .unwrap();
+//! let endpoint = api::projects::issues::CreateIssue::builder()
+//!     .project("group/project")
+//!     .title("an issue")
+//!     .build()
+//!     .unwrap();
+//! let issue: gitlab::types::Issue = endpoint.query(&client).unwrap();
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::error::Error;
+use std::hash::{Hash, Hasher};
+use std::net::SocketAddr;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use percent_encoding::percent_decode_str;
+use serde_json::{json, Value};
+use thiserror::Error;
+use url::Url;
+
+use crate::types::{PipelineId, ProjectId, StatusState, UserId};
+
+/// Errors which may occur when starting a fake GitLab server.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ServerError {
+    /// The server could not listen on a local port.
+    #[error("failed to bind the server: {}", source)]
+    Bind {
+        /// The source of the error.
+        source: Box<dyn Error + Send + Sync + 'static>,
+    },
+}
+
+/// A webhook sent by the server.
+#[derive(Debug, Clone)]
+pub struct HookDelivery {
+    /// The URL of the hook.
+    pub url: String,
+    /// The event name (the value of the `X-Gitlab-Event` header).
+    pub event: String,
+    /// The secret token for the hook (the value of the `X-Gitlab-Token` header).
+    pub token: Option<String>,
+    /// The payload of the webhook.
+    pub payload: Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HookKind {
+    Issue,
+    MergeRequest,
+    Note,
+    Pipeline,
+}
+
+impl HookKind {
+    fn event(self) -> &'static str {
+        match self {
+            HookKind::Issue => "Issue Hook",
+            HookKind::MergeRequest => "Merge Request Hook",
+            HookKind::Note => "Note Hook",
+            HookKind::Pipeline => "Pipeline Hook",
+        }
+    }
+}
+
+struct UserRecord {
+    id: u64,
+    username: String,
+    token: String,
+    created_at: DateTime<Utc>,
+}
+
+struct ProjectRecord {
+    id: u64,
+    namespace: String,
+    namespace_id: u64,
+    namespace_is_user: bool,
+    path: String,
+    description: Option<String>,
+    default_branch: String,
+    creator: u64,
+    created_at: DateTime<Utc>,
+}
+
+impl ProjectRecord {
+    fn path_with_namespace(&self) -> String {
+        format!("{}/{}", self.namespace, self.path)
+    }
+}
+
+struct IssueRecord {
+    id: u64,
+    iid: u64,
+    project: u64,
+    title: String,
+    description: Option<String>,
+    closed: bool,
+    labels: Vec<String>,
+    author: u64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    closed_at: Option<DateTime<Utc>>,
+    closed_by: Option<u64>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MergeRequestStatus {
+    Opened,
+    Closed,
+    Merged,
+}
+
+impl MergeRequestStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            MergeRequestStatus::Opened => "opened",
+            MergeRequestStatus::Closed => "closed",
+            MergeRequestStatus::Merged => "merged",
+        }
+    }
+}
+
+struct MergeRequestRecord {
+    id: u64,
+    iid: u64,
+    project: u64,
+    title: String,
+    description: Option<String>,
+    state: MergeRequestStatus,
+    source_branch: String,
+    target_branch: String,
+    labels: Vec<String>,
+    author: u64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    closed_at: Option<DateTime<Utc>>,
+    closed_by: Option<u64>,
+    merged_at: Option<DateTime<Utc>>,
+    merged_by: Option<u64>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Noteable {
+    Issue(u64),
+    MergeRequest(u64),
+}
+
+struct NoteRecord {
+    id: u64,
+    target: Noteable,
+    body: String,
+    author: u64,
+    created_at: DateTime<Utc>,
+}
+
+struct PipelineRecord {
+    id: u64,
+    project: u64,
+    ref_: String,
+    sha: String,
+    status: String,
+    user: u64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+struct HookRecord {
+    id: u64,
+    project: u64,
+    url: String,
+    token: Option<String>,
+    push_events: bool,
+    issues_events: bool,
+    merge_requests_events: bool,
+    note_events: bool,
+    pipeline_events: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl HookRecord {
+    fn wants(&self, kind: HookKind) -> bool {
+        match kind {
+            HookKind::Issue => self.issues_events,
+            HookKind::MergeRequest => self.merge_requests_events,
+            HookKind::Note => self.note_events,
+            HookKind::Pipeline => self.pipeline_events,
+        }
+    }
+}
+
+/// Request parameters from both the query string and the body.
+struct Params(Vec<(String, String)>);
+
+impl Params {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn flag(&self, key: &str) -> Option<bool> {
+        self.get(key).map(|value| value == "true" || value == "1")
+    }
+
+    fn list(&self, key: &str) -> Option<Vec<String>> {
+        self.get(key).map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|label| !label.is_empty())
+                .map(Into::into)
+                .collect()
+        })
+    }
+
+    fn number(&self, key: &str) -> Option<u64> {
+        self.get(key).and_then(|value| value.parse().ok())
+    }
+}
+
+struct Reply {
+    status: u16,
+    body: Option<Value>,
+    headers: Vec<(String, String)>,
+}
+
+impl Reply {
+    fn json(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            body: Some(body),
+            headers: Vec::new(),
+        }
+    }
+
+    fn ok(body: Value) -> Self {
+        Self::json(200, body)
+    }
+
+    fn created(body: Value) -> Self {
+        Self::json(201, body)
+    }
+
+    fn no_content() -> Self {
+        Self {
+            status: 204,
+            body: None,
+            headers: Vec::new(),
+        }
+    }
+
+    fn message(status: u16, msg: &str) -> Self {
+        Self::json(
+            status,
+            json!({
+                "message": msg,
+            }),
+        )
+    }
+
+    fn not_found(what: &str) -> Self {
+        Self::message(404, &format!("404 {} Not Found", what))
+    }
+
+    fn missing(param: &str) -> Self {
+        Self::json(
+            400,
+            json!({
+                "error": format!("{} is missing", param),
+            }),
+        )
+    }
+}
+
+fn hook_date(date: &DateTime<Utc>) -> String {
+    date.format("%Y-%m-%d %H:%M:%S UTC").to_string()
+}
+
+fn fake_sha(project: u64, name: &str) -> String {
+    let mut sha = String::with_capacity(48);
+    for part in 0..3_u8 {
+        let mut hasher = DefaultHasher::new();
+        (project, name, part).hash(&mut hasher);
+        sha.push_str(&format!("{:016x}", hasher.finish()));
+    }
+    sha.truncate(40);
+    sha
+}
+
+fn extend_json(json: &mut Value, extra: Value) {
+    if let (Some(json), Value::Object(extra)) = (json.as_object_mut(), extra) {
+        json.extend(extra);
+    }
+}
+
+fn is_finished(status: &str) -> bool {
+    matches!(status, "success" | "failed" | "canceled" | "skipped")
+}
+
+struct State {
+    base: String,
+    next_id: u64,
+    users: Vec<UserRecord>,
+    groups: Vec<(String, u64)>,
+    projects: Vec<ProjectRecord>,
+    issues: Vec<IssueRecord>,
+    merge_requests: Vec<MergeRequestRecord>,
+    notes: Vec<NoteRecord>,
+    pipelines: Vec<PipelineRecord>,
+    hooks: Vec<HookRecord>,
+    deliveries: Vec<HookDelivery>,
+    sender: Option<Sender<HookDelivery>>,
+}
+
+impl State {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn user(&self, id: u64) -> Option<&UserRecord> {
+        self.users.iter().find(|user| user.id == id)
+    }
+
+    fn project(&self, id: u64) -> &ProjectRecord {
+        self.projects
+            .iter()
+            .find(|project| project.id == id)
+            .expect("records should only refer to existing projects")
+    }
+
+    fn find_project(&self, id_or_path: &str) -> Option<u64> {
+        if let Ok(id) = id_or_path.parse::<u64>() {
+            self.projects
+                .iter()
+                .find(|project| project.id == id)
+                .map(|project| project.id)
+        } else {
+            self.projects
+                .iter()
+                .find(|project| project.path_with_namespace() == id_or_path)
+                .map(|project| project.id)
+        }
+    }
+
+    fn add_project(&mut self, creator: u64, path_with_namespace: &str) -> u64 {
+        let (namespace, path) = match path_with_namespace.rfind('/') {
+            Some(idx) => (&path_with_namespace[..idx], &path_with_namespace[idx + 1..]),
+            None => {
+                let username = self
+                    .user(creator)
+                    .map(|user| user.username.clone())
+                    .unwrap_or_default();
+                return self.add_project(creator, &format!("{}/{}", username, path_with_namespace));
+            },
+        };
+
+        let user_namespace = self
+            .users
+            .iter()
+            .find(|user| user.username == namespace)
+            .map(|user| user.id);
+        let (namespace_id, namespace_is_user) = if let Some(id) = user_namespace {
+            (id, true)
+        } else if let Some((_, id)) = self.groups.iter().find(|(name, _)| name == namespace) {
+            (*id, false)
+        } else {
+            let id = self.next_id();
+            self.groups.push((namespace.into(), id));
+            (id, false)
+        };
+
+        let id = self.next_id();
+        self.projects.push(ProjectRecord {
+            id,
+            namespace: namespace.into(),
+            namespace_id,
+            namespace_is_user,
+            path: path.into(),
+            description: None,
+            default_branch: "main".into(),
+            creator,
+            created_at: Utc::now(),
+        });
+        id
+    }
+
+    fn authenticate(&self, token: Option<&str>) -> Option<u64> {
+        token.and_then(|token| {
+            self.users
+                .iter()
+                .find(|user| user.token == token)
+                .map(|user| user.id)
+        })
+    }
+
+    fn dispatch(&mut self, method: &str, url: &Url, token: Option<&str>, params: Params) -> Reply {
+        let segments: Vec<String> = url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .map(|segment| percent_decode_str(segment).decode_utf8_lossy().into_owned())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+        let path = match &segments[..] {
+            ["api", "v4", path @ ..] => path,
+            _ => return Reply::message(404, "404 Not Found"),
+        };
+
+        let user = if let Some(user) = self.authenticate(token) {
+            user
+        } else {
+            return Reply::message(401, "401 Unauthorized");
+        };
+
+        match (method, path) {
+            ("GET", ["user"]) => Reply::ok(self.user_public_json(user)),
+            ("GET", ["users", id]) => {
+                id.parse()
+                    .ok()
+                    .and_then(|id| self.user(id))
+                    .map(|user| Reply::ok(self.user_basic_json(user.id)))
+                    .unwrap_or_else(|| Reply::not_found("User"))
+            },
+            ("GET", ["projects"]) => {
+                let mut items: Vec<_> = self.projects.iter().map(|p| p.id).collect();
+                items.reverse();
+                let items = items.into_iter().map(|p| self.project_json(p)).collect();
+                self.paginate(url, &params, items)
+            },
+            ("POST", ["projects"]) => {
+                let path = match params.get("path").or_else(|| params.get("name")) {
+                    Some(path) => path.to_string(),
+                    None => return Reply::missing("name"),
+                };
+                let username = self
+                    .user(user)
+                    .map(|u| u.username.clone())
+                    .unwrap_or_default();
+                let full_path = format!("{}/{}", username, path);
+                if self.find_project(&full_path).is_some() {
+                    return Reply::json(
+                        400,
+                        json!({
+                            "message": {
+                                "path": ["has already been taken"],
+                            },
+                        }),
+                    );
+                }
+                let id = self.add_project(user, &full_path);
+                if let Some(project) = self.projects.iter_mut().find(|p| p.id == id) {
+                    project.description = params.get("description").map(Into::into);
+                    if let Some(branch) = params.get("default_branch") {
+                        project.default_branch = branch.into();
+                    }
+                }
+                Reply::created(self.project_json(id))
+            },
+            (_, ["projects", project, rest @ ..]) => {
+                if let Some(project) = self.find_project(project) {
+                    self.project_route(method, url, user, project, rest, &params)
+                } else {
+                    Reply::not_found("Project")
+                }
+            },
+            _ => Reply::message(404, "404 Not Found"),
+        }
+    }
+
+    fn project_route(
+        &mut self,
+        method: &str,
+        url: &Url,
+        user: u64,
+        project: u64,
+        path: &[&str],
+        params: &Params,
+    ) -> Reply {
+        match (method, path) {
+            ("GET", []) => Reply::ok(self.project_json(project)),
+
+            ("GET", ["issues"]) => {
+                let state = params.get("state").unwrap_or("all");
+                let labels = params.list("labels").unwrap_or_default();
+                let mut items: Vec<_> = self
+                    .issues
+                    .iter()
+                    .filter(|issue| issue.project == project)
+                    .filter(|issue| {
+                        match state {
+                            "opened" => !issue.closed,
+                            "closed" => issue.closed,
+                            _ => true,
+                        }
+                    })
+                    .filter(|issue| labels.iter().all(|label| issue.labels.contains(label)))
+                    .map(|issue| issue.id)
+                    .collect();
+                if params.get("sort") != Some("asc") {
+                    items.reverse();
+                }
+                let items = items.into_iter().map(|id| self.issue_json(id)).collect();
+                self.paginate(url, params, items)
+            },
+            ("POST", ["issues"]) => {
+                let title = match params.get("title") {
+                    Some(title) => title.to_string(),
+                    None => return Reply::missing("title"),
+                };
+                let id = self.next_id();
+                let iid = self
+                    .issues
+                    .iter()
+                    .filter(|issue| issue.project == project)
+                    .count() as u64
+                    + 1;
+                let now = Utc::now();
+                self.issues.push(IssueRecord {
+                    id,
+                    iid,
+                    project,
+                    title,
+                    description: params.get("description").map(Into::into),
+                    closed: false,
+                    labels: params.list("labels").unwrap_or_default(),
+                    author: user,
+                    created_at: now,
+                    updated_at: now,
+                    closed_at: None,
+                    closed_by: None,
+                });
+                self.trigger_issue(id, user, "open");
+                Reply::created(self.issue_json(id))
+            },
+            (_, ["issues", iid, rest @ ..]) => {
+                let iid = iid.parse().ok();
+                let issue = self
+                    .issues
+                    .iter()
+                    .find(|issue| issue.project == project && Some(issue.iid) == iid)
+                    .map(|issue| issue.id);
+                if let Some(issue) = issue {
+                    self.issue_route(method, url, user, issue, rest, params)
+                } else {
+                    Reply::not_found("Issue")
+                }
+            },
+
+            ("GET", ["merge_requests"]) => {
+                let state = params.get("state").unwrap_or("all");
+                let labels = params.list("labels").unwrap_or_default();
+                let source_branch = params.get("source_branch");
+                let target_branch = params.get("target_branch");
+                let mut items: Vec<_> = self
+                    .merge_requests
+                    .iter()
+                    .filter(|mr| mr.project == project)
+                    .filter(|mr| state == "all" || mr.state.as_str() == state)
+                    .filter(|mr| labels.iter().all(|label| mr.labels.contains(label)))
+                    .filter(|mr| {
+                        source_branch
+                            .iter()
+                            .all(|&branch| mr.source_branch == branch)
+                    })
+                    .filter(|mr| {
+                        target_branch
+                            .iter()
+                            .all(|&branch| mr.target_branch == branch)
+                    })
+                    .map(|mr| mr.id)
+                    .collect();
+                if params.get("sort") != Some("asc") {
+                    items.reverse();
+                }
+                let items = items
+                    .into_iter()
+                    .map(|id| self.merge_request_json(id))
+                    .collect();
+                self.paginate(url, params, items)
+            },
+            ("POST", ["merge_requests"]) => {
+                let source_branch = match params.get("source_branch") {
+                    Some(branch) => branch.to_string(),
+                    None => return Reply::missing("source_branch"),
+                };
+                let target_branch = match params.get("target_branch") {
+                    Some(branch) => branch.to_string(),
+                    None => return Reply::missing("target_branch"),
+                };
+                let title = match params.get("title") {
+                    Some(title) => title.to_string(),
+                    None => return Reply::missing("title"),
+                };
+                let existing = self.merge_requests.iter().find(|mr| {
+                    mr.project == project
+                        && mr.state == MergeRequestStatus::Opened
+                        && mr.source_branch == source_branch
+                        && mr.target_branch == target_branch
+                });
+                if let Some(existing) = existing {
+                    return Reply::json(
+                        409,
+                        json!({
+                            "message": [format!(
+                                "Another open merge request already exists for this source branch: !{}",
+                                existing.iid,
+                            )],
+                        }),
+                    );
+                }
+                let id = self.next_id();
+                let iid = self
+                    .merge_requests
+                    .iter()
+                    .filter(|mr| mr.project == project)
+                    .count() as u64
+                    + 1;
+                let now = Utc::now();
+                self.merge_requests.push(MergeRequestRecord {
+                    id,
+                    iid,
+                    project,
+                    title,
+                    description: params.get("description").map(Into::into),
+                    state: MergeRequestStatus::Opened,
+                    source_branch,
+                    target_branch,
+                    labels: params.list("labels").unwrap_or_default(),
+                    author: user,
+                    created_at: now,
+                    updated_at: now,
+                    closed_at: None,
+                    closed_by: None,
+                    merged_at: None,
+                    merged_by: None,
+                });
+                self.trigger_merge_request(id, user, "open");
+                Reply::created(self.merge_request_json(id))
+            },
+            (_, ["merge_requests", iid, rest @ ..]) => {
+                let iid = iid.parse().ok();
+                let mr = self
+                    .merge_requests
+                    .iter()
+                    .find(|mr| mr.project == project && Some(mr.iid) == iid)
+                    .map(|mr| mr.id);
+                if let Some(mr) = mr {
+                    self.merge_request_route(method, url, user, mr, rest, params)
+                } else {
+                    Reply::not_found("Merge Request")
+                }
+            },
+
+            ("GET", ["pipelines"]) => {
+                let ref_ = params.get("ref");
+                let sha = params.get("sha");
+                let status = params.get("status");
+                let items: Vec<_> = self
+                    .pipelines
+                    .iter()
+                    .rev()
+                    .filter(|pipeline| pipeline.project == project)
+                    .filter(|pipeline| ref_.iter().all(|&r| pipeline.ref_ == r))
+                    .filter(|pipeline| sha.iter().all(|&s| pipeline.sha == s))
+                    .filter(|pipeline| status.iter().all(|&s| pipeline.status == s))
+                    .map(|pipeline| self.pipeline_basic_json(pipeline))
+                    .collect();
+                self.paginate(url, params, items)
+            },
+            ("GET", ["pipelines", id]) => {
+                let id = id.parse().ok();
+                self.pipelines
+                    .iter()
+                    .find(|pipeline| pipeline.project == project && Some(pipeline.id) == id)
+                    .map(|pipeline| Reply::ok(self.pipeline_json(pipeline)))
+                    .unwrap_or_else(|| Reply::not_found("Pipeline"))
+            },
+            ("POST", ["pipeline"]) => {
+                let ref_ = match params.get("ref") {
+                    Some(ref_) => ref_.to_string(),
+                    None => return Reply::missing("ref"),
+                };
+                let id = self.next_id();
+                let now = Utc::now();
+                self.pipelines.push(PipelineRecord {
+                    id,
+                    project,
+                    sha: fake_sha(project, &ref_),
+                    ref_,
+                    status: "pending".into(),
+                    user,
+                    created_at: now,
+                    updated_at: now,
+                    finished_at: None,
+                });
+                self.trigger_pipeline(id);
+                let pipeline = self.pipelines.last().expect("a pipeline was just created");
+                Reply::created(self.pipeline_json(pipeline))
+            },
+
+            ("GET", ["hooks"]) => {
+                let items = self
+                    .hooks
+                    .iter()
+                    .filter(|hook| hook.project == project)
+                    .map(|hook| self.hook_json(hook))
+                    .collect();
+                self.paginate(url, params, items)
+            },
+            ("POST", ["hooks"]) => {
+                let hook_url = match params.get("url") {
+                    Some(hook_url) => hook_url.to_string(),
+                    None => return Reply::missing("url"),
+                };
+                let id = self.next_id();
+                self.hooks.push(HookRecord {
+                    id,
+                    project,
+                    url: hook_url,
+                    token: params.get("token").map(Into::into),
+                    push_events: params.flag("push_events").unwrap_or(true),
+                    issues_events: params.flag("issues_events").unwrap_or(false),
+                    merge_requests_events: params.flag("merge_requests_events").unwrap_or(false),
+                    note_events: params.flag("note_events").unwrap_or(false),
+                    pipeline_events: params.flag("pipeline_events").unwrap_or(false),
+                    created_at: Utc::now(),
+                });
+                let hook = self.hooks.last().expect("a hook was just created");
+                Reply::created(self.hook_json(hook))
+            },
+            ("GET", ["hooks", id]) => {
+                let id = id.parse().ok();
+                self.hooks
+                    .iter()
+                    .find(|hook| hook.project == project && Some(hook.id) == id)
+                    .map(|hook| Reply::ok(self.hook_json(hook)))
+                    .unwrap_or_else(|| Reply::not_found("Hook"))
+            },
+            ("DELETE", ["hooks", id]) => {
+                let id = id.parse().ok();
+                let len = self.hooks.len();
+                self.hooks
+                    .retain(|hook| !(hook.project == project && Some(hook.id) == id));
+                if self.hooks.len() == len {
+                    Reply::not_found("Hook")
+                } else {
+                    Reply::no_content()
+                }
+            },
+
+            _ => Reply::message(404, "404 Not Found"),
+        }
+    }
+
+    fn issue_route(
+        &mut self,
+        method: &str,
+        url: &Url,
+        user: u64,
+        issue: u64,
+        path: &[&str],
+        params: &Params,
+    ) -> Reply {
+        match (method, path) {
+            ("GET", []) => Reply::ok(self.issue_json(issue)),
+            ("PUT", []) => {
+                let now = Utc::now();
+                let record = self
+                    .issues
+                    .iter_mut()
+                    .find(|record| record.id == issue)
+                    .expect("the issue was just found");
+                let mut action = "update";
+                if let Some(title) = params.get("title") {
+                    record.title = title.into();
+                }
+                if let Some(description) = params.get("description") {
+                    record.description = Some(description.into());
+                }
+                if let Some(labels) = params.list("labels") {
+                    record.labels = labels;
+                }
+                for label in params.list("add_labels").unwrap_or_default() {
+                    if !record.labels.contains(&label) {
+                        record.labels.push(label);
+                    }
+                }
+                if let Some(remove) = params.list("remove_labels") {
+                    record.labels.retain(|label| !remove.contains(label));
+                }
+                match params.get("state_event") {
+                    Some("close") if !record.closed => {
+                        record.closed = true;
+                        record.closed_at = Some(now);
+                        record.closed_by = Some(user);
+                        action = "close";
+                    },
+                    Some("reopen") if record.closed => {
+                        record.closed = false;
+                        record.closed_at = None;
+                        record.closed_by = None;
+                        action = "reopen";
+                    },
+                    _ => (),
+                }
+                record.updated_at = now;
+                self.trigger_issue(issue, user, action);
+                Reply::ok(self.issue_json(issue))
+            },
+            ("GET", ["notes"]) => self.list_notes(url, Noteable::Issue(issue), params),
+            ("POST", ["notes"]) => self.create_note(user, Noteable::Issue(issue), params),
+            _ => Reply::message(404, "404 Not Found"),
+        }
+    }
+
+    fn merge_request_route(
+        &mut self,
+        method: &str,
+        url: &Url,
+        user: u64,
+        mr: u64,
+        path: &[&str],
+        params: &Params,
+    ) -> Reply {
+        match (method, path) {
+            ("GET", []) => Reply::ok(self.merge_request_json(mr)),
+            ("PUT", []) => {
+                let now = Utc::now();
+                let record = self
+                    .merge_requests
+                    .iter_mut()
+                    .find(|record| record.id == mr)
+                    .expect("the merge request was just found");
+                let mut action = "update";
+                if let Some(title) = params.get("title") {
+                    record.title = title.into();
+                }
+                if let Some(description) = params.get("description") {
+                    record.description = Some(description.into());
+                }
+                if let Some(target_branch) = params.get("target_branch") {
+                    record.target_branch = target_branch.into();
+                }
+                if let Some(labels) = params.list("labels") {
+                    record.labels = labels;
+                }
+                for label in params.list("add_labels").unwrap_or_default() {
+                    if !record.labels.contains(&label) {
+                        record.labels.push(label);
+                    }
+                }
+                if let Some(remove) = params.list("remove_labels") {
+                    record.labels.retain(|label| !remove.contains(label));
+                }
+                match (params.get("state_event"), record.state) {
+                    (Some("close"), MergeRequestStatus::Opened) => {
+                        record.state = MergeRequestStatus::Closed;
+                        record.closed_at = Some(now);
+                        record.closed_by = Some(user);
+                        action = "close";
+                    },
+                    (Some("reopen"), MergeRequestStatus::Closed) => {
+                        record.state = MergeRequestStatus::Opened;
+                        record.closed_at = None;
+                        record.closed_by = None;
+                        action = "reopen";
+                    },
+                    _ => (),
+                }
+                record.updated_at = now;
+                self.trigger_merge_request(mr, user, action);
+                Reply::ok(self.merge_request_json(mr))
+            },
+            ("PUT", ["merge"]) => {
+                let now = Utc::now();
+                let record = self
+                    .merge_requests
+                    .iter_mut()
+                    .find(|record| record.id == mr)
+                    .expect("the merge request was just found");
+                if record.state != MergeRequestStatus::Opened {
+                    return Reply::message(405, "405 Method Not Allowed");
+                }
+                record.state = MergeRequestStatus::Merged;
+                record.merged_at = Some(now);
+                record.merged_by = Some(user);
+                record.updated_at = now;
+                self.trigger_merge_request(mr, user, "merge");
+                Reply::ok(self.merge_request_json(mr))
+            },
+            ("GET", ["notes"]) => self.list_notes(url, Noteable::MergeRequest(mr), params),
+            ("POST", ["notes"]) => self.create_note(user, Noteable::MergeRequest(mr), params),
+            _ => Reply::message(404, "404 Not Found"),
+        }
+    }
+
+    fn list_notes(&self, url: &Url, target: Noteable, params: &Params) -> Reply {
+        let mut items: Vec<_> = self
+            .notes
+            .iter()
+            .filter(|note| note.target == target)
+            .map(|note| self.note_json(note))
+            .collect();
+        if params.get("sort") != Some("asc") {
+            items.reverse();
+        }
+        self.paginate(url, params, items)
+    }
+
+    fn create_note(&mut self, user: u64, target: Noteable, params: &Params) -> Reply {
+        let body = match params.get("body") {
+            Some(body) => body.to_string(),
+            None => return Reply::missing("body"),
+        };
+        let id = self.next_id();
+        self.notes.push(NoteRecord {
+            id,
+            target,
+            body,
+            author: user,
+            created_at: Utc::now(),
+        });
+        self.trigger_note(id);
+        let note = self.notes.last().expect("a note was just created");
+        Reply::created(self.note_json(note))
+    }
+
+    fn paginate(&self, url: &Url, params: &Params, items: Vec<Value>) -> Reply {
+        let per_page = params.number("per_page").unwrap_or(20).clamp(1, 100) as usize;
+        let page = params.number("page").unwrap_or(1).max(1) as usize;
+        let total = items.len();
+        let total_pages = total.saturating_sub(1) / per_page + 1;
+
+        let page_items: Vec<_> = items
+            .into_iter()
+            .skip((page - 1) * per_page)
+            .take(per_page)
+            .collect();
+
+        let page_url = |page: usize| {
+            let mut page_url = url.clone();
+            let pairs: Vec<_> = url
+                .query_pairs()
+                .filter(|(key, _)| key != "page")
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect();
+            page_url
+                .query_pairs_mut()
+                .clear()
+                .extend_pairs(pairs)
+                .append_pair("page", &page.to_string());
+            page_url
+        };
+
+        let mut links = Vec::new();
+        let next_page = if page < total_pages {
+            links.push(format!("<{}>; rel=\"next\"", page_url(page + 1)));
+            (page + 1).to_string()
+        } else {
+            String::new()
+        };
+        let prev_page = if page > 1 {
+            links.push(format!("<{}>; rel=\"prev\"", page_url(page - 1)));
+            (page - 1).to_string()
+        } else {
+            String::new()
+        };
+        links.push(format!("<{}>; rel=\"first\"", page_url(1)));
+        links.push(format!("<{}>; rel=\"last\"", page_url(total_pages)));
+
+        let mut reply = Reply::ok(Value::Array(page_items));
+        reply.headers = vec![
+            ("x-total".into(), total.to_string()),
+            ("x-total-pages".into(), total_pages.to_string()),
+            ("x-page".into(), page.to_string()),
+            ("x-per-page".into(), per_page.to_string()),
+            ("x-next-page".into(), next_page),
+            ("x-prev-page".into(), prev_page),
+            ("link".into(), links.join(", ")),
+        ];
+        reply
+    }
+
+    fn trigger(&mut self, project: u64, kind: HookKind, payload: Value) {
+        let deliveries: Vec<_> = self
+            .hooks
+            .iter()
+            .filter(|hook| hook.project == project && hook.wants(kind))
+            .map(|hook| {
+                HookDelivery {
+                    url: hook.url.clone(),
+                    event: kind.event().into(),
+                    token: hook.token.clone(),
+                    payload: payload.clone(),
+                }
+            })
+            .collect();
+
+        for delivery in deliveries {
+            if let Some(sender) = self.sender.as_ref() {
+                // The delivery thread only goes away when the server is shutting down.
+                let _ = sender.send(delivery.clone());
+            }
+            self.deliveries.push(delivery);
+        }
+    }
+
+    fn trigger_issue(&mut self, issue: u64, user: u64, action: &str) {
+        let record = self.issue(issue);
+        let project = record.project;
+        let payload = json!({
+            "object_kind": "issue",
+            "event_type": "issue",
+            "user": self.user_hook_json(user),
+            "project": self.project_hook_json(project),
+            "object_attributes": self.issue_hook_json(issue, Some(action)),
+            "assignee": null,
+            "labels": record.labels,
+            "repository": self.repository_hook_json(project),
+        });
+        self.trigger(project, HookKind::Issue, payload);
+    }
+
+    fn trigger_merge_request(&mut self, mr: u64, user: u64, action: &str) {
+        let project = self.merge_request(mr).project;
+        let payload = json!({
+            "object_kind": "merge_request",
+            "event_type": "merge_request",
+            "user": self.user_hook_json(user),
+            "project": self.project_hook_json(project),
+            "object_attributes": self.merge_request_hook_json(mr, Some(action)),
+            "assignee": null,
+            "repository": self.repository_hook_json(project),
+        });
+        self.trigger(project, HookKind::MergeRequest, payload);
+    }
+
+    fn trigger_note(&mut self, note: u64) {
+        let record = self
+            .notes
+            .iter()
+            .find(|record| record.id == note)
+            .expect("the note should exist");
+        let (project, noteable_type, noteable_id, noteable_url, issue, merge_request) =
+            match record.target {
+                Noteable::Issue(issue) => {
+                    let issue_record = self.issue(issue);
+                    (
+                        issue_record.project,
+                        "Issue",
+                        issue,
+                        self.issue_url(issue),
+                        self.issue_hook_json(issue, None),
+                        Value::Null,
+                    )
+                },
+                Noteable::MergeRequest(mr) => {
+                    let mr_record = self.merge_request(mr);
+                    (
+                        mr_record.project,
+                        "MergeRequest",
+                        mr,
+                        self.merge_request_url(mr),
+                        Value::Null,
+                        self.merge_request_hook_json(mr, None),
+                    )
+                },
+            };
+        let created_at = hook_date(&record.created_at);
+        let payload = json!({
+            "object_kind": "note",
+            "event_type": "note",
+            "user": self.user_hook_json(record.author),
+            "project_id": project,
+            "project": self.project_hook_json(project),
+            "object_attributes": {
+                "id": record.id,
+                "note": record.body,
+                "noteable_type": noteable_type,
+                "original_position": null,
+                "position": null,
+                "author_id": record.author,
+                "created_at": created_at,
+                "updated_at": created_at,
+                "updated_by_id": null,
+                "resolved_at": null,
+                "resolved_by_id": null,
+                "project_id": project,
+                "attachment": null,
+                "line_code": null,
+                "commit_id": null,
+                "discussion_id": fake_sha(project, &format!("discussion/{}", record.id)),
+                "original_discussion_id": null,
+                "noteable_id": noteable_id,
+                "system": false,
+                "st_diff": null,
+                "url": format!("{}#note_{}", noteable_url, record.id),
+                "type": null,
+            },
+            "commit": null,
+            "issue": issue,
+            "merge_request": merge_request,
+            "snippet": null,
+            "repository": self.repository_hook_json(project),
+        });
+        self.trigger(project, HookKind::Note, payload);
+    }
+
+    fn trigger_pipeline(&mut self, pipeline: u64) {
+        let record = self
+            .pipelines
+            .iter()
+            .find(|record| record.id == pipeline)
+            .expect("the pipeline should exist");
+        let project = record.project;
+        let mut project_json = self.project_hook_json(project);
+        project_json["ci_config_path"] = Value::Null;
+        let payload = json!({
+            "object_kind": "pipeline",
+            "object_attributes": {
+                "id": record.id,
+                "ref": record.ref_,
+                "tag": false,
+                "sha": record.sha,
+                "before_sha": "0000000000000000000000000000000000000000",
+                "source": "api",
+                "status": record.status,
+                "stages": [],
+                "created_at": hook_date(&record.created_at),
+                "finished_at": record.finished_at.as_ref().map(hook_date),
+                "duration": null,
+                "variables": [],
+            },
+            "merge_request": null,
+            "user": self.user_hook_json(record.user),
+            "project": project_json,
+            "commit": null,
+            "builds": [],
+        });
+        self.trigger(project, HookKind::Pipeline, payload);
+    }
+
+    fn issue(&self, id: u64) -> &IssueRecord {
+        self.issues
+            .iter()
+            .find(|issue| issue.id == id)
+            .expect("the issue should exist")
+    }
+
+    fn merge_request(&self, id: u64) -> &MergeRequestRecord {
+        self.merge_requests
+            .iter()
+            .find(|mr| mr.id == id)
+            .expect("the merge request should exist")
+    }
+
+    fn project_url(&self, project: u64) -> String {
+        format!(
+            "{}/{}",
+            self.base,
+            self.project(project).path_with_namespace(),
+        )
+    }
+
+    fn issue_url(&self, issue: u64) -> String {
+        let record = self.issue(issue);
+        format!(
+            "{}/-/issues/{}",
+            self.project_url(record.project),
+            record.iid
+        )
+    }
+
+    fn merge_request_url(&self, mr: u64) -> String {
+        let record = self.merge_request(mr);
+        format!(
+            "{}/-/merge_requests/{}",
+            self.project_url(record.project),
+            record.iid,
+        )
+    }
+
+    fn user_basic_json(&self, user: u64) -> Value {
+        let username = self
+            .user(user)
+            .map(|user| user.username.as_str())
+            .unwrap_or("ghost");
+        json!({
+            "id": user,
+            "username": username,
+            "name": username,
+            "state": "active",
+            "avatar_url": null,
+            "web_url": format!("{}/{}", self.base, username),
+        })
+    }
+
+    fn user_public_json(&self, user: u64) -> Value {
+        let record = self.user(user).expect("authenticated users should exist");
+        let mut json = self.user_basic_json(user);
+        let extra = json!({
+            "created_at": record.created_at,
+            "is_admin": false,
+            "bio": null,
+            "private_profile": false,
+            "location": null,
+            "public_email": null,
+            "skype": "",
+            "linkedin": "",
+            "twitter": "",
+            "website_url": "",
+            "organization": null,
+            "last_sign_in_at": null,
+            "last_activity_on": null,
+            "confirmed_at": record.created_at,
+            "email": format!("{}@example.invalid", record.username),
+            "theme_id": 1,
+            "color_scheme_id": 1,
+            "projects_limit": 100_000,
+            "current_sign_in_at": null,
+            "identities": [],
+            "can_create_group": true,
+            "can_create_project": true,
+            "two_factor_enabled": false,
+            "external": false,
+        });
+        extend_json(&mut json, extra);
+        json
+    }
+
+    fn user_hook_json(&self, user: u64) -> Value {
+        let username = self
+            .user(user)
+            .map(|user| user.username.as_str())
+            .unwrap_or("ghost");
+        json!({
+            "id": user,
+            "name": username,
+            "username": username,
+            "avatar_url": null,
+            "email": format!("{}@example.invalid", username),
+        })
+    }
+
+    fn project_json(&self, project: u64) -> Value {
+        let record = self.project(project);
+        let path_with_namespace = record.path_with_namespace();
+        let web_url = self.project_url(project);
+        let host = self.base.trim_start_matches("http://");
+        let open_issues = self
+            .issues
+            .iter()
+            .filter(|issue| issue.project == project && !issue.closed)
+            .count();
+        let (kind, owner) = if record.namespace_is_user {
+            ("user", self.user_basic_json(record.namespace_id))
+        } else {
+            ("group", Value::Null)
+        };
+        let mut json = json!({
+            "id": record.id,
+            "description": record.description,
+            "default_branch": record.default_branch,
+            "tag_list": [],
+            "archived": false,
+            "empty_repo": false,
+            "visibility": "private",
+            "ssh_url_to_repo": format!("git@{}:{}.git", host, path_with_namespace),
+            "http_url_to_repo": format!("{}.git", web_url),
+            "web_url": web_url,
+            "readme_url": null,
+            "owner": owner,
+            "name": record.path,
+            "name_with_namespace": format!("{} / {}", record.namespace, record.path),
+            "path": record.path,
+            "path_with_namespace": path_with_namespace,
+            "container_registry_enabled": false,
+            "created_at": record.created_at,
+            "last_activity_at": record.created_at,
+            "shared_runners_enabled": true,
+            "lfs_enabled": true,
+            "creator_id": record.creator,
+        });
+        let extra = json!({
+            "namespace": {
+                "id": record.namespace_id,
+                "path": record.namespace,
+                "name": record.namespace,
+                "kind": kind,
+                "full_path": record.namespace,
+                "members_count_with_descendants": null,
+                "avatar_url": null,
+                "web_url": format!("{}/{}", self.base, record.namespace),
+            },
+            "forked_from_project": null,
+            "avatar_url": null,
+            "ci_config_path": null,
+            "build_git_strategy": "fetch",
+            "ci_default_git_depth": 50,
+            "import_error": null,
+            "star_count": 0,
+            "forks_count": 0,
+            "open_issues_count": open_issues,
+            "runners_token": null,
+            "public_jobs": true,
+            "shared_with_groups": [],
+            "only_allow_merge_if_pipeline_succeeds": false,
+            "only_allow_merge_if_all_discussions_are_resolved": false,
+            "remove_source_branch_after_merge": true,
+            "printing_merge_request_link_enabled": true,
+            "request_access_enabled": false,
+            "resolve_outdated_diff_discussions": false,
+            "jobs_enabled": true,
+            "issues_enabled": true,
+            "merge_requests_enabled": true,
+            "snippets_enabled": true,
+            "wiki_enabled": true,
+            "builds_access_level": "enabled",
+            "issues_access_level": "enabled",
+            "merge_requests_access_level": "enabled",
+            "repository_access_level": "enabled",
+            "snippets_access_level": "enabled",
+            "wiki_access_level": "enabled",
+            "merge_method": "merge",
+        });
+        extend_json(&mut json, extra);
+        json
+    }
+
+    fn project_hook_json(&self, project: u64) -> Value {
+        let record = self.project(project);
+        let web_url = self.project_url(project);
+        let host = self.base.trim_start_matches("http://");
+        let ssh_url = format!("git@{}:{}.git", host, record.path_with_namespace());
+        let http_url = format!("{}.git", web_url);
+        json!({
+            "id": record.id,
+            "name": record.path,
+            "description": record.description,
+            "web_url": web_url,
+            "avatar_url": null,
+            "git_ssh_url": ssh_url,
+            "git_http_url": http_url,
+            "namespace": record.namespace,
+            "visibility_level": 0,
+            "path_with_namespace": record.path_with_namespace(),
+            "default_branch": record.default_branch,
+            "homepage": web_url,
+            "url": ssh_url,
+            "ssh_url": ssh_url,
+            "http_url": http_url,
+        })
+    }
+
+    fn repository_hook_json(&self, project: u64) -> Value {
+        let record = self.project(project);
+        let web_url = self.project_url(project);
+        json!({
+            "name": record.path,
+            "url": format!("{}.git", web_url),
+            "description": record.description,
+            "homepage": web_url,
+        })
+    }
+
+    fn time_stats_json() -> Value {
+        json!({
+            "time_estimate": 0,
+            "total_time_spent": 0,
+            "human_time_estimate": null,
+            "human_total_time_spent": null,
+        })
+    }
+
+    fn note_count(&self, target: Noteable) -> usize {
+        self.notes
+            .iter()
+            .filter(|note| note.target == target)
+            .count()
+    }
+
+    fn issue_json(&self, issue: u64) -> Value {
+        let record = self.issue(issue);
+        json!({
+            "id": record.id,
+            "iid": record.iid,
+            "project_id": record.project,
+            "title": record.title,
+            "description": record.description,
+            "state": if record.closed { "closed" } else { "opened" },
+            "created_at": record.created_at,
+            "updated_at": record.updated_at,
+            "closed_at": record.closed_at,
+            "closed_by": record.closed_by.map(|user| self.user_basic_json(user)),
+            "labels": record.labels,
+            "milestone": null,
+            "author": self.user_basic_json(record.author),
+            "assignee": null,
+            "assignees": [],
+            "time_stats": Self::time_stats_json(),
+            "user_notes_count": self.note_count(Noteable::Issue(issue)),
+            "merge_requests_count": 0,
+            "upvotes": 0,
+            "downvotes": 0,
+            "due_date": null,
+            "has_tasks": false,
+            "confidential": false,
+            "discussion_locked": null,
+            "web_url": self.issue_url(issue),
+        })
+    }
+
+    fn issue_hook_json(&self, issue: u64, action: Option<&str>) -> Value {
+        let record = self.issue(issue);
+        json!({
+            "id": record.id,
+            "iid": record.iid,
+            "title": record.title,
+            "assignee_id": null,
+            "assignee_ids": [],
+            "author_id": record.author,
+            "project_id": record.project,
+            "created_at": hook_date(&record.created_at),
+            "updated_at": hook_date(&record.updated_at),
+            "deleted_at": null,
+            "closed_at": record.closed_at.as_ref().map(hook_date),
+            "due_date": null,
+            "updated_by_id": null,
+            "moved_to_id": null,
+            "branch_name": null,
+            "description": record.description,
+            "milestone_id": null,
+            "state": if record.closed { "closed" } else { "opened" },
+            "confidential": false,
+            "time_estimate": 0,
+            "total_time_spent": 0,
+            "human_time_estimate": null,
+            "human_total_time_spent": null,
+            "url": self.issue_url(issue),
+            "action": action,
+        })
+    }
+
+    fn merge_request_json(&self, mr: u64) -> Value {
+        let record = self.merge_request(mr);
+        let pipeline = self
+            .pipelines
+            .iter()
+            .rev()
+            .find(|pipeline| {
+                pipeline.project == record.project && pipeline.ref_ == record.source_branch
+            })
+            .map(|pipeline| self.pipeline_basic_json(pipeline));
+        let merge_commit_sha = record
+            .merged_at
+            .map(|_| fake_sha(record.project, &format!("merge/{}", record.id)));
+        let title = record.title.to_lowercase();
+        let mut json = json!({
+            "id": record.id,
+            "iid": record.iid,
+            "project_id": record.project,
+            "title": record.title,
+            "description": record.description,
+            "state": record.state.as_str(),
+            "created_at": record.created_at,
+            "updated_at": record.updated_at,
+            "merged_at": record.merged_at,
+            "closed_at": record.closed_at,
+            "merged_by": record.merged_by.map(|user| self.user_basic_json(user)),
+            "closed_by": record.closed_by.map(|user| self.user_basic_json(user)),
+            "target_branch": record.target_branch,
+            "source_branch": record.source_branch,
+            "upvotes": 0,
+            "downvotes": 0,
+            "author": self.user_basic_json(record.author),
+            "assignee": null,
+            "assignees": [],
+            "reviewers": [],
+            "source_project_id": record.project,
+            "target_project_id": record.project,
+            "labels": record.labels,
+        });
+        let extra = json!({
+            "work_in_progress": title.starts_with("draft:") || title.starts_with("wip:"),
+            "allow_collaboration": false,
+            "milestone": null,
+            "squash": false,
+            "merge_when_pipeline_succeeds": false,
+            "merge_status": "can_be_merged",
+            "sha": fake_sha(record.project, &record.source_branch),
+            "diff_refs": null,
+            "merge_error": null,
+            "merge_commit_sha": merge_commit_sha,
+            "squash_commit_sha": null,
+            "time_stats": Self::time_stats_json(),
+            "blocking_discussions_resolved": true,
+            "changes_count": null,
+            "user_notes_count": self.note_count(Noteable::MergeRequest(mr)),
+            "discussion_locked": null,
+            "should_remove_source_branch": null,
+            "force_remove_source_branch": false,
+            "has_conflicts": false,
+            "web_url": self.merge_request_url(mr),
+            "pipeline": pipeline,
+        });
+        extend_json(&mut json, extra);
+        json
+    }
+
+    fn merge_request_hook_json(&self, mr: u64, action: Option<&str>) -> Value {
+        let record = self.merge_request(mr);
+        let project = self.project_hook_json(record.project);
+        json!({
+            "id": record.id,
+            "iid": record.iid,
+            "source": project,
+            "target": project,
+            "last_commit": null,
+            "work_in_progress": false,
+            "in_progress_merge_commit_sha": null,
+            "target_branch": record.target_branch,
+            "target_project_id": record.project,
+            "source_branch": record.source_branch,
+            "source_project_id": record.project,
+            "author_id": record.author,
+            "assignee_id": null,
+            "title": record.title,
+            "created_at": hook_date(&record.created_at),
+            "updated_at": hook_date(&record.updated_at),
+            "deleted_at": null,
+            "locked_at": null,
+            "updated_by_id": null,
+            "merge_commit_sha": null,
+            "merge_error": null,
+            "merge_params": {
+                "force_remove_source_branch": null,
+            },
+            "merge_user_id": record.merged_by,
+            "merge_when_pipeline_succeeds": false,
+            "milestone_id": null,
+            "oldrev": null,
+            "state": record.state.as_str(),
+            "merge_status": "can_be_merged",
+            "description": record.description,
+            "head_pipeline_id": null,
+            "url": self.merge_request_url(mr),
+            "action": action,
+            "time_estimate": 0,
+        })
+    }
+
+    fn note_json(&self, note: &NoteRecord) -> Value {
+        let (noteable_type, noteable_id, noteable_iid) = match note.target {
+            Noteable::Issue(issue) => ("Issue", issue, self.issue(issue).iid),
+            Noteable::MergeRequest(mr) => ("MergeRequest", mr, self.merge_request(mr).iid),
+        };
+        json!({
+            "id": note.id,
+            "type": null,
+            "body": note.body,
+            "attachment": null,
+            "author": self.user_basic_json(note.author),
+            "created_at": note.created_at,
+            "updated_at": note.created_at,
+            "resolvable": false,
+            "resolved": null,
+            "resolved_by": null,
+            "system": false,
+            "noteable_id": noteable_id,
+            "noteable_iid": noteable_iid,
+            "noteable_type": noteable_type,
+            "position": null,
+        })
+    }
+
+    fn pipeline_basic_json(&self, pipeline: &PipelineRecord) -> Value {
+        json!({
+            "id": pipeline.id,
+            "project_id": pipeline.project,
+            "ref": pipeline.ref_,
+            "sha": pipeline.sha,
+            "status": pipeline.status,
+            "source": "api",
+            "created_at": pipeline.created_at,
+            "updated_at": pipeline.updated_at,
+            "web_url": format!("{}/-/pipelines/{}", self.project_url(pipeline.project), pipeline.id),
+        })
+    }
+
+    fn pipeline_json(&self, pipeline: &PipelineRecord) -> Value {
+        let mut json = self.pipeline_basic_json(pipeline);
+        let extra = json!({
+            "before_sha": "0000000000000000000000000000000000000000",
+            "tag": false,
+            "yaml_errors": null,
+            "started_at": null,
+            "finished_at": pipeline.finished_at,
+            "committed_at": null,
+            "duration": null,
+            "coverage": null,
+            "user": self.user_basic_json(pipeline.user),
+            "detailed_status": {
+                "text": pipeline.status,
+                "label": pipeline.status,
+                "group": pipeline.status,
+            },
+        });
+        extend_json(&mut json, extra);
+        json
+    }
+
+    fn hook_json(&self, hook: &HookRecord) -> Value {
+        json!({
+            "id": hook.id,
+            "url": hook.url,
+            "created_at": hook.created_at,
+            "project_id": hook.project,
+            "push_events": hook.push_events,
+            "push_events_branch_filter": null,
+            "tag_push_events": false,
+            "issues_events": hook.issues_events,
+            "confidential_issues_events": false,
+            "merge_requests_events": hook.merge_requests_events,
+            "note_events": hook.note_events,
+            "confidential_note_events": false,
+            "repository_update_events": false,
+            "enable_ssl_verification": true,
+            "job_events": false,
+            "pipeline_events": hook.pipeline_events,
+            "wiki_page_events": false,
+            "deployment_events": false,
+            "releases_events": false,
+        })
+    }
+}
+
+type Shared = Arc<Mutex<State>>;
+
+fn lock(state: &Shared) -> MutexGuard<'_, State> {
+    state
+        .lock()
+        .expect("the fake GitLab server state should not be poisoned")
+}
+
+fn header<'a>(request: &'a tiny_http::Request, name: &'static str) -> Option<&'a str> {
+    request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv(name))
+        .map(|header| header.value.as_str())
+}
+
+fn handle(state: &Shared, base: &str, mut request: tiny_http::Request) {
+    let mut body = Vec::new();
+    let reply = if let Err(err) = request.as_reader().read_to_end(&mut body) {
+        Reply::message(400, &format!("400 Bad request - {}", err))
+    } else if let Ok(url) = Url::parse(&format!("{}{}", base, request.url())) {
+        let mut params: Vec<_> = url.query_pairs().into_owned().collect();
+        let content_type = header(&request, "Content-Type").unwrap_or("");
+        if content_type.starts_with("application/json") {
+            if let Ok(Value::Object(object)) = serde_json::from_slice::<Value>(&body) {
+                params.extend(object.into_iter().map(|(key, value)| {
+                    let value = match value {
+                        Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    (key, value)
+                }));
+            }
+        } else {
+            params.extend(url::form_urlencoded::parse(&body).into_owned());
+        }
+
+        let token = header(&request, "PRIVATE-TOKEN").or_else(|| {
+            header(&request, "Authorization").and_then(|value| value.strip_prefix("Bearer "))
+        });
+        let method = request.method().as_str().to_string();
+
+        lock(state).dispatch(&method, &url, token, Params(params))
+    } else {
+        Reply::message(400, "400 Bad request")
+    };
+
+    let body = reply
+        .body
+        .map(|body| serde_json::to_vec(&body).unwrap_or_default())
+        .unwrap_or_default();
+    let mut response = tiny_http::Response::from_data(body).with_status_code(reply.status);
+    if reply.status != 204 {
+        response.add_header(
+            tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+                .expect("static headers are valid"),
+        );
+    }
+    for (name, value) in reply.headers {
+        if let Ok(header) = tiny_http::Header::from_bytes(name.as_bytes(), value.as_bytes()) {
+            response.add_header(header);
+        }
+    }
+    // The client may have gone away; there is nothing to do about it here.
+    let _ = request.respond(response);
+}
+
+fn deliver(deliveries: Receiver<HookDelivery>) {
+    let client = match reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+    {
+        Ok(client) => client,
+        Err(_) => return,
+    };
+
+    for delivery in deliveries {
+        let mut request = client
+            .post(&delivery.url)
+            .header("X-Gitlab-Event", &delivery.event)
+            .json(&delivery.payload);
+        if let Some(token) = delivery.token.as_ref() {
+            request = request.header("X-Gitlab-Token", token);
+        }
+        // Like GitLab, failed deliveries are not retried.
+        let _ = request.send();
+    }
+}
+
+/// A fake GitLab server listening on a local port.
+///
+/// The server shuts down when dropped.
+pub struct FakeGitlab {
+    addr: SocketAddr,
+    server: Arc<tiny_http::Server>,
+    state: Shared,
+    server_thread: Option<JoinHandle<()>>,
+    delivery_thread: Option<JoinHandle<()>>,
+}
+
+impl FakeGitlab {
+    /// Start a server on a random port on `127.0.0.1`.
+    pub fn start() -> Result<Self, ServerError> {
+        let server = tiny_http::Server::http("127.0.0.1:0").map_err(|source| {
+            ServerError::Bind {
+                source,
+            }
+        })?;
+        let addr = server
+            .server_addr()
+            .to_ip()
+            .expect("the server should listen on an IP address");
+        let server = Arc::new(server);
+        let base = format!("http://{}", addr);
+
+        let (sender, receiver) = mpsc::channel();
+        let state = Arc::new(Mutex::new(State {
+            base: base.clone(),
+            next_id: 0,
+            users: Vec::new(),
+            groups: Vec::new(),
+            projects: Vec::new(),
+            issues: Vec::new(),
+            merge_requests: Vec::new(),
+            notes: Vec::new(),
+            pipelines: Vec::new(),
+            hooks: Vec::new(),
+            deliveries: Vec::new(),
+            sender: Some(sender),
+        }));
+
+        let delivery_thread = thread::spawn(move || deliver(receiver));
+        let server_thread = {
+            let server = Arc::clone(&server);
+            let state = Arc::clone(&state);
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle(&state, &base, request);
+                }
+            })
+        };
+
+        Ok(Self {
+            addr,
+            server,
+            state,
+            server_thread: Some(server_thread),
+            delivery_thread: Some(delivery_thread),
+        })
+    }
+
+    /// The host to use for clients.
+    ///
+    /// Clients must use plain HTTP (e.g., [`GitlabBuilder::insecure`](crate::GitlabBuilder::insecure)).
+    pub fn host(&self) -> String {
+        self.addr.to_string()
+    }
+
+    /// Add a user which authenticates with the given token.
+    pub fn add_user<U, T>(&self, username: U, token: T) -> UserId
+    where
+        U: Into<String>,
+        T: Into<String>,
+    {
+        let mut state = lock(&self.state);
+        let id = state.next_id();
+        state.users.push(UserRecord {
+            id,
+            username: username.into(),
+            token: token.into(),
+            created_at: Utc::now(),
+        });
+        UserId::new(id)
+    }
+
+    /// Add a project owned by a user or group namespace.
+    ///
+    /// If the path has no namespace, the project is placed in the creator's namespace. Namespaces
+    /// which are not the username of a user are treated as groups.
+    pub fn add_project(&self, creator: UserId, path_with_namespace: &str) -> ProjectId {
+        let mut state = lock(&self.state);
+        ProjectId::new(state.add_project(creator.value(), path_with_namespace))
+    }
+
+    /// Update the status of a pipeline.
+    ///
+    /// Project hooks are notified of the change. Returns `false` if the pipeline does not exist.
+    pub fn set_pipeline_status(
+        &self,
+        project: ProjectId,
+        pipeline: PipelineId,
+        status: StatusState,
+    ) -> bool {
+        let status = match serde_json::to_value(status) {
+            Ok(Value::String(status)) => status,
+            _ => return false,
+        };
+        let mut state = lock(&self.state);
+        let now = Utc::now();
+        let record = state
+            .pipelines
+            .iter_mut()
+            .find(|record| record.project == project.value() && record.id == pipeline.value());
+        if let Some(record) = record {
+            record.finished_at = if is_finished(&status) {
+                Some(now)
+            } else {
+                None
+            };
+            record.status = status;
+            record.updated_at = now;
+            state.trigger_pipeline(pipeline.value());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The webhooks which have been sent by the server.
+    ///
+    /// Deliveries are recorded when they are queued, so they may not have been received yet.
+    pub fn deliveries(&self) -> Vec<HookDelivery> {
+        lock(&self.state).deliveries.clone()
+    }
+}
+
+impl Drop for FakeGitlab {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.sender.take();
+        }
+        self.server.unblock();
+        if let Some(thread) = self.server_thread.take() {
+            let _ = thread.join();
+        }
+        if let Some(thread) = self.delivery_thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::Value;
+
+    use crate::api::projects::{self, hooks, issues, merge_requests, pipelines};
+    use crate::api::{self, ApiError, AsyncQuery, Query};
+    use crate::testing::server::FakeGitlab;
+    use crate::types::{self, StatusState};
+    use crate::webhooks::WebHook;
+    use crate::{Gitlab, GitlabBuilder};
+
+    fn client(server: &FakeGitlab, token: &str) -> Gitlab {
+        Gitlab::builder(server.host(), token)
+            .insecure()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn unknown_token_is_rejected() {
+        let server = FakeGitlab::start().unwrap();
+        server.add_user("bot", "bot-token");
+
+        assert!(Gitlab::builder(server.host(), "wrong-token")
+            .insecure()
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn projects() {
+        let server = FakeGitlab::start().unwrap();
+        let user = server.add_user("bot", "bot-token");
+        let seeded = server.add_project(user, "group/seeded");
+        let client = client(&server, "bot-token");
+
+        let endpoint = projects::CreateProject::builder()
+            .name("created")
+            .build()
+            .unwrap();
+        let created: types::Project = endpoint.query(&client).unwrap();
+        assert_eq!(created.path_with_namespace, "bot/created");
+        assert_eq!(created.creator_id, user);
+
+        let endpoint = projects::Project::builder()
+            .project("group/seeded")
+            .build()
+            .unwrap();
+        let project: types::Project = endpoint.query(&client).unwrap();
+        assert_eq!(project.id, seeded);
+
+        let endpoint = projects::Project::builder()
+            .project("group/missing")
+            .build()
+            .unwrap();
+        let err = api::ignore(endpoint).query(&client).unwrap_err();
+        if let ApiError::NotFound {
+            error,
+        } = err
+        {
+            assert_eq!(error.message(), Some("404 Project Not Found"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        let endpoint = projects::CreateProject::builder()
+            .name("created")
+            .build()
+            .unwrap();
+        let err = api::ignore(endpoint).query(&client).unwrap_err();
+        if let ApiError::Validation {
+            error,
+        } = err
+        {
+            assert_eq!(error.field_error("path"), ["has already been taken"]);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        let endpoint = projects::Projects::builder().build().unwrap();
+        let projects: Vec<types::Project> = endpoint.query(&client).unwrap();
+        assert_eq!(projects.len(), 2);
+    }
+
+    #[test]
+    fn issues_and_notes() {
+        let server = FakeGitlab::start().unwrap();
+        let user = server.add_user("bot", "bot-token");
+        server.add_project(user, "group/project");
+        let client = client(&server, "bot-token");
+
+        let endpoint = issues::CreateIssue::builder()
+            .project("group/project")
+            .title("an issue")
+            .labels(["bug"].iter().copied())
+            .build()
+            .unwrap();
+        let issue: types::Issue = endpoint.query(&client).unwrap();
+        assert_eq!(issue.iid.value(), 1);
+        assert_eq!(issue.labels, ["bug"]);
+
+        let endpoint = issues::notes::CreateIssueNote::builder()
+            .project("group/project")
+            .issue(1)
+            .body("a comment")
+            .build()
+            .unwrap();
+        let note: types::Note = endpoint.query(&client).unwrap();
+        assert_eq!(note.body, "a comment");
+        assert_eq!(note.author.id, user);
+
+        let endpoint = issues::EditIssue::builder()
+            .project("group/project")
+            .issue(1)
+            .state_event(issues::IssueStateEvent::Close)
+            .build()
+            .unwrap();
+        let issue: types::Issue = endpoint.query(&client).unwrap();
+        assert_eq!(issue.state, types::IssueState::Closed);
+        assert_eq!(issue.user_notes_count, 1);
+
+        let endpoint = issues::Issues::builder()
+            .project("group/project")
+            .state(issues::IssueState::Opened)
+            .build()
+            .unwrap();
+        let open: Vec<types::Issue> = endpoint.query(&client).unwrap();
+        assert!(open.is_empty());
+
+        let endpoint = issues::notes::IssueNotes::builder()
+            .project("group/project")
+            .issue(1)
+            .build()
+            .unwrap();
+        let notes: Vec<types::Note> = endpoint.query(&client).unwrap();
+        assert_eq!(notes.len(), 1);
+    }
+
+    #[test]
+    fn merge_requests() {
+        let server = FakeGitlab::start().unwrap();
+        let user = server.add_user("bot", "bot-token");
+        server.add_project(user, "group/project");
+        let client = client(&server, "bot-token");
+
+        let create = merge_requests::CreateMergeRequest::builder()
+            .project("group/project")
+            .source_branch("topic")
+            .target_branch("main")
+            .title("a merge request")
+            .build()
+            .unwrap();
+        let mr: types::MergeRequest = create.query(&client).unwrap();
+        assert_eq!(mr.state, types::MergeRequestState::Opened);
+
+        let err = api::ignore(create).query(&client).unwrap_err();
+        if let ApiError::Conflict {
+            error,
+        } = err
+        {
+            assert_eq!(
+                error.message(),
+                Some("Another open merge request already exists for this source branch: !1"),
+            );
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        let endpoint = merge_requests::notes::CreateMergeRequestNote::builder()
+            .project("group/project")
+            .merge_request(1)
+            .body("looks good")
+            .build()
+            .unwrap();
+        let note: types::Note = endpoint.query(&client).unwrap();
+        assert_eq!(note.noteable_type, types::NoteType::MergeRequest);
+
+        let endpoint = merge_requests::MergeMergeRequest::builder()
+            .project("group/project")
+            .merge_request(1)
+            .build()
+            .unwrap();
+        let mr: types::MergeRequest = endpoint.query(&client).unwrap();
+        assert_eq!(mr.state, types::MergeRequestState::Merged);
+        assert_eq!(mr.merged_by.unwrap().id, user);
+
+        let endpoint = merge_requests::MergeRequests::builder()
+            .project("group/project")
+            .state(merge_requests::MergeRequestState::Merged)
+            .build()
+            .unwrap();
+        let merged: Vec<types::MergeRequest> = endpoint.query(&client).unwrap();
+        assert_eq!(merged.len(), 1);
+    }
+
+    #[test]
+    fn pipelines() {
+        let server = FakeGitlab::start().unwrap();
+        let user = server.add_user("bot", "bot-token");
+        let project = server.add_project(user, "group/project");
+        let client = client(&server, "bot-token");
+
+        let endpoint = pipelines::CreatePipeline::builder()
+            .project("group/project")
+            .ref_("main")
+            .build()
+            .unwrap();
+        let pipeline: types::Pipeline = endpoint.query(&client).unwrap();
+        assert_eq!(pipeline.status, StatusState::Pending);
+
+        assert!(server.set_pipeline_status(project, pipeline.id, StatusState::Success));
+
+        let endpoint = pipelines::Pipeline::builder()
+            .project("group/project")
+            .pipeline(pipeline.id.value())
+            .build()
+            .unwrap();
+        let pipeline: types::Pipeline = endpoint.query(&client).unwrap();
+        assert_eq!(pipeline.status, StatusState::Success);
+        assert!(pipeline.finished_at.is_some());
+
+        let endpoint = pipelines::Pipelines::builder()
+            .project("group/project")
+            .ref_("main")
+            .build()
+            .unwrap();
+        let pipelines: Vec<types::PipelineBasic> = endpoint.query(&client).unwrap();
+        assert_eq!(pipelines.len(), 1);
+    }
+
+    #[test]
+    fn pagination() {
+        let server = FakeGitlab::start().unwrap();
+        let user = server.add_user("bot", "bot-token");
+        server.add_project(user, "group/project");
+        let client = client(&server, "bot-token");
+
+        for idx in 0..45 {
+            let endpoint = issues::CreateIssue::builder()
+                .project("group/project")
+                .title(format!("issue {}", idx))
+                .build()
+                .unwrap();
+            api::ignore(endpoint).query(&client).unwrap();
+        }
+
+        let endpoint = issues::Issues::builder()
+            .project("group/project")
+            .build()
+            .unwrap();
+        let issues: Vec<types::Issue> = api::paged(endpoint, api::Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(issues.len(), 45);
+        assert_eq!(issues[0].iid.value(), 45);
+        assert_eq!(issues[44].iid.value(), 1);
+    }
+
+    #[test]
+    fn webhooks() {
+        let receiver = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let receiver_addr = receiver.server_addr().to_ip().unwrap();
+
+        let server = FakeGitlab::start().unwrap();
+        let user = server.add_user("bot", "bot-token");
+        server.add_project(user, "group/project");
+        let client = client(&server, "bot-token");
+
+        let endpoint = hooks::CreateHook::builder()
+            .project("group/project")
+            .url(format!("http://{}/hook", receiver_addr))
+            .push_events(false)
+            .issues_events(true)
+            .note_events(true)
+            .token("secret")
+            .build()
+            .unwrap();
+        let hook: types::ProjectHook = endpoint.query(&client).unwrap();
+        assert!(hook.issues_events);
+        assert!(!hook.merge_requests_events);
+
+        let endpoint = issues::CreateIssue::builder()
+            .project("group/project")
+            .title("an issue")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+        let endpoint = issues::notes::CreateIssueNote::builder()
+            .project("group/project")
+            .issue(1)
+            .body("a comment")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+        // Not subscribed to by the hook.
+        let endpoint = merge_requests::CreateMergeRequest::builder()
+            .project("group/project")
+            .source_branch("topic")
+            .target_branch("main")
+            .title("a merge request")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+
+        let deliveries = server.deliveries();
+        assert_eq!(deliveries.len(), 2);
+        assert_eq!(deliveries[0].event, "Issue Hook");
+        assert_eq!(deliveries[1].event, "Note Hook");
+
+        let mut received = Vec::new();
+        for _ in 0..2 {
+            let mut request = receiver
+                .recv_timeout(Duration::from_secs(10))
+                .unwrap()
+                .expect("a webhook should be delivered");
+            let event = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("X-Gitlab-Event"))
+                .map(|header| header.value.to_string())
+                .unwrap();
+            let token = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("X-Gitlab-Token"))
+                .map(|header| header.value.to_string());
+            assert_eq!(token.as_deref(), Some("secret"));
+            let payload: Value = serde_json::from_reader(request.as_reader()).unwrap();
+            request.respond(tiny_http::Response::empty(200)).unwrap();
+            received.push((event, payload));
+        }
+
+        assert_eq!(received[0].0, "Issue Hook");
+        let hook: WebHook = serde_json::from_value(received[0].1.clone()).unwrap();
+        if let WebHook::Issue(hook) = hook {
+            assert_eq!(hook.object_attributes.title, "an issue");
+        } else {
+            panic!("unexpected hook: {:?}", hook);
+        }
+
+        assert_eq!(received[1].0, "Note Hook");
+        let hook: WebHook = serde_json::from_value(received[1].1.clone()).unwrap();
+        if let WebHook::Note(hook) = hook {
+            assert_eq!(hook.object_attributes.note, "a comment");
+            assert_eq!(hook.issue.unwrap().iid.value(), 1);
+        } else {
+            panic!("unexpected hook: {:?}", hook);
+        }
+    }
+
+    #[test]
+    fn merge_request_and_pipeline_hook_payloads() {
+        let server = FakeGitlab::start().unwrap();
+        let user = server.add_user("bot", "bot-token");
+        let project = server.add_project(user, "group/project");
+        let client = client(&server, "bot-token");
+
+        let endpoint = hooks::CreateHook::builder()
+            .project("group/project")
+            // Nothing listens here; deliveries are still recorded.
+            .url("http://127.0.0.1:9/hook")
+            .merge_requests_events(true)
+            .pipeline_events(true)
+            .note_events(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+
+        let endpoint = merge_requests::CreateMergeRequest::builder()
+            .project("group/project")
+            .source_branch("topic")
+            .target_branch("main")
+            .title("a merge request")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+        let endpoint = merge_requests::notes::CreateMergeRequestNote::builder()
+            .project("group/project")
+            .merge_request(1)
+            .body("a comment")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+        let endpoint = pipelines::CreatePipeline::builder()
+            .project("group/project")
+            .ref_("topic")
+            .build()
+            .unwrap();
+        let pipeline: types::Pipeline = endpoint.query(&client).unwrap();
+        server.set_pipeline_status(project, pipeline.id, StatusState::Failed);
+
+        let hooks: Vec<WebHook> = server
+            .deliveries()
+            .into_iter()
+            .map(|delivery| serde_json::from_value(delivery.payload).unwrap())
+            .collect();
+        assert_eq!(hooks.len(), 4);
+        if let WebHook::MergeRequest(hook) = &hooks[0] {
+            assert_eq!(hook.object_attributes.source_branch, "topic");
+        } else {
+            panic!("unexpected hook: {:?}", hooks[0]);
+        }
+        if let WebHook::Note(hook) = &hooks[1] {
+            assert_eq!(hook.merge_request.as_ref().unwrap().iid.value(), 1);
+        } else {
+            panic!("unexpected hook: {:?}", hooks[1]);
+        }
+        if let WebHook::Pipeline(hook) = &hooks[3] {
+            assert_eq!(hook.object_attributes.status, StatusState::Failed);
+        } else {
+            panic!("unexpected hook: {:?}", hooks[3]);
+        }
+
+        let endpoint = merge_requests::MergeRequest::builder()
+            .project("group/project")
+            .merge_request(1)
+            .build()
+            .unwrap();
+        let mr: types::MergeRequest = endpoint.query(&client).unwrap();
+        assert_eq!(mr.pipeline.unwrap().status, StatusState::Failed);
+    }
+
+    #[tokio::test]
+    async fn async_client() {
+        let server = FakeGitlab::start().unwrap();
+        let user = server.add_user("bot", "bot-token");
+        server.add_project(user, "group/project");
+        let client = GitlabBuilder::new(server.host(), "bot-token")
+            .insecure()
+            .build_async()
+            .await
+            .unwrap();
+
+        let endpoint = issues::CreateIssue::builder()
+            .project("group/project")
+            .title("an issue")
+            .build()
+            .unwrap();
+        let issue: types::Issue = endpoint.query_async(&client).await.unwrap();
+        assert_eq!(issue.author.id, user);
+    }
+}
@@ -444,23 +444,33 @@ impl From<GroupHook> for Hook {
         }
     }
 }
-/// Reponse of a project variable
+
+/// A CI/CD variable of a project, group, or instance.
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ProjectVariable {
+pub struct Variable {
     /// The key
     pub key: String,
-    // The variable type
-    pub variable_type: String,
+    /// The variable type
+    #[serde(default)]
+    pub variable_type: PipelineVariableType,
     /// The value
     pub value: String,
     /// Flag if its protected
     pub protected: bool,
     /// Flag if its masked
     pub masked: bool,
+    /// Flag if its value is used as-is without expanding other variables
+    #[serde(default)]
+    pub raw: bool,
     /// Environment scope
-    pub environment_scope: String,
+    ///
+    /// Instance variables do not have an environment scope.
+    pub environment_scope: Option<String>,
 }
 
+/// Reponse of a project variable
+pub type ProjectVariable = Variable;
+
 /// Reponse of a project variable
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ProtectedTagAccessLevel {
@@ -502,6 +512,100 @@ pub struct Tag {
     pub protected: bool,
 }
 
+impl_id!(ReleaseLinkId, "Type-safe release link ID.");
+
+/// The type of an asset link of a release.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseLinkType {
+    /// A link to some other resource.
+    #[serde(rename = "other")]
+    Other,
+    /// A link to a runbook.
+    #[serde(rename = "runbook")]
+    Runbook,
+    /// A link to an image.
+    #[serde(rename = "image")]
+    Image,
+    /// A link to a package.
+    #[serde(rename = "package")]
+    Package,
+}
+
+impl Default for ReleaseLinkType {
+    fn default() -> Self {
+        ReleaseLinkType::Other
+    }
+}
+
+/// An asset link of a release.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReleaseLink {
+    /// The ID of the link.
+    pub id: ReleaseLinkId,
+    /// The name of the link.
+    pub name: String,
+    /// The URL of the link.
+    pub url: String,
+    /// The permanent URL of the link through the release.
+    pub direct_asset_url: Option<String>,
+    /// The type of the link.
+    #[serde(default)]
+    pub link_type: ReleaseLinkType,
+}
+
+/// A source code archive of a release.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReleaseSource {
+    /// The format of the archive (e.g., `zip` or `tar.gz`).
+    pub format: String,
+    /// The URL of the archive.
+    pub url: String,
+}
+
+/// The assets of a release.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReleaseAssets {
+    /// The number of assets.
+    pub count: u64,
+    /// Source code archives of the release.
+    #[serde(default)]
+    pub sources: Vec<ReleaseSource>,
+    /// Links attached to the release.
+    #[serde(default)]
+    pub links: Vec<ReleaseLink>,
+}
+
+/// A release of a project.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Release {
+    /// The name of the tag of the release.
+    pub tag_name: String,
+    /// The name of the release.
+    pub name: Option<String>,
+    /// The description of the release.
+    pub description: Option<String>,
+    /// The description of the release rendered as HTML.
+    ///
+    /// Only available if requested.
+    pub description_html: Option<String>,
+    /// When the release was created.
+    pub created_at: DateTime<Utc>,
+    /// When the release was (or will be) released.
+    pub released_at: Option<DateTime<Utc>>,
+    /// Whether the release is in the future.
+    #[serde(default)]
+    pub upcoming_release: bool,
+    /// The user who created the release.
+    pub author: Option<UserBasic>,
+    /// The commit the tag of the release points to.
+    pub commit: Option<RepoCommit>,
+    /// The milestones associated with the release.
+    #[serde(default)]
+    pub milestones: Vec<Milestone>,
+    /// The assets of the release.
+    pub assets: ReleaseAssets,
+}
+
 impl_id!(ProjectId, "Type-safe project ID.");
 
 /// Basic project information.
@@ -521,6 +625,26 @@ pub struct BasicProjectDetails {
     pub http_url_to_repo: String,
     /// The URL to the main page of the repository.
     pub web_url: String,
+    /// The description of the project.
+    pub description: Option<String>,
+    /// The default branch for the project.
+    pub default_branch: Option<String>,
+    /// The URL to clone the repository over SSH.
+    pub ssh_url_to_repo: Option<String>,
+    /// The URL for the project's readme.
+    pub readme_url: Option<String>,
+    /// The URL to the project avatar.
+    pub avatar_url: Option<String>,
+    /// The namespace the project lives in.
+    pub namespace: Option<Namespace>,
+    /// When the repository was created.
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the last activity on the project occurred.
+    pub last_activity_at: Option<DateTime<Utc>>,
+    /// The number of stars for the project.
+    pub star_count: Option<u64>,
+    /// The number of forks.
+    pub forks_count: Option<u64>,
 }
 
 /// Visibility levels of projects.
@@ -1244,6 +1368,23 @@ pub struct RepoDiff {
     pub deleted_file: bool,
 }
 
+/// The result of comparing two refs in a repository.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RepoCompare {
+    /// The newest commit of the comparison.
+    pub commit: Option<RepoCommit>,
+    /// The commits between the refs.
+    pub commits: Vec<RepoCommit>,
+    /// The changes between the refs.
+    pub diffs: Vec<RepoDiff>,
+    /// Whether the comparison timed out or not.
+    pub compare_timeout: bool,
+    /// Whether the refs point to the same commit or not.
+    pub compare_same_ref: bool,
+    /// The URL to the comparison page.
+    pub web_url: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct DiffRefs {
     /// SHA referencing base commit in the source branch
@@ -2473,6 +2614,8 @@ pub struct Environment {
     pub slug: String,
     pub external_url: Option<String>,
     pub state: Option<String>,
+    /// The deployment tier of the environment.
+    pub tier: Option<String>,
     pub last_deployment: Option<Deployment>,
 }
 
@@ -2487,7 +2630,36 @@ pub struct Deployment {
     pub created_at: String,
     pub status: Option<String>,
     pub user: UserBasic,
-    pub deployable: Deployable,
+    /// The job which performed the deployment.
+    ///
+    /// Deployments created through the API do not have a job.
+    pub deployable: Option<Deployable>,
+    /// Approvals (or rejections) of the deployment.
+    #[serde(default)]
+    pub approvals: Vec<DeploymentApproval>,
+}
+
+/// The status of a deployment approval.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentApprovalStatus {
+    /// The deployment was approved.
+    Approved,
+    /// The deployment was rejected.
+    Rejected,
+}
+
+/// An approval (or rejection) of a deployment to a protected environment.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeploymentApproval {
+    /// The user who approved or rejected the deployment.
+    pub user: UserBasic,
+    /// Whether the deployment was approved or rejected.
+    pub status: DeploymentApprovalStatus,
+    /// When the approval was given.
+    pub created_at: DateTime<Utc>,
+    /// The comment left with the approval.
+    pub comment: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2650,6 +2822,48 @@ impl Namespace {
 
 impl_id!(RunnerId, "Type-safe runner ID.");
 
+/// The type of a runner.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RunnerType {
+    /// A runner available to all projects on the instance.
+    #[serde(rename = "instance_type")]
+    Instance,
+    /// A runner available to all projects within a group.
+    #[serde(rename = "group_type")]
+    Group,
+    /// A runner available to specific projects.
+    #[serde(rename = "project_type")]
+    Project,
+}
+
+/// The connection status of a runner.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RunnerStatus {
+    /// The runner has contacted the instance recently.
+    #[serde(rename = "online")]
+    Online,
+    /// The runner has not contacted the instance recently.
+    #[serde(rename = "offline")]
+    Offline,
+    /// The runner has not contacted the instance in a long time.
+    #[serde(rename = "stale")]
+    Stale,
+    /// The runner has never contacted the instance.
+    #[serde(rename = "never_contacted")]
+    NeverContacted,
+    /// The runner is active (used by older instances).
+    #[serde(rename = "active")]
+    Active,
+    /// The runner is paused (used by older instances).
+    #[serde(rename = "paused")]
+    Paused,
+    /// The runner has never contacted the instance (used by older instances).
+    #[serde(rename = "not_connected")]
+    NotConnected,
+}
+
 /// A Gitlab CI runner.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Runner {
@@ -2659,10 +2873,127 @@ pub struct Runner {
     pub description: Option<String>,
     /// Whether the runner is active or not.
     pub active: bool,
+    /// Whether the runner is paused or not.
+    pub paused: Option<bool>,
+    /// Whether the runner is shared or not.
+    pub is_shared: bool,
+    /// The type of the runner.
+    pub runner_type: Option<RunnerType>,
+    /// The name of the runner.
+    pub name: Option<String>,
+    /// Whether the runner is online or not.
+    pub online: Option<bool>,
+    /// The connection status of the runner.
+    pub status: Option<RunnerStatus>,
+}
+
+/// The refs a runner may run jobs for.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RunnerAccessLevel {
+    /// The runner may run jobs for any ref.
+    #[serde(rename = "not_protected")]
+    NotProtected,
+    /// The runner may only run jobs for protected refs.
+    #[serde(rename = "ref_protected")]
+    RefProtected,
+}
+
+/// A project a runner is assigned to.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RunnerProject {
+    /// The ID of the project.
+    pub id: ProjectId,
+    /// The name of the project.
+    pub name: String,
+    /// The name of the project with its namespace.
+    pub name_with_namespace: String,
+    /// The path of the project.
+    pub path: String,
+    /// The path of the project with its namespace.
+    pub path_with_namespace: String,
+}
+
+/// A group a runner is assigned to.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RunnerGroup {
+    /// The ID of the group.
+    pub id: GroupId,
+    /// The name of the group.
+    pub name: String,
+    /// The URL to the group page.
+    pub web_url: String,
+}
+
+/// Detailed information about a Gitlab CI runner.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RunnerDetails {
+    /// The ID of the runner.
+    pub id: RunnerId,
+    /// The description of the runner.
+    pub description: Option<String>,
+    /// Whether the runner is active or not.
+    pub active: bool,
+    /// Whether the runner is paused or not.
+    pub paused: Option<bool>,
     /// Whether the runner is shared or not.
     pub is_shared: bool,
+    /// The type of the runner.
+    pub runner_type: Option<RunnerType>,
     /// The name of the runner.
     pub name: Option<String>,
+    /// Whether the runner is online or not.
+    pub online: Option<bool>,
+    /// The connection status of the runner.
+    pub status: Option<RunnerStatus>,
+    /// The tags of the runner.
+    pub tag_list: Vec<String>,
+    /// Whether the runner runs jobs without tags.
+    pub run_untagged: bool,
+    /// Whether the runner is locked to its current projects.
+    pub locked: bool,
+    /// The refs the runner may run jobs for.
+    pub access_level: RunnerAccessLevel,
+    /// The maximum timeout for jobs on the runner (in seconds).
+    pub maximum_timeout: Option<u64>,
+    /// A note for maintainers of the runner.
+    pub maintenance_note: Option<String>,
+    /// When the runner last contacted the instance.
+    pub contacted_at: Option<DateTime<Utc>>,
+    /// The architecture of the runner.
+    pub architecture: Option<String>,
+    /// The platform of the runner.
+    pub platform: Option<String>,
+    /// The revision of the runner software.
+    pub revision: Option<String>,
+    /// The version of the runner software.
+    pub version: Option<String>,
+    /// The projects the runner is assigned to.
+    #[serde(default)]
+    pub projects: Vec<RunnerProject>,
+    /// The groups the runner is assigned to.
+    #[serde(default)]
+    pub groups: Vec<RunnerGroup>,
+}
+
+/// A newly registered runner.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RegisteredRunner {
+    /// The ID of the runner.
+    pub id: RunnerId,
+    /// The authentication token of the runner.
+    pub token: String,
+    /// When the authentication token expires.
+    pub token_expires_at: Option<DateTime<Utc>>,
+}
+
+/// A runner registration token.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RunnerRegistrationToken {
+    /// The token.
+    pub token: String,
+    /// When the token expires.
+    pub token_expires_at: Option<DateTime<Utc>>,
 }
 
 /// An uploaded artifact from a job.
@@ -2720,11 +3051,54 @@ pub struct Job {
     pub pipeline: PipelineBasic,
     pub allow_failure: bool,
     pub duration: Option<f64>,
+    #[serde(default)]
     pub artifacts: Vec<JobArtifact>,
     pub artifacts_expire_at: Option<DateTime<Utc>>,
     pub web_url: String,
 }
 
+/// A bridge job which triggers a downstream pipeline.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Bridge {
+    /// The ID of the bridge.
+    pub id: JobId,
+    /// The status of the bridge.
+    pub status: StatusState,
+    /// The stage of the bridge.
+    pub stage: String,
+    /// The name of the bridge.
+    pub name: String,
+    #[serde(rename = "ref")]
+    /// The name of the reference that was tested.
+    pub ref_: Option<String>,
+    /// Whether the pipeline was for a tag or not.
+    pub tag: bool,
+    /// The coverage of the bridge.
+    pub coverage: Option<f64>,
+    /// When the bridge was created.
+    pub created_at: DateTime<Utc>,
+    /// When the bridge was started.
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the bridge completed.
+    pub finished_at: Option<DateTime<Utc>>,
+    /// How long the bridge ran for (in seconds).
+    pub duration: Option<f64>,
+    /// The user which triggered the bridge.
+    pub user: Option<UserBasic>,
+    /// The commit of the pipeline.
+    pub commit: RepoCommit,
+    /// The pipeline the bridge belongs to.
+    pub pipeline: PipelineBasic,
+    /// Whether the bridge is allowed to fail.
+    pub allow_failure: bool,
+    /// The URL to the bridge page.
+    pub web_url: String,
+    /// The pipeline triggered by the bridge.
+    ///
+    /// This may be in another project.
+    pub downstream_pipeline: Option<PipelineBasic>,
+}
+
 impl_id!(PipelineId, "Type-safe pipeline ID.");
 
 /// Information about a pipeline in Gitlab CI.
@@ -2819,6 +3193,271 @@ pub struct PipelineVariable {
     pub variable_type: PipelineVariableType,
 }
 
+impl_id!(PipelineScheduleId, "Type-safe pipeline schedule ID.");
+
+/// The most recent pipeline started by a pipeline schedule.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PipelineScheduleLastPipeline {
+    /// The ID of the pipeline.
+    pub id: PipelineId,
+    /// The object ID that was tested.
+    pub sha: ObjectId,
+    #[serde(rename = "ref")]
+    /// The name of the reference that was tested.
+    pub ref_: Option<String>,
+    /// The status of the pipeline.
+    pub status: StatusState,
+}
+
+/// A pipeline schedule.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PipelineSchedule {
+    /// The ID of the schedule.
+    pub id: PipelineScheduleId,
+    /// The description of the schedule.
+    pub description: String,
+    #[serde(rename = "ref")]
+    /// The reference the schedule runs pipelines for.
+    pub ref_: String,
+    /// The cron expression for the schedule.
+    pub cron: String,
+    /// The timezone the cron expression is evaluated in.
+    pub cron_timezone: String,
+    /// When the schedule will next run.
+    pub next_run_at: Option<DateTime<Utc>>,
+    /// Whether the schedule is active or not.
+    pub active: bool,
+    /// When the schedule was created.
+    pub created_at: DateTime<Utc>,
+    /// When the schedule was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// The owner of the schedule.
+    ///
+    /// Pipelines started by the schedule run as this user.
+    pub owner: Option<UserBasic>,
+    /// The last pipeline started by the schedule.
+    ///
+    /// Only available when querying a single schedule.
+    pub last_pipeline: Option<PipelineScheduleLastPipeline>,
+    /// The variables set on pipelines started by the schedule.
+    ///
+    /// Only available when querying a single schedule.
+    #[serde(default)]
+    pub variables: Vec<PipelineVariable>,
+}
+
+impl_id!(PipelineTriggerId, "Type-safe pipeline trigger ID.");
+
+/// A pipeline trigger token.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PipelineTrigger {
+    /// The ID of the trigger.
+    pub id: PipelineTriggerId,
+    /// The description of the trigger.
+    pub description: String,
+    /// The trigger token.
+    ///
+    /// Tokens owned by other users are truncated.
+    pub token: String,
+    /// When the trigger was created.
+    pub created_at: DateTime<Utc>,
+    /// When the trigger was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// When the trigger was last used to start a pipeline.
+    pub last_used: Option<DateTime<Utc>>,
+    /// The owner of the trigger.
+    pub owner: Option<UserBasic>,
+}
+
+/// The status of a test case.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TestCaseStatus {
+    /// The test passed.
+    #[serde(rename = "success")]
+    Success,
+    /// The test failed.
+    #[serde(rename = "failed")]
+    Failed,
+    /// The test was skipped.
+    #[serde(rename = "skipped")]
+    Skipped,
+    /// The test could not be run.
+    #[serde(rename = "error")]
+    Error,
+}
+
+/// Recent failures of a test case.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TestCaseRecentFailures {
+    /// How many times the test case has failed recently.
+    pub count: u64,
+    /// The branch the failures were seen on.
+    pub base_branch: String,
+}
+
+/// A test case from a test report.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TestCase {
+    /// The status of the test case.
+    pub status: TestCaseStatus,
+    /// The name of the test case.
+    pub name: String,
+    /// The class name of the test case.
+    pub classname: String,
+    /// The file which contains the test case.
+    pub file: Option<String>,
+    /// How long the test case took to run (in seconds).
+    pub execution_time: f64,
+    /// The output of the test case.
+    pub system_output: Option<String>,
+    /// The stack trace of a failed test case.
+    pub stack_trace: Option<String>,
+    /// The URL of an attachment for the test case.
+    pub attachment_url: Option<String>,
+    /// Recent failures of the test case.
+    pub recent_failures: Option<TestCaseRecentFailures>,
+}
+
+/// A test suite from a test report.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TestSuite {
+    /// The name of the test suite.
+    pub name: String,
+    /// How long the test suite took to run (in seconds).
+    pub total_time: f64,
+    /// The number of test cases in the suite.
+    pub total_count: u64,
+    /// The number of successful test cases.
+    pub success_count: u64,
+    /// The number of failed test cases.
+    pub failed_count: u64,
+    /// The number of skipped test cases.
+    pub skipped_count: u64,
+    /// The number of test cases which could not be run.
+    pub error_count: u64,
+    /// An error which occurred while parsing the test suite's reports.
+    pub suite_error: Option<String>,
+    /// The jobs which reported results for the test suite.
+    ///
+    /// Only available in test report summaries.
+    #[serde(default)]
+    pub build_ids: Vec<JobId>,
+    /// The test cases of the suite.
+    ///
+    /// Not available in test report summaries.
+    #[serde(default)]
+    pub test_cases: Vec<TestCase>,
+}
+
+/// The test report of a pipeline.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TestReport {
+    /// How long all tests took to run (in seconds).
+    pub total_time: f64,
+    /// The number of test cases.
+    pub total_count: u64,
+    /// The number of successful test cases.
+    pub success_count: u64,
+    /// The number of failed test cases.
+    pub failed_count: u64,
+    /// The number of skipped test cases.
+    pub skipped_count: u64,
+    /// The number of test cases which could not be run.
+    pub error_count: u64,
+    /// The test suites of the report.
+    pub test_suites: Vec<TestSuite>,
+}
+
+/// The totals of a test report summary.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TestReportTotals {
+    /// How long all tests took to run (in seconds).
+    pub time: f64,
+    /// The number of test cases.
+    pub count: u64,
+    /// The number of successful test cases.
+    pub success: u64,
+    /// The number of failed test cases.
+    pub failed: u64,
+    /// The number of skipped test cases.
+    pub skipped: u64,
+    /// The number of test cases which could not be run.
+    pub error: u64,
+    /// An error which occurred while parsing the reports.
+    pub suite_error: Option<String>,
+}
+
+/// A summary of the test report of a pipeline.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TestReportSummary {
+    /// The totals of the report.
+    pub total: TestReportTotals,
+    /// The test suites of the report.
+    ///
+    /// Test cases are not included.
+    pub test_suites: Vec<TestSuite>,
+}
+
+/// A job required by another job in a CI configuration.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CiLintJobNeed {
+    /// The name of the required job.
+    pub name: String,
+}
+
+/// A job from a linted CI configuration.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CiLintJob {
+    /// The name of the job.
+    pub name: String,
+    /// The stage of the job.
+    pub stage: String,
+    /// The commands run before the job's script.
+    #[serde(default)]
+    pub before_script: Vec<String>,
+    /// The commands of the job.
+    #[serde(default)]
+    pub script: Vec<String>,
+    /// The commands run after the job's script.
+    #[serde(default)]
+    pub after_script: Vec<String>,
+    /// The runner tags required by the job.
+    #[serde(default)]
+    pub tag_list: Vec<String>,
+    /// The environment the job deploys to.
+    pub environment: Option<String>,
+    /// When the job runs.
+    pub when: String,
+    /// Whether the job is allowed to fail or not.
+    pub allow_failure: bool,
+    /// The conditions under which the job runs.
+    pub only: Option<Value>,
+    /// The conditions under which the job does not run.
+    pub except: Option<Value>,
+    /// The jobs required by the job.
+    #[serde(default)]
+    pub needs: Vec<CiLintJobNeed>,
+}
+
+/// The result of linting a CI configuration.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CiLintResult {
+    /// Whether the configuration is valid or not.
+    pub valid: bool,
+    /// Errors found in the configuration.
+    pub errors: Vec<String>,
+    /// Warnings found in the configuration.
+    pub warnings: Vec<String>,
+    /// The configuration with all includes expanded.
+    pub merged_yaml: Option<String>,
+    /// The jobs of the configuration.
+    ///
+    /// Only available when requested.
+    #[serde(default)]
+    pub jobs: Vec<CiLintJob>,
+}
+
 impl_id!(LabelEventId, "Type-safe label event ID.");
 
 /// A resource label event